@@ -1,5 +1,5 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use tempotime::{dt, Duration};
+use tempotime::{dt, Duration, Format};
 
 fn bench_chain_operations(c: &mut Criterion) {
     let dur = Duration::from_object(&[("days", 100)]);
@@ -26,6 +26,14 @@ fn bench_format_custom(c: &mut Criterion) {
     });
 }
 
+fn bench_format_compiled(c: &mut Criterion) {
+    let dt_val = dt();
+    let format = Format::compile("EEEE, MMMM do yyyy 'at' h:mm:ss a").unwrap();
+    c.bench_function("Format::format complex", |b| {
+        b.iter(|| format.format(black_box(&dt_val)));
+    });
+}
+
 fn bench_duration_conversion(c: &mut Criterion) {
     let dur = Duration::from_object(&[("weeks", 2), ("days", 3), ("hours", 4)]);
     c.bench_function("duration as_unit", |b| {
@@ -37,14 +45,14 @@ fn bench_plus_operation(c: &mut Criterion) {
     let dt_val = dt();
     let dur = Duration::from_object(&[("days", 30)]);
     c.bench_function("datetime plus", |b| {
-        b.iter(|| black_box(&dt_val).clone().plus(black_box(&dur)));
+        b.iter(|| black_box(dt_val).plus(black_box(&dur)));
     });
 }
 
 fn bench_start_of(c: &mut Criterion) {
     let dt_val = dt();
     c.bench_function("start_of day", |b| {
-        b.iter(|| black_box(&dt_val).clone().start_of("day"));
+        b.iter(|| black_box(dt_val).start_of("day"));
     });
 }
 
@@ -61,6 +69,7 @@ criterion_group!(
     bench_chain_operations,
     bench_format_iso,
     bench_format_custom,
+    bench_format_compiled,
     bench_duration_conversion,
     bench_plus_operation,
     bench_start_of,
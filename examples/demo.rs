@@ -57,14 +57,10 @@ fn main() {
     println!("Start/end of units:");
     println!(
         "  Start of month: {}",
-        now.clone()
-            .start_of("month")
-            .to_format("yyyy-MM-dd HH:mm:ss")
+        now.start_of("month").to_format("yyyy-MM-dd HH:mm:ss")
     );
     println!(
         "  End of day: {}",
-        now.clone()
-            .end_of("day")
-            .to_format("yyyy-MM-dd HH:mm:ss.SSS")
+        now.end_of("day").to_format("yyyy-MM-dd HH:mm:ss.SSS")
     );
 }
@@ -46,8 +46,8 @@ fn main() {
     }
 
     let start = dt();
-    let end = start.clone().plus(&Duration::from_object(&[("days", 30)]));
-    let interval = Interval::from_date_times(start.clone(), end);
+    let end = start.plus(&Duration::from_object(&[("days", 30)]));
+    let interval = Interval::from_date_times(start, end);
 
     println!("Interval example (next 30 days):");
     println!("  Start: {}", start.to_format("MMM d, yyyy"));
@@ -7,13 +7,13 @@ fn main() {
     println!("Now (UTC): {}", now.to_iso());
     println!("Formatted: {}", now.to_format("MMMM do, yyyy - h:mm a"));
 
-    let tomorrow = now.clone().plus(&Duration::from_object(&[("days", 1)]));
+    let tomorrow = now.plus(&Duration::from_object(&[("days", 1)]));
     println!("\nTomorrow: {}", tomorrow.to_format("yyyy-MM-dd"));
 
-    let start = now.clone().start_of("day");
+    let start = now.start_of("day");
     println!("Start of day: {}", start.to_iso());
 
-    let end = now.clone().end_of("day");
+    let end = now.end_of("day");
     println!("End of day: {}", end.to_iso());
 
     let past = dt().minus(&Duration::from_object(&[("days", 7)]));
@@ -0,0 +1,308 @@
+// Calendar primitives shared by the chrono and zero-deps backends. Nothing in
+// here depends on a particular DateTime representation, so the types can be
+// used for configuration (see `Settings`) as well as returned from accessors.
+
+/// A day of the week, Monday first as in ISO-8601.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    /// ISO-8601 weekday number: 1 for Monday through 7 for Sunday.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::Weekday;
+    ///
+    /// assert_eq!(Weekday::Monday.number(), 1);
+    /// assert_eq!(Weekday::Sunday.number(), 7);
+    /// ```
+    pub const fn number(self) -> u32 {
+        self as u32 + 1
+    }
+
+    // Days counted from Monday (0 = Monday), the convention used by the
+    // formatter helpers and chrono's `num_days_from_monday`.
+    pub(crate) const fn from_monday0(n: u32) -> Self {
+        match n % 7 {
+            0 => Weekday::Monday,
+            1 => Weekday::Tuesday,
+            2 => Weekday::Wednesday,
+            3 => Weekday::Thursday,
+            4 => Weekday::Friday,
+            5 => Weekday::Saturday,
+            _ => Weekday::Sunday,
+        }
+    }
+
+    const fn bit(self) -> u8 {
+        1 << (self as u8)
+    }
+}
+
+/// The set of weekdays treated as the weekend.
+///
+/// The default is Saturday–Sunday. Use [`Weekend::FRIDAY_SATURDAY`] or
+/// [`Weekend::new`] for other conventions, and install the result process-wide
+/// with [`Settings::set_weekend`](crate::Settings::set_weekend).
+///
+/// # Examples
+///
+/// ```rust
+/// use tempotime::{Weekday, Weekend};
+///
+/// let weekend = Weekend::new(&[Weekday::Friday, Weekday::Saturday]);
+/// assert_eq!(weekend, Weekend::FRIDAY_SATURDAY);
+/// assert!(weekend.contains(Weekday::Friday));
+/// assert!(!weekend.contains(Weekday::Sunday));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Weekend {
+    mask: u8,
+}
+
+impl Weekend {
+    /// Saturday and Sunday (the default).
+    pub const SATURDAY_SUNDAY: Weekend = Weekend::new(&[Weekday::Saturday, Weekday::Sunday]);
+    /// Friday and Saturday, common across much of the Middle East.
+    pub const FRIDAY_SATURDAY: Weekend = Weekend::new(&[Weekday::Friday, Weekday::Saturday]);
+    /// Sunday only.
+    pub const SUNDAY: Weekend = Weekend::new(&[Weekday::Sunday]);
+
+    /// Builds a weekend from an arbitrary list of days. Duplicates are ignored.
+    pub const fn new(days: &[Weekday]) -> Self {
+        let mut mask = 0u8;
+        let mut i = 0;
+        while i < days.len() {
+            mask |= days[i].bit();
+            i += 1;
+        }
+        Weekend { mask }
+    }
+
+    /// Returns `true` if `day` is part of this weekend.
+    pub const fn contains(&self, day: Weekday) -> bool {
+        self.mask & day.bit() != 0
+    }
+
+    pub(crate) const fn to_bits(self) -> u8 {
+        self.mask
+    }
+
+    pub(crate) const fn from_bits(mask: u8) -> Self {
+        Weekend { mask: mask & 0x7f }
+    }
+}
+
+impl Default for Weekend {
+    fn default() -> Self {
+        Weekend::SATURDAY_SUNDAY
+    }
+}
+
+/// How weeks are numbered within a year.
+///
+/// A rule is the day weeks start on plus the minimum number of days of the new
+/// year that week 1 must contain. [`WeekRule::ISO`] (Monday, 4 days — the week
+/// containing January 4th) is the default; [`WeekRule::US`] (Sunday, 1 day — the
+/// week containing January 1st) matches US retail and reporting calendars.
+///
+/// # Examples
+///
+/// ```rust
+/// use tempotime::{DateTime, WeekRule};
+///
+/// // Sunday 2023-01-01 is the last day of ISO week 52 of 2022, but opens US week 1.
+/// let dt = DateTime::from_iso("2023-01-01T12:00:00Z").unwrap();
+/// assert_eq!(dt.week_number_with(WeekRule::ISO), 52);
+/// assert_eq!(dt.week_year_with(WeekRule::ISO), 2022);
+/// assert_eq!(dt.week_number_with(WeekRule::US), 1);
+/// assert_eq!(dt.week_year_with(WeekRule::US), 2023);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WeekRule {
+    first_day: Weekday,
+    minimal_days: u8,
+}
+
+impl WeekRule {
+    /// ISO-8601 weeks: start on Monday, week 1 contains January 4th.
+    pub const ISO: WeekRule = WeekRule::new(Weekday::Monday, 4);
+    /// US weeks: start on Sunday, week 1 contains January 1st.
+    pub const US: WeekRule = WeekRule::new(Weekday::Sunday, 1);
+
+    /// Builds a rule from the first day of the week and the minimum number of
+    /// days (1–7, clamped) week 1 must have in the new year.
+    pub const fn new(first_day: Weekday, minimal_days: u8) -> Self {
+        let minimal_days = if minimal_days < 1 {
+            1
+        } else if minimal_days > 7 {
+            7
+        } else {
+            minimal_days
+        };
+        WeekRule {
+            first_day,
+            minimal_days,
+        }
+    }
+
+    /// The day each week starts on.
+    pub const fn first_day(&self) -> Weekday {
+        self.first_day
+    }
+
+    /// The minimum number of days week 1 must have in the new year.
+    pub const fn minimal_days(&self) -> u8 {
+        self.minimal_days
+    }
+
+    // Position of `days` within its week under this rule (0 = first day).
+    fn day_in_week(&self, days: i64) -> i64 {
+        (weekday_from_days(days) as i64 - self.first_day as i64).rem_euclid(7)
+    }
+
+    // Day number on which week 1 of `year` starts.
+    fn week_one_start(&self, year: i32) -> i64 {
+        let jan1 = days_from_civil(year, 1, 1);
+        let start = jan1 - self.day_in_week(jan1);
+        if 7 - (jan1 - start) >= self.minimal_days as i64 {
+            start
+        } else {
+            start + 7
+        }
+    }
+
+    // Number of weeks (52 or 53) in a week-year.
+    pub(crate) fn weeks_in_year(&self, week_year: i32) -> u32 {
+        ((self.week_one_start(week_year + 1) - self.week_one_start(week_year)) / 7) as u32
+    }
+
+    // Calendar date for a week-year, week number and weekday, or `None` if the
+    // week-year has no such week.
+    pub(crate) fn date_from_week(
+        &self,
+        week_year: i32,
+        week: u32,
+        weekday: Weekday,
+    ) -> Option<(i32, u32, u32)> {
+        if week < 1 || week > self.weeks_in_year(week_year) {
+            return None;
+        }
+        let offset = (weekday as i64 - self.first_day as i64).rem_euclid(7);
+        let days = self.week_one_start(week_year) + (week as i64 - 1) * 7 + offset;
+        Some(civil_from_days(days))
+    }
+
+    // (week-year, week number) for a calendar date.
+    pub(crate) fn week_date(&self, year: i32, month: u32, day: u32) -> (i32, u32) {
+        let days = days_from_civil(year, month, day);
+        let week_year = if days < self.week_one_start(year) {
+            year - 1
+        } else if days >= self.week_one_start(year + 1) {
+            year + 1
+        } else {
+            year
+        };
+        let week = (days - self.week_one_start(week_year)) / 7 + 1;
+        (week_year, week as u32)
+    }
+}
+
+impl Default for WeekRule {
+    fn default() -> Self {
+        WeekRule::ISO
+    }
+}
+
+// Days since 1970-01-01 for a proleptic Gregorian date (Howard Hinnant's
+// `days_from_civil`).
+pub(crate) const fn days_from_civil(year: i32, month: u32, day: u32) -> i64 {
+    let y = year as i64 - if month <= 2 { 1 } else { 0 };
+    let m = month as i64;
+    let d = day as i64;
+    let era = if y >= 0 { y / 400 } else { (y - 399) / 400 };
+    let yoe = y - era * 400;
+    let doy = (153 * (m + if m > 2 { -3 } else { 9 }) + 2) / 5 + d - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+// Inverse of `days_from_civil`.
+pub(crate) fn civil_from_days(mut z: i64) -> (i32, u32, u32) {
+    z += 719468;
+    let era = if z >= 0 {
+        z / 146097
+    } else {
+        (z - 146096) / 146097
+    };
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let y = yoe + era * 400;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = doy - (153 * mp + 2) / 5 + 1;
+    let m = mp + if mp < 10 { 3 } else { -9 };
+    let year = (y + (m <= 2) as i64) as i32;
+    let month = m as u32;
+    (year, month, d as u32)
+}
+
+pub(crate) const fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0) && (year % 100 != 0 || year % 400 == 0)
+}
+
+pub(crate) const fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        1 => 31,
+        2 => {
+            if is_leap_year(year) {
+                29
+            } else {
+                28
+            }
+        }
+        3 => 31,
+        4 => 30,
+        5 => 31,
+        6 => 30,
+        7 => 31,
+        8 => 31,
+        9 => 30,
+        10 => 31,
+        11 => 30,
+        12 => 31,
+        _ => 30,
+    }
+}
+
+// Day of the year, 1-based.
+pub(crate) fn day_of_year(year: i32, month: u32, day: u32) -> u32 {
+    (days_from_civil(year, month, day) - days_from_civil(year, 1, 1)) as u32 + 1
+}
+
+// Month and day for a 1-based day of the year, or `None` past the year's end.
+pub(crate) fn month_day_from_ordinal(year: i32, ordinal: u32) -> Option<(u32, u32)> {
+    let mut left = ordinal.checked_sub(1)?;
+    for month in 1..=12 {
+        let len = days_in_month(year, month);
+        if left < len {
+            return Some((month, left + 1));
+        }
+        left -= len;
+    }
+    None
+}
+
+pub(crate) fn weekday_from_days(days: i64) -> Weekday {
+    // 1970-01-01 was a Thursday (index 3 counting from Monday).
+    Weekday::from_monday0((days + 3).rem_euclid(7) as u32)
+}
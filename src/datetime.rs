@@ -1,50 +1,9 @@
 #[cfg(feature = "chrono")]
 use chrono::{DateTime as ChronoDateTime, Datelike, TimeZone, Timelike, Utc};
-#[cfg(feature = "tz")]
-use chrono_tz::Tz;
 
 #[cfg(not(feature = "chrono"))]
 use std::time::{SystemTime, UNIX_EPOCH};
 
-// For zero-deps (no `tz`) builds provide a small built-in mapping of common
-// timezone names to fixed offsets (seconds east of UTC). This is intentionally
-// small and does not attempt to model DST — it's a convenience for simple
-// localized displays. We implement lookup with a compact match-based function
-// (case-insensitive ASCII) to avoid heap allocations and any extra runtime
-// dependencies.
-#[cfg(not(feature = "tz"))]
-fn zone_offset_seconds(zone: &str) -> Option<i32> {
-    // Compare case-insensitively without allocating: iterate bytes and fold to
-    // ascii-lowercase for comparison.
-    fn eq_ci(a: &str, b: &str) -> bool {
-        if a.len() != b.len() { return false; }
-        a.as_bytes()
-            .iter()
-            .zip(b.as_bytes())
-            .all(|(&ac, &bc)| ac.to_ascii_lowercase() == bc.to_ascii_lowercase())
-    }
-
-    // List of common zones and their fixed offsets (seconds east of UTC).
-    // Keep ordered by popularity to make linear scan cheap in practice.
-    let candidates: &[(&str, i32)] = &[
-        ("UTC", 0),
-        ("Europe/London", 0),
-        ("America/New_York", -5 * 3600),
-        ("America/Los_Angeles", -8 * 3600),
-        ("Europe/Paris", 1 * 3600),
-        ("Asia/Tokyo", 9 * 3600),
-        ("Asia/Shanghai", 8 * 3600),
-        ("Australia/Sydney", 10 * 3600),
-        ("Asia/Kolkata", 5 * 3600 + 30 * 60),
-        ("America/Sao_Paulo", -3 * 3600),
-    ];
-
-    for (name, off) in candidates.iter() {
-        if eq_ci(zone, name) { return Some(*off); }
-    }
-    None
-}
-
 #[cfg(feature = "serde")]
 impl Serialize for DateTime {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -65,13 +24,138 @@ impl<'de> Deserialize<'de> for DateTime {
     }
 }
 
+use std::collections::HashMap;
+
+use crate::calendar::{WeekRule, Weekday};
 use crate::duration::Duration;
-#[cfg(feature = "chrono")]
-use crate::format::format_datetime;
-use crate::locale;
+use crate::error::Error;
+use crate::format::{Item, Tokens};
+use crate::interval::Interval;
+use crate::locale::{self, Locale};
+use crate::options::{
+    Disambiguation, IsoOptions, LocaleOptions, Overflow, ParseOptions, RelativeOptions, SetOptions,
+    SetZoneOptions,
+};
+use crate::settings::Settings;
+use crate::unit::Unit;
+use crate::zone::Zone;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
+// Supported instants: years -9999 through 9999 in UTC. The bounds are
+// shared by both backends so the same input is accepted or rejected everywhere.
+const MIN_YEAR: i32 = -9999;
+const MAX_YEAR: i32 = 9999;
+pub(crate) const MIN_MS: i64 = crate::calendar::days_from_civil(MIN_YEAR, 1, 1) * 86_400_000;
+pub(crate) const MAX_MS: i64 =
+    crate::calendar::days_from_civil(MAX_YEAR + 1, 1, 1) * 86_400_000 - 1;
+
+// Reads a UTC offset for the `Z` parse tokens at byte `ix`: `Z`, `±H`, `±HH`,
+// `±HH:MM` or `±HHMM`, optionally after `UTC` or `GMT` (which alone mean +00:00).
+// Returns the offset in seconds east of UTC and the number of bytes consumed.
+pub(crate) fn parse_offset(input: &str, ix: usize) -> Result<(i32, usize), Error> {
+    let bytes = &input.as_bytes()[ix..];
+    let prefixed = bytes.len() >= 3
+        && (bytes[..3].eq_ignore_ascii_case(b"UTC") || bytes[..3].eq_ignore_ascii_case(b"GMT"));
+    let mut i = if prefixed { 3 } else { 0 };
+    let sign = match bytes.get(i) {
+        Some(b'+') => 1,
+        Some(b'-') => -1,
+        Some(b'Z' | b'z') if !prefixed => return Ok((0, 1)),
+        _ if prefixed => return Ok((0, 3)),
+        _ => {
+            return Err(Error::Invalid {
+                what: "offset",
+                position: ix,
+            })
+        }
+    };
+    i += 1;
+    let digits = |from: usize| {
+        bytes[from..]
+            .iter()
+            .take_while(|b| b.is_ascii_digit())
+            .count()
+    };
+    let number = |from: usize, len: usize| {
+        bytes[from..from + len]
+            .iter()
+            .fold(0, |acc, b| acc * 10 + (b - b'0') as i32)
+    };
+    let (hours, minutes) = match digits(i) {
+        4 => {
+            i += 4;
+            (number(i - 4, 2), number(i - 2, 2))
+        }
+        n @ (1 | 2) if bytes.get(i + n) == Some(&b':') && digits(i + n + 1) == 2 => {
+            i += n + 3;
+            (number(i - n - 3, n), number(i - 2, 2))
+        }
+        n @ (1 | 2) => {
+            i += n;
+            (number(i - n, n), 0)
+        }
+        _ => {
+            return Err(Error::Invalid {
+                what: "offset",
+                position: ix,
+            })
+        }
+    };
+    if hours > 23 || minutes > 59 {
+        return Err(Error::Invalid {
+            what: "offset",
+            position: ix,
+        });
+    }
+    Ok((sign * (hours * 3600 + minutes * 60), i))
+}
+
+// Reads between `min` and `max` ASCII digits at byte `ix` for a numeric
+// `from_format` token, returning the value and the number of digits used.
+fn read_digits(
+    input: &str,
+    ix: usize,
+    (min, max): (usize, usize),
+    what: &'static str,
+) -> Result<(u32, usize), Error> {
+    let run = input.as_bytes()[ix..]
+        .iter()
+        .take(max)
+        .take_while(|b| b.is_ascii_digit())
+        .count();
+    if run < min {
+        return Err(if ix + run == input.len() {
+            Error::UnexpectedEnd {
+                position: input.len(),
+            }
+        } else {
+            Error::Invalid { what, position: ix }
+        });
+    }
+    Ok((input[ix..ix + run].parse().unwrap_or(0), run))
+}
+
+// Parses an unsigned run of ASCII digits at compile time, for `datetime!`.
+const fn const_digits(s: &str) -> u32 {
+    let bytes = s.as_bytes();
+    if bytes.is_empty() || bytes.len() > 4 {
+        panic!("expected 1 to 4 digits");
+    }
+    let mut value = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_digit() {
+            panic!("expected digits");
+        }
+        value = value * 10 + (bytes[i] - b'0') as u32;
+        i += 1;
+    }
+    value
+}
+// Julian day number of 1970-01-01T00:00:00Z.
+const UNIX_EPOCH_JULIAN_DAY: f64 = 2_440_587.5;
+
 /// A date and time value with optional timezone support.
 ///
 /// `DateTime` is **immutable** – all operations return new instances, making it safe to share
@@ -109,13 +193,13 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 /// let dt = DateTime::now();
 ///
 /// // Add duration
-/// let future = dt.clone().plus(&Duration::from_object(&[("days", 7), ("hours", 3)]));
+/// let future = dt.plus(&Duration::from_object(&[("days", 7), ("hours", 3)]));
 ///
 /// // Subtract duration
-/// let past = dt.clone().minus(&Duration::from_object(&[("weeks", 2)]));
+/// let past = dt.minus(&Duration::from_object(&[("weeks", 2)]));
 ///
 /// // Round to start/end of unit
-/// let start_of_day = dt.clone().start_of("day");     // Sets time to 00:00:00.000
+/// let start_of_day = dt.start_of("day");     // Sets time to 00:00:00.000
 /// let end_of_month = dt.end_of("month");     // Last millisecond of month
 /// ```
 ///
@@ -153,25 +237,114 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 ///     println!("Now is after past");
 /// }
 /// ```
-#[derive(Debug, Clone, PartialEq)]
+///
+/// Equality, ordering and hashing compare the underlying instant only: the same
+/// moment seen from two different zones is `==`.
+///
+/// The supported range is years -9999 through 9999 (UTC). Constructors reject
+/// anything outside it, [`plus`](DateTime::plus) and [`minus`](DateTime::minus)
+/// clamp to it, and [`checked_plus`](DateTime::checked_plus) /
+/// [`checked_minus`](DateTime::checked_minus) return `None` rather than leaving it.
+///
+/// `DateTime` is `Copy` (an instant plus an optional zone), so methods that take
+/// `self` never need a `.clone()` to keep using the original.
+#[derive(Debug, Clone, Copy)]
 pub struct DateTime {
+    // The instant, always stored in UTC.
     #[cfg(feature = "chrono")]
     inner: ChronoDateTime<Utc>,
     #[cfg(not(feature = "chrono"))]
     timestamp_ms: i64,
-    #[cfg(feature = "tz")]
-    zone: Option<Tz>,
-    // In zero-deps builds we support a small static zone map via set_zone()
-    #[cfg(not(feature = "tz"))]
-    _zone_applied: bool,
+    // Zone used to read the instant back as wall-clock fields. With `tz` this
+    // is an IANA zone; otherwise an entry from the small static zone map.
+    zone: Option<Zone>,
+}
+
+// A zone from a provider points back at it; make sure that never costs
+// `DateTime` its auto traits.
+const _: () = {
+    fn assert_auto_traits<T: Send + Sync + std::panic::UnwindSafe + std::panic::RefUnwindSafe>() {}
+    let _ = assert_auto_traits::<DateTime>;
+};
+
+// Equality, ordering and hashing all look at the instant only, so the same
+// moment viewed from two zones compares equal in every build mode.
+impl PartialEq for DateTime {
+    fn eq(&self, other: &Self) -> bool {
+        #[cfg(feature = "chrono")]
+        return self.inner == other.inner;
+        #[cfg(not(feature = "chrono"))]
+        return self.timestamp_ms == other.timestamp_ms;
+    }
+}
+
+impl Eq for DateTime {}
+
+impl std::hash::Hash for DateTime {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        #[cfg(feature = "chrono")]
+        self.inner.hash(state);
+        #[cfg(not(feature = "chrono"))]
+        self.timestamp_ms.hash(state);
+    }
 }
 
 impl PartialOrd for DateTime {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for DateTime {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
         #[cfg(feature = "chrono")]
-        return self.inner.partial_cmp(&other.inner);
+        return self.inner.cmp(&other.inner);
         #[cfg(not(feature = "chrono"))]
-        return self.timestamp_ms.partial_cmp(&other.timestamp_ms);
+        return self.timestamp_ms.cmp(&other.timestamp_ms);
+    }
+}
+
+// Operators forward to `plus`/`minus`, so calendar units follow the same
+// wall-clock rules (e.g. month-end clamping) as the named methods.
+impl std::ops::Add<Duration> for DateTime {
+    type Output = DateTime;
+
+    fn add(self, dur: Duration) -> DateTime {
+        self.plus(&dur)
+    }
+}
+
+impl std::ops::Add<&Duration> for DateTime {
+    type Output = DateTime;
+
+    fn add(self, dur: &Duration) -> DateTime {
+        self.plus(dur)
+    }
+}
+
+impl std::ops::Sub<Duration> for DateTime {
+    type Output = DateTime;
+
+    fn sub(self, dur: Duration) -> DateTime {
+        self.minus(&dur)
+    }
+}
+
+impl std::ops::Sub<&Duration> for DateTime {
+    type Output = DateTime;
+
+    fn sub(self, dur: &Duration) -> DateTime {
+        self.minus(dur)
+    }
+}
+
+/// The elapsed time between two instants, in milliseconds; negative when
+/// `other` is later. Use [`Duration::shift_to`] to re-express it in larger units.
+impl std::ops::Sub<DateTime> for DateTime {
+    type Output = Duration;
+
+    fn sub(self, other: DateTime) -> Duration {
+        Duration::from_object(&[("milliseconds", self.to_millis() - other.to_millis())])
     }
 }
 
@@ -209,23 +382,23 @@ impl DateTime {
     /// ```
     #[cfg(feature = "chrono")]
     pub fn now() -> Self {
-        DateTime {
-            inner: Utc::now(),
-            #[cfg(feature = "tz")]
-            zone: None,
-            #[cfg(not(feature = "tz"))]
-            _zone_applied: false,
+        if let Some(ms) = Settings::clock_millis() {
+            return Self::from_epoch_ms(ms);
         }
+        // Millisecond precision, as in zero-deps mode and `from_iso`.
+        Self::from_epoch_ms(Utc::now().timestamp_millis())
     }
 
     #[cfg(not(feature = "chrono"))]
     pub fn now() -> Self {
+        if let Some(ms) = Settings::clock_millis() {
+            return Self::from_epoch_ms(ms);
+        }
         let now = SystemTime::now();
         let duration = now.duration_since(UNIX_EPOCH).expect("Time went backwards");
         DateTime {
             timestamp_ms: duration.as_millis() as i64,
-            #[cfg(not(feature = "tz"))]
-            _zone_applied: false,
+            zone: None,
         }
     }
 
@@ -253,14 +426,10 @@ impl DateTime {
     /// Enable the `chrono` feature for proper local timezone detection.
     #[cfg(feature = "chrono")]
     pub fn local() -> Self {
-        let local = chrono::Local::now();
-        DateTime {
-            inner: local.with_timezone(&Utc),
-            #[cfg(feature = "tz")]
-            zone: None,
-            #[cfg(not(feature = "tz"))]
-            _zone_applied: false,
+        if let Some(ms) = Settings::clock_millis() {
+            return Self::from_epoch_ms(ms);
         }
+        Self::from_epoch_ms(chrono::Local::now().timestamp_millis())
     }
 
     #[cfg(not(feature = "chrono"))]
@@ -270,7 +439,14 @@ impl DateTime {
 
     /// Parses a DateTime from an ISO 8601 formatted string.
     ///
-    /// Supports standard ISO 8601 format: `YYYY-MM-DDTHH:MM:SS[.mmm]Z`
+    /// Supports the extended ISO 8601 format `YYYY-MM-DDTHH:MM:SS[.mmm]Z`, with
+    /// a `±HH:MM` offset in place of `Z` (the instant is converted to UTC) or no
+    /// designator at all (read as UTC). The date may also be an ordinal date
+    /// (`YYYY-DDD`) or a week date (`YYYY-Www-D`), and the time may be omitted
+    /// for midnight. The compact basic format (`20251030T143000Z`) is read as
+    /// well. Years before 1 CE use a sign and
+    /// astronomical numbering, e.g. `-0044` for 45 BCE. Both build modes accept
+    /// exactly the same strings.
     ///
     /// # Arguments
     ///
@@ -279,7 +455,7 @@ impl DateTime {
     /// # Returns
     ///
     /// * `Ok(DateTime)` - Successfully parsed datetime
-    /// * `Err(String)` - Parse error with description
+    /// * `Err(Error)` - Parse error with its position
     ///
     /// # Examples
     ///
@@ -293,676 +469,2923 @@ impl DateTime {
     /// // With milliseconds
     /// let dt2 = DateTime::from_iso("2025-10-30T14:30:00.123Z").unwrap();
     /// assert_eq!(dt2.to_format("SSS"), "123");
+    ///
+    /// // Basic (compact) format
+    /// let dt3 = DateTime::from_iso("20251030T143000Z").unwrap();
+    /// assert_eq!(dt3, dt);
+    ///
+    /// // Negative (proleptic Gregorian) years
+    /// let ides = DateTime::from_iso("-0044-03-15T00:00:00Z").unwrap();
+    /// assert_eq!(ides.to_format("yyyy-MM-dd G"), "-0044-03-15 BC");
     /// ```
     ///
     /// # Errors
     ///
     /// Returns an error if the string is not valid ISO 8601 format or represents
     /// an invalid date/time.
-    #[cfg(feature = "chrono")]
-    pub fn from_iso(s: &str) -> Result<Self, String> {
-        s.parse::<ChronoDateTime<Utc>>()
-            .map(|dt| DateTime {
-                inner: dt,
-                #[cfg(feature = "tz")]
-                zone: None,
-                #[cfg(not(feature = "tz"))]
-                _zone_applied: false,
-            })
-            .map_err(|e| format!("Invalid ISO date: {}", e))
+    pub fn from_iso(s: &str) -> Result<Self, Error> {
+        Self::from_fields(crate::iso::parse(s)?)
     }
 
-    #[cfg(not(feature = "chrono"))]
-    pub fn from_iso(s: &str) -> Result<Self, String> {
-        let s = s.trim();
-        if s.len() < 19 {
-            return Err("ISO string too short".to_string());
-        }
-
-        let year: i32 = s[0..4].parse().map_err(|_| "Invalid year")?;
-        let month: u32 = s[5..7].parse().map_err(|_| "Invalid month")?;
-        let day: u32 = s[8..10].parse().map_err(|_| "Invalid day")?;
-        let hour: u32 = s[11..13].parse().map_err(|_| "Invalid hour")?;
-        let minute: u32 = s[14..16].parse().map_err(|_| "Invalid minute")?;
-        let second: u32 = s[17..19].parse().map_err(|_| "Invalid second")?;
-
-        let timestamp_ms = Self::compute_timestamp(year, month, day, hour, minute, second, 0);
-        Ok(DateTime { timestamp_ms, #[cfg(not(feature = "tz"))] _zone_applied: false })
+    /// Like [`from_iso`](Self::from_iso), but out-of-range calendar
+    /// components roll over into the next larger unit instead of failing:
+    /// month 13 is January of the next year, October 45th is November 14th,
+    /// and `T25:61:00` is 02:01 the next day.
+    ///
+    /// The string must still be well-formed ISO 8601, and ordinal and week
+    /// dates are validated as usual.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::DateTime;
+    ///
+    /// assert!(DateTime::from_iso("2025-13-45T99:99:99Z").is_err());
+    ///
+    /// let dt = DateTime::from_iso_lenient("2025-13-45T99:99:99Z").unwrap();
+    /// assert_eq!(dt.to_format("yyyy-MM-dd HH:mm:ss"), "2026-02-18 04:40:39");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string is not well-formed or the normalized
+    /// instant falls outside years -9999 to 9999.
+    pub fn from_iso_lenient(s: &str) -> Result<Self, Error> {
+        let f = crate::iso::parse(s)?;
+        let months = f.year as i64 * 12 + f.month as i64 - 1;
+        let year = i32::try_from(months.div_euclid(12)).map_err(|_| Error::OutOfRange)?;
+        let month = months.rem_euclid(12) as u32 + 1;
+        let days = crate::calendar::days_from_civil(year, month, 1) + f.day as i64 - 1;
+        let secs = f.hour as i64 * 3600 + f.minute as i64 * 60 + f.second as i64
+            - f.offset_secs.unwrap_or(0) as i64;
+        Self::from_millis(days * 86_400_000 + secs * 1000 + f.millis as i64)
     }
 
-    pub fn from_format(s: &str, fmt: &str) -> Result<Self, String> {
-        // Simple parser for patterns similar to to_format tokens.
-        // Supported tokens: yyyy, yy, MMMM, MMM, MM, M, dd, d, do, H/H H, HH, h/h hh, m/mm, s/ss, SSS, a
-        let input = s;
-        let mut ix: usize = 0;
-        let mut year: Option<i32> = None;
-        let mut month: Option<u32> = None;
-        let mut day: Option<u32> = None;
-        let mut hour: Option<u32> = None;
-        let mut minute: Option<u32> = None;
-        let mut second: Option<u32> = None;
-        let mut millis: Option<u32> = None;
-        let mut pm = false;
+    /// Parses a SQL timestamp literal as printed by Postgres or MySQL, like
+    /// Luxon's `fromSQL`.
+    ///
+    /// Accepts `YYYY-MM-DD`, optionally followed by ` HH:MM:SS[.fff]` and an
+    /// offset (`Z`, `+02`, `+02:00`, optionally after a space). An offset is
+    /// converted to UTC; without one the value is read as UTC.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::DateTime;
+    ///
+    /// let a = DateTime::from_sql("2025-10-30 14:30:00.123").unwrap();
+    /// assert_eq!(a.to_format("HH:mm:ss.SSS"), "14:30:00.123");
+    ///
+    /// let b = DateTime::from_sql("2025-10-30 16:30:00+02").unwrap();
+    /// assert_eq!(b, DateTime::from_iso("2025-10-30T14:30:00Z").unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for malformed input or components out of range.
+    pub fn from_sql(s: &str) -> Result<Self, Error> {
+        Self::from_fields(crate::iso::parse_sql(s)?)
+    }
 
-        let mut chars = fmt.chars().peekable();
-        while let Some(ch) = chars.next() {
-            match ch {
-                '\'' => {
-                    // literal until next '\''; support escape of single-quote via doubled '' per common patterns
-                    let mut lit = String::new();
-                    loop {
-                        match chars.next() {
-                            Some(c2) => {
-                                if c2 == '\'' {
-                                    // if next char is also a single-quote, that's an escaped quote -> append one and continue
-                                    if chars.peek() == Some(&'\'') {
-                                        // consume the escaped quote and append a single quote
-                                        chars.next();
-                                        lit.push('\'');
-                                        continue;
-                                    }
-                                    // otherwise it's the closing quote
-                                    break;
-                                }
-                                lit.push(c2);
-                            }
-                            None => {
-                                return Err("Unterminated literal in format string".to_string());
-                            }
-                        }
-                    }
-                    // match literal in input at current position
-                    if input.get(ix..).map_or(false, |s| s.starts_with(&lit)) {
-                        ix += lit.len();
-                    } else {
-                        return Err(format!("Literal '{}' not found at input position {}", lit, ix));
-                    }
-                }
-                'y' => {
-                    let count = 1 + chars.clone().take_while(|&c| c == 'y').count();
-                    for _ in 1..count { chars.next(); }
-                    if count >= 4 {
-                        if ix + 4 > input.len() { return Err("Unexpected end while parsing year".to_string()); }
-                        let v: i32 = input[ix..ix+4].parse().map_err(|_| "Invalid year")?;
-                        year = Some(v);
-                        ix += 4;
-                    } else {
-                        if ix + 2 > input.len() { return Err("Unexpected end while parsing year".to_string()); }
-                        let v: i32 = input[ix..ix+2].parse().map_err(|_| "Invalid year")?;
-                        // two-digit year: assume 2000-2099 for simplicity
-                        year = Some(2000 + v);
-                        ix += 2;
-                    }
-                }
-                'M' => {
-                    let count = 1 + chars.clone().take_while(|&c| c == 'M').count();
-                    for _ in 1..count { chars.next(); }
-                    if count >= 4 {
-                        // full month name - try matching any month name (case-insensitive)
-                        let names = ["January","February","March","April","May","June","July","August","September","October","November","December"];
-                        let mut matched = None;
-                        for (i,name) in names.iter().enumerate() {
-                            let nl = name.len();
-                            if input.len() >= ix + nl && input[ix..ix+nl].eq_ignore_ascii_case(name) {
-                                matched = Some((i+1) as u32);
-                                ix += nl;
-                                break;
-                            }
-                        }
-                        if matched.is_none() { return Err("Month name not found".to_string()); }
-                        month = matched;
-                    } else if count == 3 {
-                        let names = ["Jan","Feb","Mar","Apr","May","Jun","Jul","Aug","Sep","Oct","Nov","Dec"];
-                        let mut matched = None;
-                        for (i,name) in names.iter().enumerate() {
-                            let nl = name.len();
-                            if input.len() >= ix + nl && input[ix..ix+nl].eq_ignore_ascii_case(name) {
-                                matched = Some((i+1) as u32);
-                                ix += nl;
-                                break;
-                            }
-                        }
-                        if matched.is_none() { return Err("Short month name not found".to_string()); }
-                        month = matched;
-                    } else {
-                        // numeric month
-                        let _digits = if count == 2 {2} else {1};
-                        let mut parsed = None;
-                        // try 2-digit first if possible
-                        if count == 2 && ix + 2 <= input.len() {
-                            if let Ok(v) = input[ix..ix+2].parse::<u32>() { parsed = Some((v,2)); }
-                        }
-                        if parsed.is_none() {
-                            // try 1-digit
-                            if ix + 1 <= input.len() {
-                                if let Ok(v) = input[ix..ix+1].parse::<u32>() { parsed = Some((v,1)); }
-                            }
-                        }
-                        if let Some((v,len)) = parsed { month = Some(v); ix += len; } else { return Err("Invalid month number".to_string()); }
-                    }
-                }
-                'd' => {
-                    if chars.peek() == Some(&'o') {
-                        chars.next();
-                        // ordinal: digits followed by st/nd/rd/th
-                        let mut j = ix;
-                        while j < input.len() && input.as_bytes()[j].is_ascii_digit() { j += 1; }
-                        if j==ix { return Err("Expected day number".to_string()); }
-                        let v: u32 = input[ix..j].parse().map_err(|_| "Invalid day")?;
-                        // skip suffix letters
-                        let mut k = j;
-                        while k < input.len() && input.as_bytes()[k].is_ascii_alphabetic() { k += 1; }
-                        ix = k;
-                        day = Some(v);
-                    } else {
-                        let count = 1 + chars.clone().take_while(|&c| c == 'd').count();
-                        for _ in 1..count { chars.next(); }
-                        let len = if count>=2 {2} else {1};
-                        if ix + len > input.len() { return Err("Unexpected end while parsing day".to_string()); }
-                        let v: u32 = input[ix..ix+len].parse().map_err(|_| "Invalid day")?;
-                        day = Some(v);
-                        ix += len;
-                    }
-                }
-                'H' | 'h' => {
-                    let is_h = ch == 'h';
-                    let count = 1 + chars.clone().take_while(|&c| c == ch).count();
-                    for _ in 1..count { chars.next(); }
-                    let len = if count>=2 {2} else {1};
-                    if ix + len > input.len() { return Err("Unexpected end while parsing hour".to_string()); }
-                    let v: u32 = input[ix..ix+len].parse().map_err(|_| "Invalid hour")?;
-                    hour = Some(v);
-                    ix += len;
-                    if is_h {
-                        // will adjust based on am/pm
-                    }
-                }
-                'm' => {
-                    let count = 1 + chars.clone().take_while(|&c| c == 'm').count();
-                    for _ in 1..count { chars.next(); }
-                    let len = if count>=2 {2} else {1};
-                    if ix + len > input.len() { return Err("Unexpected end while parsing minute".to_string()); }
-                    let v: u32 = input[ix..ix+len].parse().map_err(|_| "Invalid minute")?;
-                    minute = Some(v);
-                    ix += len;
-                }
-                's' => {
-                    let count = 1 + chars.clone().take_while(|&c| c == 's').count();
-                    for _ in 1..count { chars.next(); }
-                    let len = if count>=2 {2} else {1};
-                    if ix + len > input.len() { return Err("Unexpected end while parsing second".to_string()); }
-                    let v: u32 = input[ix..ix+len].parse().map_err(|_| "Invalid second")?;
-                    second = Some(v);
-                    ix += len;
-                }
-                'S' => {
-                    let count = 1 + chars.clone().take_while(|&c| c == 'S').count();
-                    for _ in 1..count { chars.next(); }
-                    // parse milliseconds (up to 3 digits)
-                    let mut j = ix;
-                    while j < input.len() && input.as_bytes()[j].is_ascii_digit() { j += 1; }
-                    if j==ix { return Err("Expected millis".to_string()); }
-                    let txt = &input[ix..j];
-                    let mut v: u32 = txt.parse().map_err(|_| "Invalid millis")?;
-                    // normalize to milliseconds length
-                    if txt.len() == 1 { v *= 100; } else if txt.len() == 2 { v *= 10; }
-                    millis = Some(v);
-                    ix = j;
-                }
-                'a' => {
-                    // am/pm
-                    if input[ix..].to_lowercase().starts_with("am") { pm = false; ix += 2; }
-                    else if input[ix..].to_lowercase().starts_with("pm") { pm = true; ix += 2; }
-                    else { return Err("Expected am or pm".to_string()); }
-                }
-                other => {
-                    // expect literal char
-                    let c = other;
-                    if ix >= input.len() || input.as_bytes()[ix] as char != c { return Err(format!("Expected '{}'", c)); }
-                    ix += 1;
-                }
+    fn from_fields(f: crate::iso::IsoFields) -> Result<Self, Error> {
+        let offset = match f.offset_secs {
+            None | Some(0) => {
+                return Self::from_civil(
+                    f.year, f.month, f.day, f.hour, f.minute, f.second, f.millis,
+                )
             }
+            Some(offset) => offset,
+        };
+        // Near either end the wall clock can be a year outside the range
+        // while the instant is inside it, as in `+10000-01-01T08:59:59+09:00`.
+        if !(MIN_YEAR - 1..=MAX_YEAR + 1).contains(&f.year) {
+            return Err(Error::InvalidYear(f.year as i64));
         }
+        let wall_ms = Self::civil_ms(f.year, f.month, f.day, f.hour, f.minute, f.second, f.millis)?;
+        Self::from_millis(wall_ms - offset as i64 * 1000)
+    }
 
-        // fill defaults
-        let y = year.unwrap_or(1970);
-        let m = month.unwrap_or(1);
-        let d = day.unwrap_or(1);
-        let mut h = hour.unwrap_or(0);
-        let min = minute.unwrap_or(0);
-        let sec = second.unwrap_or(0);
-        let ms = millis.unwrap_or(0);
-        if let Some(_) = hour {
-            // if 12-hour clock and pm flag
-            if pm {
-                if h < 12 { h += 12; }
-            } else {
-                if h == 12 && fmt.contains('h') { h = 0; }
+    /// Creates a DateTime in UTC from named components, like Luxon's `DateTime.fromObject`.
+    ///
+    /// Recognised keys are `year`, `month`, `day`, `hour`, `minute`, `second`
+    /// and `millisecond` (plural spellings are accepted too). Missing components
+    /// default to the start of their range, with the year defaulting to 1970.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::DateTime;
+    ///
+    /// let fields = [("year", 2025), ("month", 3), ("day", 15), ("hour", 9)];
+    /// let dt = DateTime::from_object(&fields).unwrap();
+    /// assert_eq!(dt.to_format("yyyy-MM-dd HH:mm"), "2025-03-15 09:00");
+    ///
+    /// assert!(DateTime::from_object(&[("year", 2025), ("month", 2), ("day", 30)]).is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for unknown keys and for components outside their valid
+    /// range, including days past the end of the month.
+    pub fn from_object(obj: &[(&str, i64)]) -> Result<Self, Error> {
+        let mut year = 1970;
+        let mut parts: [i64; 6] = [1, 1, 0, 0, 0, 0];
+        for (key, value) in obj {
+            match *key {
+                "years" | "year" => year = *value,
+                "months" | "month" => parts[0] = *value,
+                "days" | "day" => parts[1] = *value,
+                "hours" | "hour" => parts[2] = *value,
+                "minutes" | "minute" => parts[3] = *value,
+                "seconds" | "second" => parts[4] = *value,
+                "milliseconds" | "millisecond" => parts[5] = *value,
+                other => return Err(Error::UnknownUnit(other.to_string())),
             }
         }
-
-        #[cfg(feature = "chrono")]
-        {
-            use chrono::Utc;
-            let naive = Utc.with_ymd_and_hms(y, m, d, h, min, sec).single().ok_or("Invalid date")?;
-            let dt = naive + chrono::Duration::milliseconds(ms as i64);
-            return Ok(DateTime { inner: dt, #[cfg(feature = "tz")] zone: None, #[cfg(not(feature = "tz"))] _zone_applied: false });
+        let year = i32::try_from(year).map_err(|_| Error::InvalidYear(year))?;
+        let mut fields = [0u32; 6];
+        for (field, value) in fields.iter_mut().zip(parts) {
+            // Negative values fail the range checks in `from_civil`.
+            *field = u32::try_from(value).unwrap_or(u32::MAX);
         }
+        let [month, day, hour, minute, second, millis] = fields;
+        Self::from_civil(year, month, day, hour, minute, second, millis)
+    }
 
-        #[cfg(not(feature = "chrono"))]
-        {
-            let ts = Self::compute_timestamp(y, m, d, h, min, sec, ms);
-            return Ok(DateTime { timestamp_ms: ts, #[cfg(feature = "tz")] zone: None, #[cfg(not(feature = "tz"))] _zone_applied: false });
-        }
+    /// Creates a DateTime in `zone` from named components, which are read as
+    /// wall-clock values there.
+    ///
+    /// Keys and defaults are as for [`from_object`](Self::from_object). A time
+    /// repeated or skipped by a DST change is resolved by `disambiguation`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::{DateTime, Disambiguation};
+    ///
+    /// let fields = [("year", 2025), ("month", 10), ("day", 30), ("hour", 9)];
+    /// let dt = DateTime::from_object_in(&fields, "Asia/Tokyo", Disambiguation::Reject).unwrap();
+    /// assert_eq!(dt.to_format("yyyy-MM-dd HH:mm"), "2025-10-30 09:00");
+    /// assert_eq!(dt, DateTime::from_iso("2025-10-30T00:00:00Z").unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`from_object`](Self::from_object),
+    /// [`Error::UnknownZone`] for an unrecognised zone name and, with
+    /// [`Disambiguation::Reject`], [`Error::AmbiguousLocalTime`] or
+    /// [`Error::NonexistentLocalTime`].
+    pub fn from_object_in(
+        obj: &[(&str, i64)],
+        zone: &str,
+        disambiguation: Disambiguation,
+    ) -> Result<Self, Error> {
+        let wall = Self::from_object(obj)?;
+        let zone = Zone::parse(zone).ok_or_else(|| Error::UnknownZone(zone.to_string()))?;
+        let instant_ms = zone.local_to_instant_ms(wall.to_millis(), disambiguation)?;
+        Ok(DateTime {
+            zone: Some(zone),
+            ..Self::from_millis(instant_ms)?
+        })
     }
 
-    #[cfg(feature = "tz")]
-    pub fn set_zone(mut self, zone: &str) -> Self {
-        if let Ok(tz) = zone.parse::<Tz>() {
-            self.zone = Some(tz);
-        }
-        self
+    /// Creates a DateTime in UTC from calendar date and wall-clock time.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::DateTime;
+    ///
+    /// let dt = DateTime::from_ymd_hms(2025, 10, 30, 14, 30, 0).unwrap();
+    /// assert_eq!(dt, DateTime::from_iso("2025-10-30T14:30:00Z").unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any component is out of range.
+    pub fn from_ymd_hms(
+        year: i32,
+        month: u32,
+        day: u32,
+        hour: u32,
+        minute: u32,
+        second: u32,
+    ) -> Result<Self, Error> {
+        Self::from_civil(year, month, day, hour, minute, second, 0)
     }
 
-    #[cfg(not(feature = "tz"))]
-    pub fn set_zone(self, _zone: &str) -> Self {
-        // Try to apply a static offset if the zone appears in our STATIC_ZONES map.
-        let mut out = self;
-        #[cfg(not(feature = "chrono"))]
-        {
-            if let Some(offset) = zone_offset_seconds(_zone) {
-                // offset is seconds east of UTC; applying offset shows local wall time
-                out.timestamp_ms = out.timestamp_ms + (offset as i64) * 1000;
-                out._zone_applied = true;
-            }
+    /// Creates a DateTime from milliseconds since the Unix epoch.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::DateTime;
+    ///
+    /// let dt = DateTime::from_millis(1_761_834_600_000).unwrap();
+    /// assert_eq!(dt.to_format("yyyy-MM-dd HH:mm"), "2025-10-30 14:30");
+    /// assert_eq!(dt.to_millis(), 1_761_834_600_000);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the instant falls outside years -9999 to 9999.
+    pub fn from_millis(ms: i64) -> Result<Self, Error> {
+        if !(MIN_MS..=MAX_MS).contains(&ms) {
+            return Err(Error::OutOfRange);
         }
-        out
+        Ok(Self::from_epoch_ms(ms))
     }
 
-    pub fn plus(self, dur: &Duration) -> Self {
-        let (years, months, weeks, days, hours, minutes, seconds, millis) = dur.components();
-        #[cfg(feature = "chrono")]
-        {
-            let mut dt = self.inner;
-
-            if years != 0 {
-                let new_year = dt.year() + years as i32;
-                dt = Utc
-                    .with_ymd_and_hms(
-                        new_year,
-                        dt.month(),
-                        dt.day(),
-                        dt.hour(),
-                        dt.minute(),
-                        dt.second(),
-                    )
-                    .single()
-                    .unwrap_or(dt);
-            }
-            if months != 0 {
-                let total_months = dt.month() as i32 + months as i32;
-                let new_month = ((total_months - 1).rem_euclid(12) + 1) as u32;
-                let year_offset = (total_months - 1).div_euclid(12);
-                let new_year = dt.year() + year_offset;
-                dt = Utc
-                    .with_ymd_and_hms(
-                        new_year,
-                        new_month,
-                        dt.day(),
-                        dt.hour(),
-                        dt.minute(),
-                        dt.second(),
-                    )
-                    .single()
-                    .unwrap_or(dt);
-            }
+    /// Creates a DateTime from milliseconds since the Unix epoch in a `const`
+    /// context, for compile-time constants such as cutoff dates. See also the
+    /// [`datetime!`](crate::datetime!) macro.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::DateTime;
+    ///
+    /// const CUTOFF: DateTime = DateTime::from_timestamp_ms(1_761_834_600_000);
+    /// assert_eq!(CUTOFF.to_format("yyyy-MM-dd HH:mm"), "2025-10-30 14:30");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the instant falls outside years -9999 to 9999. In a `const`
+    /// item this is a compile error; use [`from_millis`](Self::from_millis)
+    /// for runtime input.
+    pub const fn from_timestamp_ms(ms: i64) -> Self {
+        if ms < MIN_MS || ms > MAX_MS {
+            panic!("timestamp out of range");
+        }
+
+        #[cfg(feature = "chrono")]
+        {
+            match ChronoDateTime::<Utc>::from_timestamp_millis(ms) {
+                Some(inner) => DateTime { inner, zone: None },
+                None => panic!("timestamp out of range"),
+            }
+        }
+
+        #[cfg(not(feature = "chrono"))]
+        {
+            DateTime {
+                timestamp_ms: ms,
+                zone: None,
+            }
+        }
+    }
+
+    // Backs the `datetime!` macro, which has to reach it from other crates. The
+    // macro passes each literal through `stringify!` so that zero-padded parts
+    // like `08` are not linted as octal-looking constants at the call site.
+    #[doc(hidden)]
+    pub const fn __from_literal_parts(parts: [&str; 6]) -> Self {
+        let year = const_digits(parts[0]);
+        let [month, day, hour, minute, second] = [
+            const_digits(parts[1]),
+            const_digits(parts[2]),
+            const_digits(parts[3]),
+            const_digits(parts[4]),
+            const_digits(parts[5]),
+        ];
+        // At most four digits, so always within MIN_YEAR..=MAX_YEAR.
+        let year = year as i32;
+        if month < 1 || month > 12 {
+            panic!("invalid month");
+        }
+        if day < 1 || day > crate::calendar::days_in_month(year, month) {
+            panic!("invalid day");
+        }
+        if hour > 23 || minute > 59 || second > 59 {
+            panic!("invalid time");
+        }
+        let days = crate::calendar::days_from_civil(year, month, day);
+        let secs = (hour * 3600 + minute * 60 + second) as i64;
+        Self::from_timestamp_ms(days * 86_400_000 + secs * 1000)
+    }
+
+    /// Creates a DateTime from whole seconds since the Unix epoch.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the instant falls outside years -9999 to 9999.
+    pub fn from_seconds(secs: i64) -> Result<Self, Error> {
+        let ms = secs.checked_mul(1000).ok_or(Error::OutOfRange)?;
+        Self::from_millis(ms)
+    }
+
+    /// Creates a DateTime from fractional seconds since the Unix epoch, rounded
+    /// to the nearest millisecond.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::DateTime;
+    ///
+    /// let dt = DateTime::from_seconds_f64(1_761_834_600.25).unwrap();
+    /// assert_eq!(dt.to_format("ss.SSS"), "00.250");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for NaN, infinities and instants outside years -9999 to 9999.
+    pub fn from_seconds_f64(secs: f64) -> Result<Self, Error> {
+        let ms = (secs * 1000.0).round();
+        if !ms.is_finite() || ms < MIN_MS as f64 || ms > MAX_MS as f64 {
+            return Err(Error::OutOfRange);
+        }
+        Self::from_millis(ms as i64)
+    }
+
+    /// Creates a DateTime at midnight UTC from a year and a day of the year
+    /// (1–366), the ISO-8601 ordinal date.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::DateTime;
+    ///
+    /// let dt = DateTime::from_ordinal_date(2025, 300).unwrap();
+    /// assert_eq!(dt.to_format("yyyy-MM-dd"), "2025-10-27");
+    /// assert_eq!(dt.to_format("yyyy-DDD"), "2025-300");
+    /// assert!(DateTime::from_ordinal_date(2025, 366).is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the day does not exist in that year.
+    pub fn from_ordinal_date(year: i32, day_of_year: u32) -> Result<Self, Error> {
+        let (month, day) = crate::calendar::month_day_from_ordinal(year, day_of_year).ok_or(
+            Error::InvalidComponent {
+                unit: "day of year",
+                value: day_of_year as i64,
+            },
+        )?;
+        Self::from_civil(year, month, day, 0, 0, 0, 0)
+    }
+
+    /// Creates a DateTime at midnight UTC from an ISO-8601 week date: the
+    /// week-numbering year, the week (1–53) and the day within it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::{DateTime, Weekday};
+    ///
+    /// let dt = DateTime::from_iso_week_date(2025, 44, Weekday::Thursday).unwrap();
+    /// assert_eq!(dt.to_format("yyyy-MM-dd"), "2025-10-30");
+    /// assert_eq!(dt.to_iso_week_date(), "2025-W44-4");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the week-year has no such week.
+    pub fn from_iso_week_date(week_year: i32, week: u32, weekday: Weekday) -> Result<Self, Error> {
+        let (year, month, day) = WeekRule::ISO
+            .date_from_week(week_year, week, weekday)
+            .ok_or(Error::InvalidComponent {
+                unit: "week",
+                value: week as i64,
+            })?;
+        Self::from_civil(year, month, day, 0, 0, 0, 0)
+    }
+
+    /// Creates a DateTime from a Julian day number, the continuous day count
+    /// used in astronomy. Julian days start at noon UTC, so `2451545.0` is
+    /// 2000-01-01T12:00:00Z. The result is rounded to the nearest millisecond.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::DateTime;
+    ///
+    /// let j2000 = DateTime::from_julian_day(2_451_545.0).unwrap();
+    /// assert_eq!(j2000.to_format("yyyy-MM-dd HH:mm"), "2000-01-01 12:00");
+    /// assert_eq!(j2000.to_julian_day(), 2_451_545.0);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for NaN, infinities and days outside years -9999 to 9999.
+    pub fn from_julian_day(jd: f64) -> Result<Self, Error> {
+        let ms = ((jd - UNIX_EPOCH_JULIAN_DAY) * 86_400_000.0).round();
+        if !ms.is_finite() || ms < MIN_MS as f64 || ms > MAX_MS as f64 {
+            return Err(Error::OutOfRange);
+        }
+        Self::from_millis(ms as i64)
+    }
+
+    // Validated construction from UTC calendar components.
+    fn from_civil(
+        year: i32,
+        month: u32,
+        day: u32,
+        hour: u32,
+        minute: u32,
+        second: u32,
+        millis: u32,
+    ) -> Result<Self, Error> {
+        if !(MIN_YEAR..=MAX_YEAR).contains(&year) {
+            return Err(Error::InvalidYear(year as i64));
+        }
+        Self::civil_ms(year, month, day, hour, minute, second, millis).map(Self::from_epoch_ms)
+    }
+
+    // Milliseconds since the epoch of calendar components read as UTC, after
+    // checking each is in range; the year is left to the caller.
+    fn civil_ms(
+        year: i32,
+        month: u32,
+        day: u32,
+        hour: u32,
+        minute: u32,
+        second: u32,
+        millis: u32,
+    ) -> Result<i64, Error> {
+        if !(1..=12).contains(&month) {
+            return Err(Error::InvalidComponent {
+                unit: "month",
+                value: month as i64,
+            });
+        }
+        if day < 1 || day > crate::calendar::days_in_month(year, month) {
+            return Err(Error::InvalidComponent {
+                unit: "day",
+                value: day as i64,
+            });
+        }
+        if hour > 23 {
+            return Err(Error::InvalidComponent {
+                unit: "hour",
+                value: hour as i64,
+            });
+        }
+        if minute > 59 {
+            return Err(Error::InvalidComponent {
+                unit: "minute",
+                value: minute as i64,
+            });
+        }
+        if second > 59 {
+            return Err(Error::InvalidComponent {
+                unit: "second",
+                value: second as i64,
+            });
+        }
+        if millis > 999 {
+            return Err(Error::InvalidComponent {
+                unit: "millisecond",
+                value: millis as i64,
+            });
+        }
+
+        Ok(Self::compute_timestamp(
+            year, month, day, hour, minute, second, millis,
+        ))
+    }
+
+    /// Parses `s` according to a format string using the same tokens as
+    /// [`to_format`](Self::to_format). The result is in UTC.
+    ///
+    /// An offset token (`Z`, `ZZ` or `ZZZ`, all read the same way) accepts
+    /// `Z`, `+5`, `+05:00`, `+0500` and `UTC`/`GMT` with or without an offset
+    /// (`GMT-5`); the parsed wall clock is converted to UTC with it, and the
+    /// offset is kept as a fixed-offset zone such as `UTC+05:30`.
+    ///
+    /// `X` and `x` read a Unix timestamp in seconds or milliseconds, which
+    /// gives the instant directly.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::DateTime;
+    ///
+    /// let dt = DateTime::from_format("2025-10-30 16:30 +02:00", "yyyy-MM-dd HH:mm ZZ").unwrap();
+    /// assert_eq!(dt, DateTime::from_iso("2025-10-30T14:30:00Z").unwrap());
+    ///
+    /// let log =
+    ///     DateTime::from_format("30/Oct/2025:09:30:00 GMT-5", "dd/MMM/yyyy:HH:mm:ss Z").unwrap();
+    /// assert_eq!(log, DateTime::from_iso("2025-10-30T14:30:00Z").unwrap());
+    /// assert_eq!(log.zone_name(), Some("UTC-05:00"));
+    /// assert_eq!(log.to_format("HH:mm ZZ"), "09:30 -05:00");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input does not match the format or the parsed
+    /// components do not form a valid date.
+    pub fn from_format(s: &str, fmt: &str) -> Result<Self, Error> {
+        Self::from_format_with(s, fmt, ParseOptions::default())
+    }
+
+    /// Parses a relative expression such as `"tomorrow at 3pm"`, `"next friday"`
+    /// or `"in 2 hours"` against `relative_to`.
+    ///
+    /// The grammar is small and English-only:
+    ///
+    /// - `now`
+    /// - `today`, `tomorrow`, `yesterday`, a weekday, or `next`/`last` and a
+    ///   weekday, optionally followed by `at` and a time; without a time the
+    ///   result is midnight
+    /// - `at` and a time, for today
+    /// - `in <n> <unit>` and `<n> <unit> ago`, where `<n>` may be `a`/`an`
+    ///
+    /// Times are `3pm`, `3:30 pm`, `15:00`, `noon` or `midnight`. A bare or
+    /// `next` weekday is the first one after today; `last` is the most recent
+    /// one before today. Dates are counted in `relative_to`'s zone, which the
+    /// result keeps. Words are case-insensitive.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::DateTime;
+    ///
+    /// // A Thursday
+    /// let now = DateTime::from_iso("2025-10-30T09:15:00Z").unwrap();
+    /// let parse = |s| DateTime::from_human(s, &now).unwrap().to_format("EEE yyyy-MM-dd HH:mm");
+    ///
+    /// assert_eq!(parse("tomorrow at 3pm"), "Fri 2025-10-31 15:00");
+    /// assert_eq!(parse("next friday"), "Fri 2025-10-31 00:00");
+    /// assert_eq!(parse("last thursday at noon"), "Thu 2025-10-23 12:00");
+    /// assert_eq!(parse("in 2 hours"), "Thu 2025-10-30 11:15");
+    /// assert_eq!(parse("3 days ago"), "Mon 2025-10-27 09:15");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for input outside the grammar, pointing at the first
+    /// word that could not be used, and [`Error::OutOfRange`] when an amount
+    /// leads outside years -9999 to 9999.
+    pub fn from_human(s: &str, relative_to: &DateTime) -> Result<Self, Error> {
+        crate::human::parse(s, relative_to)
+    }
+
+    /// Tries each format in order with [`from_format`](Self::from_format) and
+    /// returns the first successful parse, for input mixing several layouts.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::DateTime;
+    ///
+    /// let formats = ["yyyy-MM-dd HH:mm", "MM/dd/yyyy", "MMM d, yyyy"];
+    /// for s in ["2025-10-30 00:00", "10/30/2025", "Oct 30, 2025"] {
+    ///     let dt = DateTime::from_formats(s, &formats).unwrap();
+    ///     assert_eq!(dt.to_format("yyyy-MM-dd"), "2025-10-30");
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NoMatchingFormat`] with every format and the error it
+    /// produced when none matches (an empty list included).
+    pub fn from_formats(s: &str, formats: &[&str]) -> Result<Self, Error> {
+        let mut attempts = Vec::with_capacity(formats.len());
+        for fmt in formats {
+            match Self::from_format(s, fmt) {
+                Ok(dt) => return Ok(dt),
+                Err(err) => attempts.push((fmt.to_string(), err)),
+            }
+        }
+        Err(Error::NoMatchingFormat(attempts))
+    }
+
+    /// Like [`from_format`](Self::from_format), with explicit [`ParseOptions`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::{DateTime, Error, ParseOptions, Weekday};
+    ///
+    /// let strict = ParseOptions { validate_weekday: true, ..Default::default() };
+    /// let fmt = "EEEE, MMMM dd, yyyy";
+    /// assert!(DateTime::from_format_with("Thursday, October 30, 2025", fmt, strict).is_ok());
+    /// assert_eq!(
+    ///     DateTime::from_format_with("Friday, October 30, 2025", fmt, strict),
+    ///     Err(Error::WeekdayMismatch { parsed: Weekday::Friday, actual: Weekday::Thursday })
+    /// );
+    ///
+    /// let lenient = ParseOptions { lenient: true, ..Default::default() };
+    /// let dt =
+    ///     DateTime::from_format_with(" Oct  5 2025  7:09:03", "MMM dd yyyy HH:mm:ss", lenient);
+    /// assert_eq!(dt.unwrap().to_format("yyyy-MM-dd HH:mm:ss"), "2025-10-05 07:09:03");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// As for [`from_format`](Self::from_format), plus
+    /// [`Error::WeekdayMismatch`] when `validate_weekday` is set.
+    pub fn from_format_with(s: &str, fmt: &str, options: ParseOptions) -> Result<Self, Error> {
+        let items = Tokens::new(fmt).collect::<Result<Vec<_>, _>>()?;
+        Self::from_items(s, &items, options)
+    }
+
+    // Parses `s` against a tokenized pattern; shared with `Format::parse`.
+    pub(crate) fn from_items(
+        s: &str,
+        items: &[Item],
+        options: ParseOptions,
+    ) -> Result<Self, Error> {
+        // Simple parser for patterns similar to to_format tokens.
+        // Supported tokens: yyyy, yy, MMMM, MMM, MM, M, dd, d, do, H/H H, HH, h/h hh, m/mm, s/ss, SSS, a
+        // Lenient mode ignores surrounding whitespace; positions stay relative to `s`.
+        let input = if options.lenient { s.trim_end() } else { s };
+        let mut ix: usize = if options.lenient {
+            input.len() - input.trim_start().len()
+        } else {
+            0
+        };
+        // Digits accepted by a numeric token of `count` letters: a single letter
+        // takes 1-2 digits, a doubled one exactly 2 unless lenient.
+        let width = |count: usize| {
+            if count >= 2 && !options.lenient {
+                (2, 2)
+            } else {
+                (1, 2)
+            }
+        };
+        let mut year: Option<i32> = None;
+        let mut month: Option<u32> = None;
+        let mut day: Option<u32> = None;
+        let mut hour: Option<u32> = None;
+        let mut minute: Option<u32> = None;
+        let mut second: Option<u32> = None;
+        let mut millis: Option<u32> = None;
+        let mut offset: Option<i32> = None;
+        let mut weekday: Option<Weekday> = None;
+        let mut pm = false;
+        let mut epoch_ms: Option<i64> = None;
+
+        let mut tokens = items.iter().peekable();
+        while let Some(item) = tokens.next() {
+            match *item {
+                Item::Literal(ref lit) => {
+                    // quoted literal; a `''` inside one comes as its own `Char`
+                    if input.get(ix..).is_some_and(|s| s.starts_with(&**lit)) {
+                        ix += lit.len();
+                    } else {
+                        return Err(Error::Invalid {
+                            what: "literal",
+                            position: ix,
+                        });
+                    }
+                }
+                Item::Field('y', count) => {
+                    if count >= 4 {
+                        if ix + 4 > input.len() {
+                            return Err(Error::UnexpectedEnd {
+                                position: input.len(),
+                            });
+                        }
+                        let v: i32 = input
+                            .get(ix..ix + 4)
+                            .and_then(|digits| digits.parse().ok())
+                            .ok_or(Error::Invalid {
+                                what: "year",
+                                position: ix,
+                            })?;
+                        year = Some(v);
+                        ix += 4;
+                    } else {
+                        if ix + 2 > input.len() {
+                            return Err(Error::UnexpectedEnd {
+                                position: input.len(),
+                            });
+                        }
+                        let v: i32 = input
+                            .get(ix..ix + 2)
+                            .and_then(|digits| digits.parse().ok())
+                            .ok_or(Error::Invalid {
+                                what: "year",
+                                position: ix,
+                            })?;
+                        // two-digit year: 2000-2099 unless a cutoff moves the upper
+                        // values to the 1900s
+                        let century = match options.two_digit_year_cutoff {
+                            Some(cutoff) if v >= cutoff as i32 => 1900,
+                            _ => 2000,
+                        };
+                        year = Some(century + v);
+                        ix += 2;
+                    }
+                }
+                Item::Field('M', count) => {
+                    if count >= 4 {
+                        // full month name - try matching any month name (case-insensitive)
+                        let names = [
+                            "January",
+                            "February",
+                            "March",
+                            "April",
+                            "May",
+                            "June",
+                            "July",
+                            "August",
+                            "September",
+                            "October",
+                            "November",
+                            "December",
+                        ];
+                        let mut matched = None;
+                        for (i, name) in names.iter().enumerate() {
+                            let nl = name.len();
+                            // compared as bytes, as for weekdays, so non-ASCII
+                            // input is never sliced mid-character
+                            if input
+                                .as_bytes()
+                                .get(ix..ix + nl)
+                                .is_some_and(|text| text.eq_ignore_ascii_case(name.as_bytes()))
+                            {
+                                matched = Some((i + 1) as u32);
+                                ix += nl;
+                                break;
+                            }
+                        }
+                        if matched.is_none() {
+                            return Err(Error::Invalid {
+                                what: "month name",
+                                position: ix,
+                            });
+                        }
+                        month = matched;
+                    } else if count == 3 {
+                        let names = [
+                            "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct",
+                            "Nov", "Dec",
+                        ];
+                        let mut matched = None;
+                        for (i, name) in names.iter().enumerate() {
+                            let nl = name.len();
+                            if input
+                                .as_bytes()
+                                .get(ix..ix + nl)
+                                .is_some_and(|text| text.eq_ignore_ascii_case(name.as_bytes()))
+                            {
+                                matched = Some((i + 1) as u32);
+                                ix += nl;
+                                break;
+                            }
+                        }
+                        if matched.is_none() {
+                            return Err(Error::Invalid {
+                                what: "month name",
+                                position: ix,
+                            });
+                        }
+                        month = matched;
+                    } else {
+                        // numeric month
+                        let (v, len) = read_digits(input, ix, width(count), "month")?;
+                        month = Some(v);
+                        ix += len;
+                    }
+                }
+                Item::Ordinal => {
+                    // ordinal: digits followed by st/nd/rd/th
+                    let mut j = ix;
+                    while j < input.len() && input.as_bytes()[j].is_ascii_digit() {
+                        j += 1;
+                    }
+                    if j == ix {
+                        return Err(Error::Invalid {
+                            what: "day",
+                            position: ix,
+                        });
+                    }
+                    let v: u32 = input[ix..j].parse().map_err(|_| Error::Invalid {
+                        what: "day",
+                        position: ix,
+                    })?;
+                    // skip suffix letters
+                    let mut k = j;
+                    while k < input.len() && input.as_bytes()[k].is_ascii_alphabetic() {
+                        k += 1;
+                    }
+                    ix = k;
+                    day = Some(v);
+                }
+                Item::Field('d', count) => {
+                    let (v, len) = read_digits(input, ix, width(count), "day")?;
+                    day = Some(v);
+                    ix += len;
+                }
+                Item::Field('H' | 'h', count) => {
+                    // 12-hour values are adjusted for am/pm below
+                    let (v, len) = read_digits(input, ix, width(count), "hour")?;
+                    hour = Some(v);
+                    ix += len;
+                }
+                Item::Field('m', count) => {
+                    let (v, len) = read_digits(input, ix, width(count), "minute")?;
+                    minute = Some(v);
+                    ix += len;
+                }
+                Item::Field('s', count) => {
+                    let (v, len) = read_digits(input, ix, width(count), "second")?;
+                    second = Some(v);
+                    ix += len;
+                }
+                Item::Field('S', _) => {
+                    // parse milliseconds (up to 3 digits)
+                    let mut j = ix;
+                    while j < input.len() && input.as_bytes()[j].is_ascii_digit() {
+                        j += 1;
+                    }
+                    if j == ix {
+                        return Err(Error::Invalid {
+                            what: "millisecond",
+                            position: ix,
+                        });
+                    }
+                    let txt = &input[ix..j];
+                    let mut v: u32 = txt.parse().map_err(|_| Error::Invalid {
+                        what: "millisecond",
+                        position: ix,
+                    })?;
+                    // normalize to milliseconds length
+                    if txt.len() == 1 {
+                        v *= 100;
+                    } else if txt.len() == 2 {
+                        v *= 10;
+                    }
+                    millis = Some(v);
+                    ix = j;
+                }
+                Item::Field('E', count) => {
+                    // weekday: EEEE full name, EEE short name, E/EE number 1-7 (Monday first)
+                    let names: fn(u32) -> &'static str = if count >= 4 {
+                        crate::format::weekday_name
+                    } else {
+                        crate::format::weekday_short
+                    };
+                    let matched = if count >= 3 {
+                        (0..7)
+                            .map(|i| (i, names(i)))
+                            .find(|(_, name)| {
+                                input.len() >= ix + name.len()
+                                    && input.as_bytes()[ix..ix + name.len()]
+                                        .eq_ignore_ascii_case(name.as_bytes())
+                            })
+                            .map(|(i, name)| (i, name.len()))
+                    } else {
+                        match input.as_bytes().get(ix) {
+                            Some(b @ b'1'..=b'7') => Some(((b - b'1') as u32, 1)),
+                            _ => None,
+                        }
+                    };
+                    let Some((i, len)) = matched else {
+                        return Err(Error::Invalid {
+                            what: "weekday",
+                            position: ix,
+                        });
+                    };
+                    weekday = Some(Weekday::from_monday0(i));
+                    ix += len;
+                }
+                Item::Field('Z', _) => {
+                    // offset: Z, ZZ and ZZZ all accept any of the offset forms
+                    let (secs, len) = parse_offset(input, ix)?;
+                    offset = Some(secs);
+                    ix += len;
+                }
+                Item::Char(unit @ ('X' | 'x')) => {
+                    // Unix timestamp, seconds for X and milliseconds for x
+                    let rest = input.get(ix..).unwrap_or("");
+                    let sign = usize::from(rest.starts_with('-'));
+                    let len = sign
+                        + rest[sign..]
+                            .bytes()
+                            .take_while(|b| b.is_ascii_digit())
+                            .count();
+                    if len == sign {
+                        return Err(Error::Invalid {
+                            what: "timestamp",
+                            position: ix,
+                        });
+                    }
+                    let n: i64 = rest[..len].parse().map_err(|_| Error::OutOfRange)?;
+                    epoch_ms = Some(if unit == 'X' {
+                        n.checked_mul(1000).ok_or(Error::OutOfRange)?
+                    } else {
+                        n
+                    });
+                    ix += len;
+                }
+                Item::Char('a' | 'A') => {
+                    // am/pm in either case
+                    let meridiem = input.get(ix..ix + 2).unwrap_or("");
+                    if meridiem.eq_ignore_ascii_case("am") {
+                        pm = false;
+                        ix += 2;
+                    } else if meridiem.eq_ignore_ascii_case("pm") {
+                        pm = true;
+                        ix += 2;
+                    } else {
+                        return Err(Error::Invalid {
+                            what: "meridiem",
+                            position: ix,
+                        });
+                    }
+                }
+                Item::Char(c) if options.lenient && c.is_whitespace() => {
+                    // any run of whitespace in the format matches any non-empty run in the input
+                    while tokens
+                        .next_if(|item| matches!(item, Item::Char(c) if c.is_whitespace()))
+                        .is_some()
+                    {}
+                    let run = input[ix..].len() - input[ix..].trim_start().len();
+                    if run == 0 {
+                        return Err(Error::Expected {
+                            expected: ' ',
+                            position: ix,
+                        });
+                    }
+                    ix += run;
+                }
+                Item::Char(c) => {
+                    // expect literal char
+                    if !input.get(ix..).is_some_and(|s| s.starts_with(c)) {
+                        return Err(Error::Expected {
+                            expected: c,
+                            position: ix,
+                        });
+                    }
+                    ix += c.len_utf8();
+                }
+                Item::Field(c, count) => {
+                    // letters that are not parse tokens match themselves
+                    for _ in 0..count {
+                        if !input.get(ix..).is_some_and(|s| s.starts_with(c)) {
+                            return Err(Error::Expected {
+                                expected: c,
+                                position: ix,
+                            });
+                        }
+                        ix += c.len_utf8();
+                    }
+                }
+            }
+        }
+
+        if ix < input.len() {
+            return Err(Error::TrailingInput { position: ix });
+        }
+
+        if let Some(ms) = epoch_ms {
+            return Self::from_millis(ms);
+        }
+
+        // fill defaults
+        let y = year.unwrap_or(1970);
+        let m = month.unwrap_or(1);
+        let d = day.unwrap_or(1);
+        let mut h = hour.unwrap_or(0);
+        let min = minute.unwrap_or(0);
+        let sec = second.unwrap_or(0);
+        let ms = millis.unwrap_or(0);
+        if hour.is_some() {
+            // if 12-hour clock and pm flag
+            if pm {
+                if h < 12 {
+                    h += 12;
+                }
+            } else {
+                if h == 12 && items.iter().any(|item| matches!(item, Item::Field('h', _))) {
+                    h = 0;
+                }
+            }
+        }
+
+        let wall = Self::from_civil(y, m, d, h, min, sec, ms)?;
+        if let (Some(parsed), true) = (weekday, options.validate_weekday) {
+            let actual = wall.weekday();
+            if parsed != actual {
+                return Err(Error::WeekdayMismatch { parsed, actual });
+            }
+        }
+        match offset {
+            None => Ok(wall),
+            Some(secs) => {
+                let utc = Self::from_millis(wall.to_millis() - secs as i64 * 1000)?;
+                Ok(DateTime {
+                    zone: Some(Zone::with_offset(secs)),
+                    ..utc
+                })
+            }
+        }
+    }
+
+    /// Attaches a timezone, keeping the instant and changing the wall clock.
+    ///
+    /// With the `tz` feature any IANA name is accepted. Without it a small
+    /// built-in table of common zones (fixed offsets, no DST) is used. Fixed
+    /// offsets such as `"+05:30"`, `"-07:00"` or `"UTC+05:30"` work in every
+    /// build and are named `UTC±HH:MM`. Unknown names leave the DateTime
+    /// unchanged; use [`try_set_zone`](Self::try_set_zone) to detect them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::DateTime;
+    ///
+    /// let dt = DateTime::from_iso("2025-10-30T12:00:00Z").unwrap().set_zone("+05:30");
+    /// assert_eq!(dt.to_format("HH:mm ZZ"), "17:30 +05:30");
+    /// assert_eq!(dt.zone_name(), Some("UTC+05:30"));
+    /// assert!(dt.to_iso().starts_with("2025-10-30T17:30:00"));
+    /// ```
+    pub fn set_zone(&self, zone: &str) -> Self {
+        DateTime {
+            zone: Zone::parse(zone).or(self.zone),
+            ..*self
+        }
+    }
+
+    /// Like [`set_zone`](Self::set_zone), but fails on an unknown zone name.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::{DateTime, Error};
+    ///
+    /// let dt = DateTime::from_iso("2025-10-30T12:00:00Z").unwrap();
+    /// assert!(dt.try_set_zone("UTC").is_ok());
+    /// assert_eq!(
+    ///     dt.try_set_zone("Mars/Olympus_Mons"),
+    ///     Err(Error::UnknownZone("Mars/Olympus_Mons".to_string()))
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::UnknownZone`] if the name is not recognised.
+    pub fn try_set_zone(&self, zone: &str) -> Result<Self, Error> {
+        let parsed = Zone::parse(zone).ok_or_else(|| Error::UnknownZone(zone.to_string()))?;
+        Ok(DateTime {
+            zone: Some(parsed),
+            ..*self
+        })
+    }
+
+    /// Attaches a timezone, optionally keeping the wall-clock time.
+    ///
+    /// With `keep_local_time` the date and time read the same in the new zone
+    /// and the instant moves; otherwise this is
+    /// [`try_set_zone`](Self::try_set_zone). A wall-clock time the new zone
+    /// repeats or skips is read as [`Disambiguation::Shift`] does.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::{DateTime, SetZoneOptions};
+    ///
+    /// let meeting = DateTime::from_iso("2025-10-30T09:00:00Z").unwrap();
+    /// let options = SetZoneOptions { keep_local_time: true };
+    /// let tokyo = meeting.set_zone_with("Asia/Tokyo", options).unwrap();
+    ///
+    /// assert_eq!(tokyo.to_format("yyyy-MM-dd HH:mm"), "2025-10-30 09:00");
+    /// assert_eq!(tokyo.to_millis(), meeting.to_millis() - 9 * 3_600_000);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::UnknownZone`] if the name is not recognised.
+    pub fn set_zone_with(&self, zone: &str, options: SetZoneOptions) -> Result<Self, Error> {
+        let converted = self.try_set_zone(zone)?;
+        if !options.keep_local_time {
+            return Ok(converted);
+        }
+        let local_ms = self.wall_clock_ms();
+        let instant_ms = match converted.zone {
+            Some(zone) => zone.local_to_instant_ms(local_ms, Disambiguation::Shift)?,
+            None => local_ms,
+        };
+        Ok(Self::from_epoch_ms(instant_ms).with_zone_of(&converted))
+    }
+
+    /// The name of the attached zone, or `None` if no zone has been set and
+    /// the DateTime reads as UTC.
+    ///
+    /// With the `tz` feature this is the IANA name; without it, the built-in
+    /// table's canonical spelling.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::DateTime;
+    ///
+    /// let dt = DateTime::from_iso("2025-10-30T12:00:00Z").unwrap();
+    /// assert_eq!(dt.zone_name(), None);
+    /// assert_eq!(dt.set_zone("Asia/Tokyo").zone_name(), Some("Asia/Tokyo"));
+    /// ```
+    pub fn zone_name(&self) -> Option<&str> {
+        self.zone.as_ref().map(Zone::name)
+    }
+
+    /// The UTC offset in effect at this instant, in minutes east of UTC.
+    ///
+    /// Without a zone the offset is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::DateTime;
+    ///
+    /// let dt = DateTime::from_iso("2025-10-30T12:00:00Z").unwrap();
+    /// assert_eq!(dt.offset(), 0);
+    /// assert_eq!(dt.set_zone("Asia/Tokyo").offset(), 540);
+    /// assert_eq!(dt.set_zone("Asia/Kolkata").offset(), 330);
+    /// ```
+    pub fn offset(&self) -> i32 {
+        self.offset_secs() / 60
+    }
+
+    /// Whether daylight saving time is in effect at this instant in the
+    /// attached zone. Always `false` without a zone, for fixed offsets and
+    /// for the built-in table used without the `tz` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "tz")]
+    /// # {
+    /// use tempotime::DateTime;
+    ///
+    /// let summer = DateTime::from_iso("2025-07-01T12:00:00Z").unwrap();
+    /// let winter = DateTime::from_iso("2025-01-01T12:00:00Z").unwrap();
+    /// assert!(summer.set_zone("America/New_York").is_dst());
+    /// assert!(!winter.set_zone("America/New_York").is_dst());
+    /// assert!(!summer.set_zone("Asia/Tokyo").is_dst());
+    /// # }
+    /// ```
+    pub fn is_dst(&self) -> bool {
+        self.zone
+            .is_some_and(|zone| zone.is_dst_at(self.to_millis()))
+    }
+
+    /// The next change of UTC offset after this instant, such as a DST
+    /// transition.
+    ///
+    /// Returns the first instant on the new offset, in this DateTime's zone,
+    /// and the change in offset: positive when clocks go forward. `None` if
+    /// the zone's offset never changes. IANA zones are searched up to two
+    /// years ahead; a [`TimeZoneProvider`](crate::TimeZoneProvider) may look
+    /// further.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "tz")]
+    /// # {
+    /// use tempotime::{DateTime, Duration};
+    ///
+    /// let dt = DateTime::from_iso("2025-10-01T12:00:00Z").unwrap().set_zone("America/New_York");
+    /// let (at, delta) = dt.next_dst_transition().unwrap();
+    /// assert_eq!(at.to_format("yyyy-MM-dd HH:mm"), "2025-11-02 01:00");
+    /// assert_eq!(delta, Duration::hours(-1));
+    ///
+    /// assert!(dt.set_zone("Asia/Tokyo").next_dst_transition().is_none());
+    /// # }
+    /// ```
+    pub fn next_dst_transition(&self) -> Option<(DateTime, Duration)> {
+        self.dst_transition(true)
+    }
+
+    /// The most recent change of UTC offset at or before this instant. See
+    /// [`next_dst_transition`](Self::next_dst_transition).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "tz")]
+    /// # {
+    /// use tempotime::{DateTime, Duration};
+    ///
+    /// let dt = DateTime::from_iso("2025-10-01T12:00:00Z").unwrap().set_zone("America/New_York");
+    /// let (at, delta) = dt.previous_dst_transition().unwrap();
+    /// assert_eq!(at.to_format("yyyy-MM-dd HH:mm"), "2025-03-09 03:00");
+    /// assert_eq!(delta, Duration::hours(1));
+    /// # }
+    /// ```
+    pub fn previous_dst_transition(&self) -> Option<(DateTime, Duration)> {
+        self.dst_transition(false)
+    }
+
+    fn dst_transition(&self, forward: bool) -> Option<(DateTime, Duration)> {
+        let zone = self.zone?;
+        let at = zone.transition_ms(self.to_millis(), forward)?;
+        let delta = zone.offset_secs_at(at) - zone.offset_secs_at(at - 1);
+        Some((
+            Self::from_epoch_ms(at).with_zone_of(self),
+            Duration::seconds(delta as i64),
+        ))
+    }
+
+    /// Returns a copy with the named components replaced, like Luxon's `set`.
+    ///
+    /// Keys are the same as for [`from_object`](Self::from_object). Components
+    /// are wall-clock values in this DateTime's zone, which is kept. A day that
+    /// does not exist in the resulting month is clamped to the month's last day,
+    /// and a time repeated or skipped by a DST change is read as
+    /// [`Disambiguation::Shift`] does; use [`set_with`](Self::set_with) to
+    /// choose otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::DateTime;
+    ///
+    /// let dt = DateTime::from_iso("2025-01-31T14:30:00Z").unwrap();
+    ///
+    /// let morning = dt.set(&[("hour", 9), ("minute", 0)]).unwrap();
+    /// assert_eq!(morning.to_format("yyyy-MM-dd HH:mm"), "2025-01-31 09:00");
+    ///
+    /// let feb = dt.set(&[("month", 2)]).unwrap();
+    /// assert_eq!(feb.to_format("yyyy-MM-dd"), "2025-02-28");
+    ///
+    /// assert!(dt.set(&[("month", 13)]).is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for unknown keys and out-of-range components.
+    pub fn set(&self, fields: &[(&str, i64)]) -> Result<Self, Error> {
+        self.set_with(fields, SetOptions::default())
+    }
+
+    /// Like [`set`](Self::set), with explicit [`SetOptions`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::{DateTime, Overflow, SetOptions};
+    ///
+    /// let dt = DateTime::from_iso("2025-01-31T00:00:00Z").unwrap();
+    /// let strict = SetOptions { overflow: Overflow::Reject, ..Default::default() };
+    /// assert!(dt.set_with(&[("month", 2)], strict).is_err());
+    /// ```
+    ///
+    /// Picking the second 01:30 when New York's clocks go back:
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "tz")]
+    /// # {
+    /// use tempotime::{DateTime, Disambiguation, SetOptions};
+    ///
+    /// let dt = DateTime::from_iso("2025-11-02T12:00:00Z").unwrap().set_zone("America/New_York");
+    /// let later = SetOptions { disambiguation: Disambiguation::Later, ..Default::default() };
+    /// let second = dt.set_with(&[("hour", 1), ("minute", 30)], later).unwrap();
+    /// assert_eq!(second.offset(), -300);
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for unknown keys, out-of-range components and, with
+    /// [`Overflow::Reject`], days past the end of the month. With
+    /// [`Disambiguation::Reject`] a repeated or skipped wall-clock time fails
+    /// with [`Error::AmbiguousLocalTime`] or [`Error::NonexistentLocalTime`].
+    pub fn set_with(&self, fields: &[(&str, i64)], options: SetOptions) -> Result<Self, Error> {
+        let (y, m, d, h, mi, s, ms) = self.local_parts();
+        let mut year = y as i64;
+        let mut parts = [m, d, h, mi, s, ms].map(i64::from);
+        for (key, value) in fields {
+            match *key {
+                "years" | "year" => year = *value,
+                "months" | "month" => parts[0] = *value,
+                "days" | "day" => parts[1] = *value,
+                "hours" | "hour" => parts[2] = *value,
+                "minutes" | "minute" => parts[3] = *value,
+                "seconds" | "second" => parts[4] = *value,
+                "milliseconds" | "millisecond" => parts[5] = *value,
+                other => return Err(Error::UnknownUnit(other.to_string())),
+            }
+        }
+        let year = i32::try_from(year).map_err(|_| Error::InvalidYear(year))?;
+        let [month, mut day, hour, minute, second, millis] =
+            parts.map(|value| u32::try_from(value).unwrap_or(u32::MAX));
+
+        if options.overflow == Overflow::Clamp && (1..=12).contains(&month) {
+            day = day.min(crate::calendar::days_in_month(year, month));
+        }
+
+        let wall = Self::from_civil(year, month, day, hour, minute, second, millis)?;
+        let instant = match self.zone {
+            Some(zone) => Self::from_epoch_ms(
+                zone.local_to_instant_ms(wall.to_millis(), options.disambiguation)?,
+            ),
+            None => wall,
+        };
+        Ok(DateTime {
+            zone: self.zone,
+            ..instant
+        })
+    }
+
+    /// Returns a copy with the year replaced.
+    ///
+    /// The `with_*` setters are typed shorthands for [`set_with`](Self::set_with)
+    /// with [`Overflow::Reject`]: a result that would not exist, such as
+    /// February 30th, is an error rather than being clamped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::DateTime;
+    ///
+    /// let dt = DateTime::from_iso("2025-10-30T14:30:00Z").unwrap();
+    /// let moved = dt.with_year(2026).and_then(|d| d.with_month(3)).unwrap();
+    /// assert_eq!(moved.to_format("yyyy-MM-dd"), "2026-03-30");
+    ///
+    /// assert!(dt.with_month(2).is_err()); // February 30th
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the resulting date does not exist.
+    pub fn with_year(&self, year: i32) -> Result<Self, Error> {
+        self.with_field("year", year as i64)
+    }
+
+    /// Returns a copy with the month (1–12) replaced.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the month is out of range or the day does not exist in it.
+    pub fn with_month(&self, month: u32) -> Result<Self, Error> {
+        self.with_field("month", month as i64)
+    }
+
+    /// Returns a copy with the day of the month replaced.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the day does not exist in the current month.
+    pub fn with_day(&self, day: u32) -> Result<Self, Error> {
+        self.with_field("day", day as i64)
+    }
+
+    /// Returns a copy with the hour (0–23) replaced.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the hour is out of range.
+    pub fn with_hour(&self, hour: u32) -> Result<Self, Error> {
+        self.with_field("hour", hour as i64)
+    }
+
+    /// Returns a copy with the minute (0–59) replaced.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the minute is out of range.
+    pub fn with_minute(&self, minute: u32) -> Result<Self, Error> {
+        self.with_field("minute", minute as i64)
+    }
+
+    /// Returns a copy with the second (0–59) replaced.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the second is out of range.
+    pub fn with_second(&self, second: u32) -> Result<Self, Error> {
+        self.with_field("second", second as i64)
+    }
+
+    /// Returns a copy with the millisecond (0–999) replaced.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the millisecond is out of range.
+    pub fn with_millisecond(&self, millisecond: u32) -> Result<Self, Error> {
+        self.with_field("millisecond", millisecond as i64)
+    }
+
+    fn with_field(self, key: &str, value: i64) -> Result<Self, Error> {
+        let options = SetOptions {
+            overflow: Overflow::Reject,
+            ..Default::default()
+        };
+        self.set_with(&[(key, value)], options)
+    }
+
+    pub fn plus(&self, dur: &Duration) -> Self {
+        Self::from_epoch_ms(self.plus_ms(dur)).with_zone_of(self)
+    }
+
+    // The instant `dur` after this one, in milliseconds. It may fall outside
+    // the supported range, which `plus` clamps to and `checked_plus` rejects.
+    fn plus_ms(&self, dur: &Duration) -> i64 {
+        let (years, months, weeks, days, hours, minutes, seconds, millis) = dur.components();
+
+        // Years, months, weeks and days move the wall clock in the attached
+        // zone, so a day later is the same time of day even across a DST
+        // change. Years count as twelve months, and the day is clamped to the
+        // end of the resulting month.
+        let total_months = months.saturating_add(years.saturating_mul(12));
+        let calendar_ms = weeks
+            .saturating_mul(7)
+            .saturating_add(days)
+            .saturating_mul(86_400_000);
+        let base_ms = if total_months == 0 && calendar_ms == 0 {
+            self.to_millis()
+        } else {
+            let (mut y, mut m, mut d, h, mi, s, ms) = self.local_parts();
+            if total_months != 0 {
+                (y, m, d) = add_months_to_ymd(y, m, d, total_months);
+            }
+            // A day past either end is still out of range after any zone
+            // offset, and keeps the zone lookup within the years it knows.
+            let local_ms = Self::compute_timestamp(y, m, d, h, mi, s, ms)
+                .saturating_add(calendar_ms)
+                .clamp(MIN_MS - 86_400_000, MAX_MS + 86_400_000);
+            self.instant_at_wall_clock(local_ms)
+        };
+
+        // The rest is elapsed time.
+        let clock_ms = [
+            (hours, 3_600_000),
+            (minutes, 60_000),
+            (seconds, 1000),
+            (millis, 1),
+        ]
+        .iter()
+        .fold(0i64, |acc, &(n, unit)| {
+            acc.saturating_add(n.saturating_mul(unit))
+        });
+        base_ms.saturating_add(clock_ms)
+    }
+
+    /// Like [`plus`](Self::plus), but returns `None` instead of clamping.
+    ///
+    /// The result must lie within the supported range of years -9999 to 9999;
+    /// durations too large to apply at all also yield `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::{DateTime, Duration};
+    ///
+    /// let dt = DateTime::from_iso("2025-10-30T00:00:00Z").unwrap();
+    /// assert!(dt.checked_plus(&Duration::from_object(&[("days", 1)])).is_some());
+    /// assert!(dt.checked_plus(&Duration::from_object(&[("years", 8000)])).is_none());
+    /// assert!(dt.checked_plus(&Duration::from_object(&[("milliseconds", i64::MAX)])).is_none());
+    /// ```
+    pub fn checked_plus(&self, dur: &Duration) -> Option<Self> {
+        let (years, months, weeks, days, hours, minutes, seconds, millis) = dur.components();
+
+        // Any calendar shift larger than the whole supported span cannot land
+        // inside it; bounding the parts keeps `plus_ms` from saturating back
+        // into range when large parts of opposite signs cancel.
+        const SPAN_YEARS: i64 = (MAX_YEAR - MIN_YEAR + 1) as i64;
+        if years.unsigned_abs() > SPAN_YEARS as u64
+            || months.unsigned_abs() > (SPAN_YEARS * 12) as u64
+        {
+            return None;
+        }
+
+        let shift_ms = [
+            (weeks, 604_800_000),
+            (days, 86_400_000),
+            (hours, 3_600_000),
+            (minutes, 60_000),
+            (seconds, 1000),
+            (millis, 1),
+        ]
+        .iter()
+        .try_fold(0i64, |acc, &(n, unit)| {
+            acc.checked_add(n.checked_mul(unit)?)
+        })?;
+        if shift_ms.unsigned_abs() > (MAX_MS - MIN_MS) as u64 {
+            return None;
+        }
+
+        let ms = self.plus_ms(dur);
+        (MIN_MS..=MAX_MS)
+            .contains(&ms)
+            .then(|| Self::from_epoch_ms(ms).with_zone_of(self))
+    }
+
+    /// Like [`minus`](Self::minus), but returns `None` instead of clamping.
+    pub fn checked_minus(&self, dur: &Duration) -> Option<Self> {
+        let (years, months, weeks, days, hours, minutes, seconds, millis) = dur.components();
+        let negated = Duration::from_object(&[
+            ("years", years.checked_neg()?),
+            ("months", months.checked_neg()?),
+            ("weeks", weeks.checked_neg()?),
+            ("days", days.checked_neg()?),
+            ("hours", hours.checked_neg()?),
+            ("minutes", minutes.checked_neg()?),
+            ("seconds", seconds.checked_neg()?),
+            ("milliseconds", millis.checked_neg()?),
+        ]);
+        self.checked_plus(&negated)
+    }
+
+    pub fn minus(&self, dur: &Duration) -> Self {
+        self.plus(&dur.negate())
+    }
+
+    /// The first millisecond of the `unit` containing this DateTime, read in
+    /// its zone. Unknown units leave it unchanged; see
+    /// [`start_of_unit`](Self::start_of_unit) for the typed form.
+    pub fn start_of(&self, unit: &str) -> Self {
+        match unit.parse::<Unit>() {
+            Ok(unit) => self.start_of_unit(unit),
+            Err(_) => *self,
+        }
+    }
+
+    /// The first millisecond of the `unit` containing this DateTime, read in
+    /// its zone. Weeks start on Monday, as ISO 8601 has it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::{DateTime, Unit};
+    ///
+    /// let dt = DateTime::from_iso("2025-10-30T14:30:00Z").unwrap();
+    /// let month = dt.start_of_unit(Unit::Months);
+    /// assert_eq!(month.to_format("yyyy-MM-dd HH:mm"), "2025-10-01 00:00");
+    /// let week = dt.start_of_unit(Unit::Weeks);
+    /// assert_eq!(week.to_format("yyyy-MM-dd HH:mm"), "2025-10-27 00:00");
+    /// ```
+    pub fn start_of_unit(&self, unit: Unit) -> Self {
+        if unit == Unit::Weeks {
+            let day = self.start_of_unit(Unit::Days);
+            return day.minus(&Duration::days(self.weekday().number() as i64 - 1));
+        }
+        let (y, m, d, h, mi, s, ms) = self.local_parts();
+        let (ny, nm, nd, nh, nmi, ns, nms) = match unit {
+            Unit::Years => (y, 1, 1, 0, 0, 0, 0),
+            Unit::Months => (y, m, 1, 0, 0, 0, 0),
+            Unit::Days => (y, m, d, 0, 0, 0, 0),
+            Unit::Hours => (y, m, d, h, 0, 0, 0),
+            Unit::Minutes => (y, m, d, h, mi, 0, 0),
+            Unit::Seconds => (y, m, d, h, mi, s, 0),
+            _ => (y, m, d, h, mi, s, ms),
+        };
+        self.at_wall_clock(Self::compute_timestamp(ny, nm, nd, nh, nmi, ns, nms))
+    }
+
+    /// The last millisecond of the `unit` containing this DateTime, read in
+    /// its zone. Unknown units leave it unchanged; see
+    /// [`end_of_unit`](Self::end_of_unit) for the typed form.
+    pub fn end_of(&self, unit: &str) -> Self {
+        match unit.parse::<Unit>() {
+            Ok(unit) => self.end_of_unit(unit),
+            Err(_) => *self,
+        }
+    }
+
+    /// The last millisecond of the `unit` containing this DateTime, read in
+    /// its zone. Weeks end on Sunday, as ISO 8601 has it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::{DateTime, Unit};
+    ///
+    /// let dt = DateTime::from_iso("2025-10-30T14:30:00Z").unwrap();
+    /// assert_eq!(dt.end_of_unit(Unit::Days).to_format("HH:mm:ss.SSS"), "23:59:59.999");
+    /// assert_eq!(dt.end_of_unit(Unit::Weeks).to_format("yyyy-MM-dd"), "2025-11-02");
+    /// ```
+    pub fn end_of_unit(&self, unit: Unit) -> Self {
+        if unit == Unit::Weeks {
+            let day = self.end_of_unit(Unit::Days);
+            return day.plus(&Duration::days(7 - self.weekday().number() as i64));
+        }
+        let (y, m, d, h, mi, s, ms) = self.local_parts();
+        let (ny, nm, nd, nh, nmi, ns, nms) = match unit {
+            Unit::Years => (y, 12, 31, 23, 59, 59, 999),
+            Unit::Months => (y, m, crate::calendar::days_in_month(y, m), 23, 59, 59, 999),
+            Unit::Days => (y, m, d, 23, 59, 59, 999),
+            Unit::Hours => (y, m, d, h, 59, 59, 999),
+            Unit::Minutes => (y, m, d, h, mi, 59, 999),
+            Unit::Seconds => (y, m, d, h, mi, s, 999),
+            _ => (y, m, d, h, mi, s, ms),
+        };
+        self.at_wall_clock(Self::compute_timestamp(ny, nm, nd, nh, nmi, ns, nms))
+    }
+
+    /// Formats as ISO 8601 on the wall clock of the attached zone, ending in
+    /// its UTC offset (such as `+09:00`, or `Z` for UTC), so the string names
+    /// the same instant in every zone. Milliseconds are written when they are
+    /// not zero. [`from_iso`](Self::from_iso) reads it back.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::DateTime;
+    ///
+    /// let utc = DateTime::from_iso("2025-10-30T12:00:00Z").unwrap();
+    /// let tokyo = utc.set_zone("Asia/Tokyo");
+    /// assert_eq!(utc.to_iso(), "2025-10-30T12:00:00Z");
+    /// assert_eq!(tokyo.to_iso(), "2025-10-30T21:00:00+09:00");
+    /// assert_eq!(DateTime::from_iso(&tokyo.to_iso()).unwrap(), utc);
+    ///
+    /// let precise = DateTime::from_iso("2025-10-30T14:30:00.123Z").unwrap();
+    /// assert_eq!(precise.to_iso(), "2025-10-30T14:30:00.123Z");
+    /// ```
+    pub fn to_iso(&self) -> String {
+        let options = IsoOptions {
+            suppress_milliseconds: true,
+            ..Default::default()
+        };
+        let mut out = String::with_capacity(29);
+        let _ =
+            crate::iso::write_datetime(&mut out, self.local_parts(), self.offset_secs(), &options);
+        out
+    }
+
+    /// Formats as ISO 8601 with control over the parts strict consumers care
+    /// about: milliseconds, zero seconds, `Z` against `+00:00`, and basic
+    /// against extended format. The wall clock and offset are those of the
+    /// attached zone.
+    ///
+    /// With the default options milliseconds are always written, like Luxon's
+    /// `toISO`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::{DateTime, IsoFormat, IsoOffset, IsoOptions};
+    ///
+    /// let dt = DateTime::from_iso("2025-10-30T14:30:00Z").unwrap();
+    /// assert_eq!(dt.to_iso_with(IsoOptions::default()), "2025-10-30T14:30:00.000Z");
+    ///
+    /// let options = IsoOptions {
+    ///     suppress_milliseconds: true,
+    ///     offset: IsoOffset::Numeric,
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(dt.to_iso_with(options), "2025-10-30T14:30:00+00:00");
+    ///
+    /// let options = IsoOptions {
+    ///     suppress_seconds: true,
+    ///     format: IsoFormat::Basic,
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(dt.to_iso_with(options), "20251030T1430Z");
+    /// ```
+    pub fn to_iso_with(&self, options: IsoOptions) -> String {
+        let mut out = String::with_capacity(29);
+        let _ =
+            crate::iso::write_datetime(&mut out, self.local_parts(), self.offset_secs(), &options);
+        out
+    }
+
+    /// The ISO-8601 calendar date of this DateTime's wall-clock date, e.g.
+    /// `2025-10-30`, like Luxon's `toISODate`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::DateTime;
+    ///
+    /// let dt = DateTime::from_iso("2025-10-30T23:30:00Z").unwrap();
+    /// assert_eq!(dt.to_iso_date(), "2025-10-30");
+    /// assert_eq!(dt.set_zone("Asia/Tokyo").to_iso_date(), "2025-10-31");
+    /// ```
+    pub fn to_iso_date(&self) -> String {
+        use std::fmt::Write as _;
+
+        let (y, m, d) = self.local_ymd();
+        let mut out = String::with_capacity(10);
+        let _ = crate::iso::write_year(&mut out, y);
+        let _ = write!(out, "-{:02}-{:02}", m, d);
+        out
+    }
+
+    /// The ISO-8601 time of day on this DateTime's wall clock, with
+    /// milliseconds and without an offset, e.g. `14:30:00.000`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::DateTime;
+    ///
+    /// let dt = DateTime::from_iso("2025-10-30T14:30:00.250Z").unwrap();
+    /// assert_eq!(dt.to_iso_time(), "14:30:00.250");
+    /// assert_eq!(dt.set_zone("Asia/Tokyo").to_iso_time(), "23:30:00.250");
+    /// ```
+    pub fn to_iso_time(&self) -> String {
+        let (_, _, _, h, mi, s, ms) = self.local_parts();
+        format!("{:02}:{:02}:{:02}.{:03}", h, mi, s, ms)
+    }
+
+    /// The ISO-8601 week date of this DateTime's wall-clock date, e.g.
+    /// `2025-W44-4`. [`from_iso`](Self::from_iso) reads this form back.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::DateTime;
+    ///
+    /// let dt = DateTime::from_iso("2025-10-30T14:30:00Z").unwrap();
+    /// assert_eq!(dt.to_iso_week_date(), "2025-W44-4");
+    /// ```
+    pub fn to_iso_week_date(&self) -> String {
+        use std::fmt::Write as _;
+
+        let mut out = String::with_capacity(10);
+        let _ = crate::iso::write_year(&mut out, self.week_year());
+        let _ = write!(
+            out,
+            "-W{:02}-{}",
+            self.week_number(),
+            self.weekday().number()
+        );
+        out
+    }
+
+    /// Formats the instant in the ISO-8601 basic format, always in UTC:
+    /// `yyyyMMddTHHmmssZ`, with a `.SSS` fraction only when the milliseconds
+    /// are non-zero. [`from_iso`](Self::from_iso) reads it back.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::DateTime;
+    ///
+    /// let dt = DateTime::from_iso("2025-10-30T14:30:00Z").unwrap();
+    /// assert_eq!(dt.to_iso_basic(), "20251030T143000Z");
+    /// ```
+    pub fn to_iso_basic(&self) -> String {
+        use std::fmt::Write as _;
+
+        let (y, m, d, h, mi, s, ms) = Self::from_epoch_ms(self.to_millis()).local_parts();
+        let mut out = String::with_capacity(20);
+        let _ = crate::iso::write_year(&mut out, y);
+        let _ = write!(out, "{:02}{:02}T{:02}{:02}{:02}", m, d, h, mi, s);
+        if ms != 0 {
+            let _ = write!(out, ".{:03}", ms);
+        }
+        out.push('Z');
+        out
+    }
+
+    /// Formats this DateTime as a SQL timestamp with offset, like Luxon's
+    /// `toSQL`: `yyyy-MM-dd HH:mm:ss.SSS ±HH:MM`, using the wall clock and UTC
+    /// offset of the attached zone. Postgres accepts it for `timestamptz`
+    /// columns and [`from_sql`](Self::from_sql) reads it back.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::DateTime;
+    ///
+    /// let dt = DateTime::from_iso("2025-10-30T14:30:00.123Z").unwrap();
+    /// assert_eq!(dt.to_sql(), "2025-10-30 14:30:00.123 +00:00");
+    /// assert_eq!(DateTime::from_sql(&dt.to_sql()).unwrap(), dt);
+    /// ```
+    pub fn to_sql(&self) -> String {
+        use std::fmt::Write as _;
+
+        let (y, m, d, h, mi, s, ms) = self.local_parts();
+        let mut out = String::with_capacity(30);
+        let _ = crate::iso::write_year(&mut out, y);
+        let _ = write!(
+            out,
+            "-{:02}-{:02} {:02}:{:02}:{:02}.{:03} ",
+            m, d, h, mi, s, ms
+        );
+        let _ = crate::iso::write_offset(&mut out, self.offset_secs());
+        out
+    }
+
+    /// Formats this instant as an HTTP date (IMF-fixdate), e.g. for
+    /// `Last-Modified` or `Expires` headers. HTTP dates are always in GMT, so
+    /// the zone is ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::DateTime;
+    ///
+    /// let dt = DateTime::from_iso("1994-11-06T08:49:37Z").unwrap();
+    /// assert_eq!(dt.to_http_date(), "Sun, 06 Nov 1994 08:49:37 GMT");
+    /// ```
+    pub fn to_http_date(&self) -> String {
+        let utc = Self::from_epoch_ms(self.to_millis());
+        let (y, m, d, h, mi, s, _) = utc.local_parts();
+        let mut out = String::with_capacity(29);
+        let _ = crate::http::write(&mut out, (y, m, d, h, mi, s), utc.weekday() as u32);
+        out
+    }
+
+    /// Parses an HTTP date in UTC.
+    ///
+    /// Accepts the preferred IMF-fixdate (`Sun, 06 Nov 1994 08:49:37 GMT`) and
+    /// the obsolete RFC 850 (`Sunday, 06-Nov-94 08:49:37 GMT`) and asctime
+    /// (`Sun Nov  6 08:49:37 1994`) forms. Two-digit RFC 850 years are read as
+    /// the most recent matching year no more than 50 years in the future.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::DateTime;
+    ///
+    /// let a = DateTime::from_http_date("Sun, 06 Nov 1994 08:49:37 GMT").unwrap();
+    /// let b = DateTime::from_http_date("Sun Nov  6 08:49:37 1994").unwrap();
+    /// assert_eq!(a, b);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string matches none of the three forms or the
+    /// date does not exist.
+    pub fn from_http_date(s: &str) -> Result<Self, Error> {
+        let (y, m, d, h, mi, sec) = crate::http::parse(s, Self::now().year())?;
+        Self::from_civil(y, m, d, h, mi, sec, 0)
+    }
+
+    /// Parses an RFC 2822 (email) date-time such as
+    /// `Thu, 30 Oct 2025 16:30:00 +0200`, converting it to UTC like
+    /// [`from_iso`](Self::from_iso) does.
+    ///
+    /// The weekday and seconds are optional, and the obsolete forms (two-digit
+    /// years, `GMT`/`EST`-style zone names) are accepted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::DateTime;
+    ///
+    /// let dt = DateTime::from_rfc2822("Thu, 30 Oct 2025 16:30:00 +0200").unwrap();
+    /// assert_eq!(dt, DateTime::from_iso("2025-10-30T14:30:00Z").unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string is not an RFC 2822 date-time or the date
+    /// does not exist.
+    pub fn from_rfc2822(s: &str) -> Result<Self, Error> {
+        let ((y, m, d, h, mi, sec), offset) = crate::http::parse_rfc2822(s)?;
+        let wall = Self::from_civil(y, m, d, h, mi, sec, 0)?;
+        Self::from_millis(wall.to_millis() - offset as i64 * 1000)
+    }
+
+    /// Parses a timestamp in whichever common format it is written in, for
+    /// input from mixed sources such as logs.
+    ///
+    /// Tried in order:
+    ///
+    /// 1. A Unix timestamp: an integer is read as seconds if its magnitude is
+    ///    below 10¹¹ (about year 5138) and as milliseconds otherwise; a number
+    ///    with a fractional part is always seconds.
+    /// 2. ISO 8601 ([`from_iso`](Self::from_iso)), extended or basic.
+    /// 3. SQL ([`from_sql`](Self::from_sql)).
+    /// 4. RFC 2822 ([`from_rfc2822`](Self::from_rfc2822)).
+    /// 5. HTTP dates ([`from_http_date`](Self::from_http_date)).
+    ///
+    /// Because numbers are tried first, a compact date without a time such as
+    /// `20251030` is read as seconds; use [`from_iso`](Self::from_iso) for those.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::DateTime;
+    ///
+    /// let expected = DateTime::from_iso("2025-10-30T14:30:00Z").unwrap();
+    /// for s in [
+    ///     "1761834600",
+    ///     "1761834600000",
+    ///     "2025-10-30T14:30:00Z",
+    ///     "2025-10-30 16:30:00+02",
+    ///     "Thu, 30 Oct 2025 14:30:00 GMT",
+    /// ] {
+    ///     assert_eq!(DateTime::parse(s).unwrap(), expected);
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an out-of-range error for numbers outside the supported range,
+    /// and otherwise [`Error::NoMatchingFormat`] with the error from each
+    /// format when none applies.
+    pub fn parse(s: &str) -> Result<Self, Error> {
+        let s = s.trim();
+        if let Some(result) = Self::parse_epoch(s) {
+            return result;
+        }
+
+        type Parser = fn(&str) -> Result<DateTime, Error>;
+        let parsers: [(&str, Parser); 4] = [
+            ("ISO 8601", Self::from_iso),
+            ("SQL", Self::from_sql),
+            ("RFC 2822", Self::from_rfc2822),
+            ("HTTP date", Self::from_http_date),
+        ];
+        let mut attempts = Vec::with_capacity(parsers.len());
+        for (name, parser) in parsers {
+            match parser(s) {
+                Ok(dt) => return Ok(dt),
+                Err(err) => attempts.push((name.to_string(), err)),
+            }
+        }
+        Err(Error::NoMatchingFormat(attempts))
+    }
+
+    // `[-]digits[.digits]` as a Unix timestamp, or `None` if `s` is not a number.
+    fn parse_epoch(s: &str) -> Option<Result<Self, Error>> {
+        let digits = s.strip_prefix('-').unwrap_or(s);
+        let (int, frac) = digits.split_once('.').unwrap_or((digits, ""));
+        let all_digits = |part: &str| part.bytes().all(|b| b.is_ascii_digit());
+        if int.is_empty() || !all_digits(int) || !all_digits(frac) || digits.ends_with('.') {
+            return None;
+        }
+        if !frac.is_empty() {
+            return Some(
+                s.parse()
+                    .map_err(|_| Error::OutOfRange)
+                    .and_then(Self::from_seconds_f64),
+            );
+        }
+        Some(match s.parse::<i64>() {
+            Ok(n) if n.unsigned_abs() < 100_000_000_000 => Self::from_seconds(n),
+            Ok(n) => Self::from_millis(n),
+            Err(_) => Err(Error::OutOfRange),
+        })
+    }
+
+    // Serde support: serialize as ISO 8601 string and deserialize from it. This
+    // keeps the format stable and avoids pulling in chrono for serde when the
+    // `chrono` feature is not enabled.
+    #[cfg(feature = "serde")]
+    pub fn _serde_serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_iso())
+    }
+
+    #[cfg(feature = "serde")]
+    pub fn _serde_deserialize<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        DateTime::from_iso(&s).map_err(serde::de::Error::custom)
+    }
+
+    /// Formats with Luxon-style tokens such as `yyyy-MM-dd HH:mm`; see the
+    /// token table in the README.
+    ///
+    /// Text in single quotes is copied as-is, and `''` prints one quote, so
+    /// a pattern formats and parses ([`from_format`](Self::from_format)) the
+    /// same text. A quote that is never closed runs to the end of the pattern.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::DateTime;
+    ///
+    /// let dt = DateTime::from_iso("2025-10-30T14:00:00Z").unwrap();
+    /// assert_eq!(dt.to_format("h 'o''clock' a"), "2 o'clock pm");
+    /// assert_eq!(dt.to_format("yyyy-MM-dd'T'HH:mm"), "2025-10-30T14:00");
+    /// ```
+    pub fn to_format(&self, fmt: &str) -> String {
+        crate::format::format_datetime(&self.format_fields(), fmt)
+    }
+
+    /// Write formatted output directly into the provided writer (zero-allocation except the writer's buffer).
+    pub fn format_into<W: core::fmt::Write>(&self, w: &mut W, fmt: &str) -> core::fmt::Result {
+        crate::format::write_pattern(w, &self.format_fields(), fmt, &Locale::EN)
+    }
+
+    /// Formats into `buf` without allocating, for targets with no heap, and
+    /// returns the number of bytes written. The output is UTF-8.
+    ///
+    /// Tokens are as for [`to_format`](DateTime::to_format); quoted literals
+    /// are copied straight from the pattern. The one thing that can allocate
+    /// is a `ZZZZ` abbreviation from an installed
+    /// [`TimeZoneProvider`](crate::TimeZoneProvider) that builds it.
+    ///
+    /// # Errors
+    ///
+    /// [`Error::BufferTooSmall`] with the length needed when the output does
+    /// not fit. `buf` then holds as much of it as fitted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::{DateTime, Error};
+    ///
+    /// let dt = DateTime::from_iso("2025-10-30T14:30:00Z").unwrap();
+    /// let mut buf = [0u8; 32];
+    /// let len = dt.format_to_slice(&mut buf, "yyyy-MM-dd HH:mm").unwrap();
+    /// assert_eq!(&buf[..len], b"2025-10-30 14:30");
+    ///
+    /// let len = dt.format_to_slice(&mut buf, "h 'o''clock' a").unwrap();
+    /// assert_eq!(&buf[..len], b"2 o'clock pm");
+    ///
+    /// let mut small = [0u8; 8];
+    /// assert_eq!(
+    ///     dt.format_to_slice(&mut small, "yyyy-MM-dd HH:mm"),
+    ///     Err(Error::BufferTooSmall { needed: 16 })
+    /// );
+    /// ```
+    pub fn format_to_slice(&self, buf: &mut [u8], fmt: &str) -> Result<usize, Error> {
+        let mut w = crate::format::SliceWriter::new(buf);
+        let _ = self.format_into(&mut w, fmt);
+        w.finish()
+    }
+
+    /// Writes this DateTime as ISO 8601 into `buf` without allocating, laid
+    /// out as by [`to_iso_with`](DateTime::to_iso_with), and returns the
+    /// number of bytes written. 30 bytes always suffice.
+    ///
+    /// # Errors
+    ///
+    /// [`Error::BufferTooSmall`] with the length needed when the output does
+    /// not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::{DateTime, IsoOptions};
+    ///
+    /// let dt = DateTime::from_iso("2025-10-30T14:30:00Z").unwrap();
+    /// let mut buf = [0u8; 30];
+    /// let len = dt.iso_to_slice(&mut buf, IsoOptions::default()).unwrap();
+    /// assert_eq!(&buf[..len], b"2025-10-30T14:30:00.000Z");
+    /// ```
+    pub fn iso_to_slice(&self, buf: &mut [u8], options: IsoOptions) -> Result<usize, Error> {
+        let mut w = crate::format::SliceWriter::new(buf);
+        let _ =
+            crate::iso::write_datetime(&mut w, self.local_parts(), self.offset_secs(), &options);
+        w.finish()
+    }
+
+    /// Fills a message template with named fields, for user-facing text
+    /// where format tokens would be cryptic.
+    ///
+    /// Each `{name}` is replaced by a field on this DateTime's wall clock:
+    ///
+    /// | Placeholder | Example | | Placeholder | Example |
+    /// |---|---|---|---|---|
+    /// | `{year}` | 2025 | | `{hour}` | 14 |
+    /// | `{quarter}` | 4 | | `{hour12}` | 2 |
+    /// | `{month}` | 10 | | `{minute}` | 05 |
+    /// | `{month_name}` | October | | `{second}` | 09 |
+    /// | `{month_short}` | Oct | | `{millisecond}` | 250 |
+    /// | `{day}` | 30 | | `{meridiem}` | pm |
+    /// | `{day_ordinal}` | 30th | | `{offset}` | +00:00 |
+    /// | `{weekday}` | Thursday | | `{zone}` | UTC |
+    /// | `{weekday_short}` | Thu | | `{zone_abbreviation}` | UTC |
+    ///
+    /// Write `{{` and `}}` for literal braces.
+    ///
+    /// # Errors
+    ///
+    /// [`Error::Invalid`] for an unknown placeholder or a stray `}`, and
+    /// [`Error::UnexpectedEnd`] for a `{` that is never closed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::DateTime;
+    ///
+    /// let dt = DateTime::from_iso("2025-10-30T14:05:00Z").unwrap();
+    /// assert_eq!(
+    ///     dt.render("Backup finished on {weekday} at {hour}:{minute}").unwrap(),
+    ///     "Backup finished on Thursday at 14:05",
+    /// );
+    /// assert!(dt.render("{weekdya}").is_err());
+    /// ```
+    pub fn render(&self, template: &str) -> Result<String, Error> {
+        let mut out = String::with_capacity(template.len());
+        crate::format::render_template(&mut out, &self.format_fields(), template)?;
+        Ok(out)
+    }
+
+    /// Formats like [`to_format`](DateTime::to_format), with month, weekday
+    /// and meridiem names and ordinal suffixes taken from `locale`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::{DateTime, Locale};
+    ///
+    /// let dt = DateTime::from_iso("2025-10-30T14:30:00Z").unwrap();
+    /// assert_eq!(
+    ///     dt.to_format_with_locale("EEEE, MMMM do", &Locale::EN),
+    ///     "Thursday, October 30th"
+    /// );
+    /// # #[cfg(feature = "locales")]
+    /// assert_eq!(
+    ///     dt.to_format_with_locale("EEEE, d. MMMM yyyy", &Locale::DE),
+    ///     "Donnerstag, 30. Oktober 2025"
+    /// );
+    /// ```
+    pub fn to_format_with_locale(&self, fmt: &str, locale: &Locale) -> String {
+        let mut result = String::new();
+        let _ = crate::format::write_pattern(&mut result, &self.format_fields(), fmt, locale);
+        result
+    }
+
+    /// Formats with one of the Luxon-style presets such as
+    /// [`DATE_MED`](DateTime::DATE_MED), in this DateTime's zone.
+    ///
+    /// The preset may also be given by name (`"DATE_MED"`); any other string
+    /// is used as a [`to_format`](DateTime::to_format) pattern; prefer
+    /// [`to_locale`](DateTime::to_locale) for a type-checked choice. Presets with
+    /// a short offset print the zone abbreviation (`EDT`, or `UTC-4` when the
+    /// zone has none) and long ones the zone name.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::DateTime;
+    ///
+    /// let dt = DateTime::from_iso("2025-10-30T14:30:00Z").unwrap();
+    /// assert_eq!(dt.to_locale_string(DateTime::DATE_HUGE), "Thursday, October 30, 2025");
+    /// assert_eq!(dt.to_locale_string(DateTime::TIME_24_SIMPLE), "14:30");
+    /// assert_eq!(dt.to_locale_string("DATETIME_MED_WITH_WEEKDAY"), "Thu, Oct 30, 2025, 2:30 pm");
+    /// ```
+    pub fn to_locale_string(&self, preset: &str) -> String {
+        let mut result = String::new();
+        let _ = crate::format::write_pattern(
+            &mut result,
+            &self.format_fields(),
+            locale::preset_pattern(preset),
+            &Locale::EN,
+        );
+        result
+    }
+
+    /// Formats from typed options rather than a preset or pattern: a date
+    /// and a time style, the hour cycle and the locale for names.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::{DateTime, HourCycle, LocaleOptions, LocaleStyle};
+    ///
+    /// let dt = DateTime::from_iso("2025-10-30T14:30:05Z").unwrap();
+    /// let options = LocaleOptions {
+    ///     date_style: Some(LocaleStyle::Medium),
+    ///     time_style: Some(LocaleStyle::Short),
+    ///     hour_cycle: HourCycle::H23,
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(dt.to_locale(options), "Oct 30, 2025, 14:30");
+    /// assert_eq!(dt.to_locale(LocaleOptions::default()), "10/30/25");
+    /// ```
+    pub fn to_locale(&self, options: LocaleOptions) -> String {
+        let locale = options.locale.unwrap_or_default();
+        let mut result = String::new();
+        let _ = crate::format::write_pattern(
+            &mut result,
+            &self.format_fields(),
+            &locale::options_pattern(&options),
+            &locale,
+        );
+        result
+    }
+
+    /// Returns `true` if both DateTimes represent the same instant, whatever
+    /// zones they carry.
+    ///
+    /// This is what `==` checks as well; the named method just makes the intent
+    /// explicit at call sites that compare values from different zones.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::DateTime;
+    ///
+    /// let utc = DateTime::from_iso("2025-10-30T12:00:00Z").unwrap();
+    /// let tokyo = utc.set_zone("Asia/Tokyo");
+    /// assert!(tokyo.same_instant_as(&utc));
+    /// assert_eq!(tokyo, utc);
+    /// ```
+    pub fn same_instant_as(&self, other: &DateTime) -> bool {
+        self == other
+    }
+
+    /// Milliseconds since the Unix epoch. The zone does not affect the result.
+    pub fn to_millis(&self) -> i64 {
+        #[cfg(feature = "chrono")]
+        {
+            self.inner.timestamp_millis()
+        }
+
+        #[cfg(not(feature = "chrono"))]
+        {
+            self.timestamp_ms
+        }
+    }
+
+    /// Whole seconds since the Unix epoch, rounded towards negative infinity.
+    pub fn to_seconds(&self) -> i64 {
+        self.to_millis().div_euclid(1000)
+    }
+
+    /// Seconds since the Unix epoch including the millisecond fraction.
+    pub fn to_seconds_f64(&self) -> f64 {
+        self.to_millis() as f64 / 1000.0
+    }
+
+    /// The earlier of two DateTimes, like Luxon's `DateTime.min`. Ties return `a`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::DateTime;
+    ///
+    /// let a = DateTime::from_iso("2025-10-30T00:00:00Z").unwrap();
+    /// let b = DateTime::from_iso("2025-11-01T00:00:00Z").unwrap();
+    /// assert_eq!(DateTime::min(a, b), a);
+    /// assert_eq!(DateTime::max(a, b), b);
+    /// ```
+    pub fn min(a: DateTime, b: DateTime) -> DateTime {
+        if b < a {
+            b
+        } else {
+            a
+        }
+    }
+
+    /// The later of two DateTimes, like Luxon's `DateTime.max`. Ties return `a`.
+    pub fn max(a: DateTime, b: DateTime) -> DateTime {
+        if b > a {
+            b
+        } else {
+            a
+        }
+    }
+
+    /// The interval from this DateTime to `other`, like Luxon's `until`.
+    ///
+    /// The endpoints are ordered, so the interval always runs from the earlier
+    /// instant to the later one whichever way round they are given. Each
+    /// endpoint keeps its own zone.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::DateTime;
+    ///
+    /// let start = DateTime::from_iso("2025-10-01T00:00:00Z").unwrap();
+    /// let end = DateTime::from_iso("2025-10-08T00:00:00Z").unwrap();
+    ///
+    /// let week = start.until(&end);
+    /// assert_eq!(week.length("days").as_unit("days"), 7);
+    /// assert_eq!(end.until(&start).start(), &start);
+    /// ```
+    pub fn until(&self, other: &DateTime) -> Interval {
+        if other < self {
+            Interval::from_date_times(*other, *self)
+        } else {
+            Interval::from_date_times(*self, *other)
+        }
+    }
+
+    /// Whole years from this DateTime (a birth date, say) to `now`, counting an
+    /// anniversary only once it has been reached.
+    ///
+    /// Anniversaries are calendar dates in this DateTime's zone at the same
+    /// time of day, so leap years are handled exactly. A February 29th date
+    /// has its anniversary on February 28th in common years. The result is
+    /// negative if `now` is earlier than this DateTime.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::DateTime;
+    ///
+    /// let born = DateTime::from_iso("2000-02-29T00:00:00Z").unwrap();
+    /// let eve = DateTime::from_iso("2025-02-27T23:59:59Z").unwrap();
+    /// let day = DateTime::from_iso("2025-02-28T00:00:00Z").unwrap();
+    /// assert_eq!(born.age_in_years(&eve), 24);
+    /// assert_eq!(born.age_in_years(&day), 25);
+    /// ```
+    pub fn age_in_years(&self, now: &DateTime) -> i32 {
+        let now_year = DateTime {
+            zone: self.zone,
+            ..*now
+        }
+        .year();
+        let mut years = now_year - self.year();
+        match self.anniversary_in(now_year) {
+            Some(anniversary) if years > 0 && anniversary > *now => years -= 1,
+            Some(anniversary) if years < 0 && anniversary < *now => years += 1,
+            _ => {}
+        }
+        years
+    }
+
+    /// The first anniversary of this DateTime strictly after `now`, or this
+    /// DateTime itself if `now` is earlier.
+    ///
+    /// Uses the same calendar rules as [`age_in_years`](Self::age_in_years).
+    /// Returns `None` if the anniversary would fall after year 9999.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::DateTime;
+    ///
+    /// let born = DateTime::from_iso("2000-02-29T00:00:00Z").unwrap();
+    /// let now = DateTime::from_iso("2025-10-30T00:00:00Z").unwrap();
+    /// let next = born.next_anniversary(&now).unwrap();
+    /// assert_eq!(next.to_format("yyyy-MM-dd"), "2026-02-28");
+    /// ```
+    pub fn next_anniversary(&self, now: &DateTime) -> Option<DateTime> {
+        let now_year = DateTime {
+            zone: self.zone,
+            ..*now
+        }
+        .year();
+        let year = now_year.max(self.year());
+        (year..=year + 1)
+            .filter_map(|y| self.anniversary_in(y))
+            .find(|anniversary| anniversary > now)
+    }
+
+    // Same month, day and time of day in `year`, with February 29th clamped.
+    fn anniversary_in(&self, year: i32) -> Option<DateTime> {
+        self.set(&[("year", year as i64)]).ok()
+    }
+
+    /// Bounds this DateTime to the closed range covered by `interval`.
+    ///
+    /// Instants before the interval become its start and instants after it its
+    /// end; the zone of `self` is kept either way.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::{DateTime, Interval};
+    ///
+    /// let window = Interval::from_date_times(
+    ///     DateTime::from_iso("2025-10-01T00:00:00Z").unwrap(),
+    ///     DateTime::from_iso("2025-10-31T00:00:00Z").unwrap(),
+    /// );
+    /// let late = DateTime::from_iso("2025-12-25T00:00:00Z").unwrap();
+    /// assert_eq!(late.clamp(&window), *window.end());
+    /// ```
+    pub fn clamp(self, interval: &Interval) -> DateTime {
+        let (lo, hi) = if interval.start() <= interval.end() {
+            (interval.start(), interval.end())
+        } else {
+            (interval.end(), interval.start())
+        };
+        let bound = if self < *lo {
+            lo
+        } else if self > *hi {
+            hi
+        } else {
+            return self;
+        };
+        DateTime {
+            zone: self.zone,
+            ..*bound
+        }
+    }
+
+    /// The Julian day number of this instant, including the fraction of the
+    /// day elapsed since noon UTC. See [`from_julian_day`](Self::from_julian_day).
+    pub fn to_julian_day(&self) -> f64 {
+        self.to_millis() as f64 / 86_400_000.0 + UNIX_EPOCH_JULIAN_DAY
+    }
+
+    /// Describes this DateTime relative to `base` in English, such as
+    /// `"3 days ago"`, `"in 2 hours"` or `"just now"`, like Luxon's `toRelative`.
+    ///
+    /// The largest unit from years down to seconds with a count of at least
+    /// one is used, and the count is truncated toward zero. Months and years
+    /// follow the calendar in `base`'s zone. Anything under a second is
+    /// "just now". See [`to_relative_with`](Self::to_relative_with) for
+    /// rounding and a largest unit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::DateTime;
+    ///
+    /// let now = DateTime::from_iso("2025-10-30T12:00:00Z").unwrap();
+    /// let at = |s| DateTime::from_iso(s).unwrap().to_relative(&now);
+    ///
+    /// assert_eq!(at("2025-10-27T09:00:00Z"), "3 days ago");
+    /// assert_eq!(at("2025-10-30T14:59:00Z"), "in 2 hours");
+    /// assert_eq!(at("2025-09-30T12:00:00Z"), "1 month ago");
+    /// assert_eq!(at("2025-10-30T12:00:00.400Z"), "just now");
+    /// ```
+    pub fn to_relative(&self, base: &DateTime) -> String {
+        self.to_relative_with(base, RelativeOptions::default())
+    }
+
+    /// Like [`to_relative`](Self::to_relative), with rounding and a largest
+    /// unit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::{DateTime, RelativeOptions};
+    ///
+    /// let now = DateTime::from_iso("2025-10-30T12:00:00Z").unwrap();
+    /// let then = DateTime::from_iso("2025-09-14T00:00:00Z").unwrap();
+    ///
+    /// assert_eq!(then.to_relative(&now), "1 month ago");
+    /// let rounded = RelativeOptions { round: true, ..Default::default() };
+    /// assert_eq!(then.to_relative_with(&now, rounded), "2 months ago");
+    /// let days = RelativeOptions { max_unit: Some("days"), ..Default::default() };
+    /// assert_eq!(then.to_relative_with(&now, days), "46 days ago");
+    /// ```
+    pub fn to_relative_with(&self, base: &DateTime, options: RelativeOptions) -> String {
+        crate::relative::relative(self, base, options)
+    }
 
-            let total_secs =
-                weeks * 7 * 86400 + days * 86400 + hours * 3600 + minutes * 60 + seconds;
-            dt += chrono::Duration::seconds(total_secs);
-            dt += chrono::Duration::milliseconds(millis);
+    /// Describes this DateTime's calendar date relative to `base`'s, such as
+    /// `"today"`, `"yesterday"`, `"in 3 days"`, `"next month"` or
+    /// `"2 years ago"`, like Luxon's `toRelativeCalendar`.
+    ///
+    /// Only calendar years, months and days in `base`'s zone are compared, so
+    /// 23:59 and 00:01 the next morning are "tomorrow", and December 31st and
+    /// January 1st are "next year". Compare [`to_relative`](Self::to_relative),
+    /// which measures elapsed time.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::DateTime;
+    ///
+    /// let now = DateTime::from_iso("2025-10-30T23:30:00Z").unwrap();
+    /// let at = |s| DateTime::from_iso(s).unwrap().to_relative_calendar(&now);
+    ///
+    /// assert_eq!(at("2025-10-31T00:15:00Z"), "tomorrow");
+    /// assert_eq!(at("2025-10-30T00:00:00Z"), "today");
+    /// assert_eq!(at("2025-10-26T12:00:00Z"), "4 days ago");
+    /// assert_eq!(at("2025-11-01T00:00:00Z"), "next month");
+    /// assert_eq!(at("2023-06-01T00:00:00Z"), "2 years ago");
+    /// ```
+    pub fn to_relative_calendar(&self, base: &DateTime) -> String {
+        crate::relative::calendar(self, base)
+    }
 
-            DateTime {
-                inner: dt,
-                #[cfg(feature = "tz")]
-                zone: self.zone,
-                #[cfg(not(feature = "tz"))]
-                _zone_applied: false,
-            }
+    // This instant, read in `other`'s zone.
+    pub(crate) fn with_zone_of(&self, other: &DateTime) -> Self {
+        DateTime {
+            zone: other.zone,
+            ..*self
         }
+    }
 
-        #[cfg(not(feature = "chrono"))]
-        {
-            // Accurate month/year handling without chrono.
-            // 1) Decompose current timestamp into components
-            let (mut y, mut m, mut d, h, mi, s, ms) =
-                crate::format::decompose_timestamp_ms(self.timestamp_ms);
+    /// The time from `other` to this DateTime in `unit`, with 30-day months
+    /// and 365-day years; 0 for an unknown unit. See [`diff_in`](Self::diff_in)
+    /// for the typed form.
+    pub fn diff(&self, other: &DateTime, unit: &str) -> f64 {
+        match unit.parse::<Unit>() {
+            Ok(unit) => self.diff_in(other, unit),
+            Err(_) => 0.0,
+        }
+    }
 
-            // Apply years as months offset
-            let total_months: i64 = months as i64 + years as i64 * 12;
-            if total_months != 0 {
-                let (ny, nm, nd) = add_months_to_ymd(y, m, d, total_months);
-                y = ny;
-                m = nm;
-                d = nd;
-            }
+    /// The time from `other` to this DateTime in `unit`, with 30-day months
+    /// and 365-day years; negative when `other` is later. Use
+    /// [`Duration::between`] for exact calendar months and years.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::{DateTime, Unit};
+    ///
+    /// let start = DateTime::from_iso("2025-10-30T00:00:00Z").unwrap();
+    /// let end = DateTime::from_iso("2025-10-31T12:00:00Z").unwrap();
+    /// assert_eq!(end.diff_in(&start, Unit::Days), 1.5);
+    /// assert_eq!(start.diff_in(&end, Unit::Hours), -36.0);
+    /// ```
+    pub fn diff_in(&self, other: &DateTime, unit: Unit) -> f64 {
+        (self.to_millis() - other.to_millis()) as f64 / unit.length_ms() as f64
+    }
+
+    /// The wall-clock components as a map, mirroring [`Duration::to_object`].
+    ///
+    /// Keys are `year`, `month`, `day`, `hour`, `minute`, `second` and
+    /// `millisecond`, read in this DateTime's zone. When a zone is set, an
+    /// `offset` entry gives its UTC offset in minutes at this instant (as
+    /// Luxon's `offset`); the zone itself is not in the map, as its values
+    /// are all numbers. Without a zone the map feeds straight back into
+    /// [`from_object`](Self::from_object); with one, drop `offset` and pass
+    /// the rest with [`zone_name`](Self::zone_name) to
+    /// [`from_object_in`](Self::from_object_in).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::DateTime;
+    ///
+    /// let dt = DateTime::from_iso("2025-10-30T14:30:00Z").unwrap();
+    /// let obj = dt.to_object();
+    /// assert_eq!(obj["year"], 2025);
+    /// assert_eq!(obj["hour"], 14);
+    /// assert!(!obj.contains_key("offset"));
+    ///
+    /// let tokyo = dt.set_zone("Asia/Tokyo").to_object();
+    /// assert_eq!(tokyo["hour"], 23);
+    /// assert_eq!(tokyo["offset"], 540);
+    /// ```
+    ///
+    /// Rebuilding a zoned value:
+    ///
+    /// ```rust
+    /// use tempotime::{DateTime, Disambiguation};
+    ///
+    /// let dt = DateTime::from_iso("2025-10-30T14:30:00Z").unwrap().set_zone("Asia/Tokyo");
+    /// let obj = dt.to_object();
+    /// let fields: Vec<(&str, i64)> =
+    ///     obj.iter().filter(|(k, _)| *k != "offset").map(|(k, v)| (k.as_str(), *v)).collect();
+    /// let zone = dt.zone_name().unwrap();
+    /// let back = DateTime::from_object_in(&fields, zone, Disambiguation::Reject).unwrap();
+    /// assert_eq!(back, dt);
+    /// ```
+    pub fn to_object(&self) -> HashMap<String, i64> {
+        let (y, m, d, h, mi, s, ms) = self.local_parts();
+        let mut map = HashMap::new();
+        map.insert("year".to_string(), y as i64);
+        map.insert("month".to_string(), m as i64);
+        map.insert("day".to_string(), d as i64);
+        map.insert("hour".to_string(), h as i64);
+        map.insert("minute".to_string(), mi as i64);
+        map.insert("second".to_string(), s as i64);
+        map.insert("millisecond".to_string(), ms as i64);
+        if self.zone.is_some() {
+            map.insert("offset".to_string(), (self.offset_secs() / 60) as i64);
+        }
+        map
+    }
+
+    /// The calendar year in this DateTime's zone.
+    ///
+    /// All component accessors read the wall clock in the attached zone (UTC
+    /// unless [`set_zone`](Self::set_zone) was used) and behave identically in
+    /// zero-deps and chrono builds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::DateTime;
+    ///
+    /// let dt = DateTime::from_iso("2025-10-30T14:05:09.250Z").unwrap();
+    /// assert_eq!(dt.year(), 2025);
+    /// assert_eq!(dt.month(), 10);
+    /// assert_eq!(dt.day(), 30);
+    /// assert_eq!(dt.hour(), 14);
+    /// assert_eq!(dt.minute(), 5);
+    /// assert_eq!(dt.second(), 9);
+    /// ```
+    pub fn year(&self) -> i32 {
+        self.local_parts().0
+    }
 
-            // Recompute base timestamp after year/month/day adjustments
-            let base_ts = Self::compute_timestamp(y, m, d, h, mi, s, ms);
+    /// The month of the year, 1–12.
+    pub fn month(&self) -> u32 {
+        self.local_parts().1
+    }
 
-            // Apply weeks/days/hours/minutes/seconds/millis as ms offset
-            let small_ms = (weeks * 7 * 86400 * 1000)
-                + (days * 86400 * 1000)
-                + (hours * 3600 * 1000)
-                + (minutes * 60 * 1000)
-                + (seconds * 1000)
-                + millis;
+    /// The day of the month, 1–31.
+    pub fn day(&self) -> u32 {
+        self.local_parts().2
+    }
 
-            DateTime {
-                timestamp_ms: base_ts + small_ms,
-                #[cfg(feature = "tz")]
-                zone: self.zone,
-                #[cfg(not(feature = "tz"))]
-                _zone_applied: false,
-            }
-        }
+    /// The hour of the day, 0–23.
+    pub fn hour(&self) -> u32 {
+        self.local_parts().3
     }
 
-    pub fn minus(self, dur: &Duration) -> Self {
-        let (years, months, weeks, days, hours, minutes, seconds, millis) = dur.components();
-        let negated = Duration::from_object(&[
-            ("years", -years),
-            ("months", -months),
-            ("weeks", -weeks),
-            ("days", -days),
-            ("hours", -hours),
-            ("minutes", -minutes),
-            ("seconds", -seconds),
-            ("milliseconds", -millis),
-        ]);
-        self.plus(&negated)
+    /// The minute of the hour, 0–59.
+    pub fn minute(&self) -> u32 {
+        self.local_parts().4
     }
 
-    pub fn start_of(self, unit: &str) -> Self {
-        #[cfg(feature = "chrono")]
-        {
-            let dt = match unit {
-                "year" => Utc
-                    .with_ymd_and_hms(self.inner.year(), 1, 1, 0, 0, 0)
-                    .single()
-                    .unwrap(),
-                "month" => Utc
-                    .with_ymd_and_hms(self.inner.year(), self.inner.month(), 1, 0, 0, 0)
-                    .single()
-                    .unwrap(),
-                "day" => Utc
-                    .with_ymd_and_hms(
-                        self.inner.year(),
-                        self.inner.month(),
-                        self.inner.day(),
-                        0,
-                        0,
-                        0,
-                    )
-                    .single()
-                    .unwrap(),
-                "hour" => Utc
-                    .with_ymd_and_hms(
-                        self.inner.year(),
-                        self.inner.month(),
-                        self.inner.day(),
-                        self.inner.hour(),
-                        0,
-                        0,
-                    )
-                    .single()
-                    .unwrap(),
-                "minute" => Utc
-                    .with_ymd_and_hms(
-                        self.inner.year(),
-                        self.inner.month(),
-                        self.inner.day(),
-                        self.inner.hour(),
-                        self.inner.minute(),
-                        0,
-                    )
-                    .single()
-                    .unwrap(),
-                "second" => self.inner,
-                _ => self.inner,
-            };
-            DateTime {
-                inner: dt,
-                #[cfg(feature = "tz")]
-                zone: self.zone,
-                #[cfg(not(feature = "tz"))]
-                _zone_applied: false,
-            }
+    /// The second of the minute, 0–59.
+    pub fn second(&self) -> u32 {
+        self.local_parts().5
+    }
+
+    /// The millisecond of the second, 0–999.
+    pub fn millisecond(&self) -> u32 {
+        self.local_parts().6
+    }
+
+    /// The day of the week.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::{DateTime, Weekday};
+    ///
+    /// let dt = DateTime::from_iso("2025-10-30T14:30:00Z").unwrap();
+    /// assert_eq!(dt.weekday(), Weekday::Thursday);
+    /// assert_eq!(dt.day_of_year(), 303);
+    /// assert_eq!(dt.quarter(), 4);
+    /// ```
+    pub fn weekday(&self) -> Weekday {
+        let (y, m, d) = self.local_ymd();
+        crate::calendar::weekday_from_days(crate::calendar::days_from_civil(y, m, d))
+    }
+
+    /// The day of the year, 1–366.
+    pub fn day_of_year(&self) -> u32 {
+        let (y, m, d) = self.local_ymd();
+        crate::calendar::day_of_year(y, m, d)
+    }
+
+    /// The quarter of the year, 1–4.
+    pub fn quarter(&self) -> u32 {
+        (self.month() - 1) / 3 + 1
+    }
+
+    /// Returns `true` if this date falls on the weekend.
+    ///
+    /// The weekend is Saturday–Sunday unless reconfigured with
+    /// [`Settings::set_weekend`]. The check uses the wall-clock date in the
+    /// DateTime's zone.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::DateTime;
+    ///
+    /// let saturday = DateTime::from_iso("2025-11-01T09:00:00Z").unwrap();
+    /// assert!(saturday.is_weekend());
+    /// ```
+    pub fn is_weekend(&self) -> bool {
+        Settings::weekend().contains(self.weekday())
+    }
+
+    /// Returns `true` if this date is a working day, i.e. not part of the
+    /// weekend configured in [`Settings`].
+    pub fn is_weekday(&self) -> bool {
+        !self.is_weekend()
+    }
+
+    /// The number of days in this DateTime's month, 28–31.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::DateTime;
+    ///
+    /// let dt = DateTime::from_iso("2024-02-10T00:00:00Z").unwrap();
+    /// assert_eq!(dt.days_in_month(), 29);
+    /// assert_eq!(dt.days_in_year(), 366);
+    /// assert!(dt.is_in_leap_year());
+    /// ```
+    pub fn days_in_month(&self) -> u32 {
+        let (y, m, _) = self.local_ymd();
+        crate::calendar::days_in_month(y, m)
+    }
+
+    /// The number of days in this DateTime's year, 365 or 366.
+    pub fn days_in_year(&self) -> u32 {
+        if self.is_in_leap_year() {
+            366
+        } else {
+            365
         }
+    }
 
-        #[cfg(not(feature = "chrono"))]
-        {
-            let (y, m, d, h, mi, s, ms) = crate::format::decompose_timestamp_ms(self.timestamp_ms);
-            let (ny, nm, nd, nh, nmi, ns, nms) = match unit {
-                "year" => (y, 1, 1, 0, 0, 0, 0),
-                "month" => (y, m, 1, 0, 0, 0, 0),
-                "day" => (y, m, d, 0, 0, 0, 0),
-                "hour" => (y, m, d, h, 0, 0, 0),
-                "minute" => (y, m, d, h, mi, 0, 0),
-                "second" => (y, m, d, h, mi, s, 0),
-                _ => (y, m, d, h, mi, s, ms),
-            };
-            DateTime {
-                timestamp_ms: Self::compute_timestamp(ny, nm, nd, nh, nmi, ns, nms),
-                #[cfg(not(feature = "tz"))]
-                _zone_applied: false,
-            }
+    /// Returns `true` if this DateTime's year is a Gregorian leap year.
+    pub fn is_in_leap_year(&self) -> bool {
+        crate::calendar::is_leap_year(self.year())
+    }
+
+    /// ISO-8601 week number, 1–53.
+    ///
+    /// Weeks start on Monday and week 1 is the week containing January 4th,
+    /// so the first days of January can belong to the previous year's last
+    /// week (see [`week_year`](Self::week_year)). Use
+    /// [`week_number_with`](Self::week_number_with) for other conventions.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::DateTime;
+    ///
+    /// let dt = DateTime::from_iso("2021-01-01T12:00:00Z").unwrap();
+    /// assert_eq!(dt.week_number(), 53);
+    /// assert_eq!(dt.week_year(), 2020);
+    /// assert_eq!(dt.to_format("kkkk WW"), "2020 53");
+    /// ```
+    pub fn week_number(&self) -> u32 {
+        self.week_number_with(WeekRule::ISO)
+    }
+
+    /// The ISO-8601 week-numbering year, which can differ from
+    /// [`year`](Self::year) around New Year.
+    pub fn week_year(&self) -> i32 {
+        self.week_year_with(WeekRule::ISO)
+    }
+
+    /// Week number of this date under the given numbering rule.
+    ///
+    /// See [`WeekRule`] for the available conventions.
+    pub fn week_number_with(&self, rule: WeekRule) -> u32 {
+        let (y, m, d) = self.local_ymd();
+        rule.week_date(y, m, d).1
+    }
+
+    /// The year the week containing this date belongs to under the given rule.
+    ///
+    /// Around New Year this can differ from the calendar year.
+    pub fn week_year_with(&self, rule: WeekRule) -> i32 {
+        let (y, m, d) = self.local_ymd();
+        rule.week_date(y, m, d).0
+    }
+
+    /// Week number under the process-wide rule from [`Settings::week_rule`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::{DateTime, Settings, WeekRule};
+    ///
+    /// let dt = DateTime::from_iso("2023-01-01T12:00:00Z").unwrap();
+    /// Settings::set_week_rule(WeekRule::US);
+    /// assert_eq!(dt.local_week_number(), 1);
+    /// Settings::set_week_rule(WeekRule::ISO);
+    /// assert_eq!(dt.local_week_number(), 52);
+    /// ```
+    pub fn local_week_number(&self) -> u32 {
+        self.week_number_with(Settings::week_rule())
+    }
+
+    /// Week-year under the process-wide rule from [`Settings::week_rule`].
+    pub fn local_week_year(&self) -> i32 {
+        self.week_year_with(Settings::week_rule())
+    }
+
+    /// Returns `true` if this DateTime is before the current moment.
+    ///
+    /// "Now" comes from [`DateTime::now`], so it follows any clock installed
+    /// with [`Settings::set_now`].
+    pub fn is_past(&self) -> bool {
+        *self < Self::now()
+    }
+
+    /// Returns `true` if this DateTime is after the current moment.
+    pub fn is_future(&self) -> bool {
+        *self > Self::now()
+    }
+
+    /// Returns `true` if this DateTime falls on the current calendar day.
+    ///
+    /// Both dates are compared as wall-clock dates in this DateTime's zone, so a
+    /// Tokyo-zoned value is "today" according to Tokyo's calendar.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::{DateTime, Duration};
+    ///
+    /// assert!(DateTime::now().is_today());
+    /// assert!(!DateTime::now().plus(&Duration::from_object(&[("days", 2)])).is_today());
+    /// ```
+    pub fn is_today(&self) -> bool {
+        self.local_ymd() == self.now_in_zone().local_ymd()
+    }
+
+    /// Returns `true` if this DateTime falls in the current Monday-to-Sunday week.
+    pub fn is_this_week(&self) -> bool {
+        fn week_start(dt: &DateTime) -> i64 {
+            let (y, m, d) = dt.local_ymd();
+            let days = crate::calendar::days_from_civil(y, m, d);
+            days - crate::calendar::weekday_from_days(days) as i64
         }
+        week_start(self) == week_start(&self.now_in_zone())
+    }
+
+    /// Returns `true` if this DateTime falls in the current calendar month.
+    pub fn is_this_month(&self) -> bool {
+        let (y, m, _) = self.local_ymd();
+        let (ny, nm, _) = self.now_in_zone().local_ymd();
+        (y, m) == (ny, nm)
+    }
+
+    // The current instant, carrying this DateTime's zone so calendar
+    // comparisons happen on the same wall clock.
+    fn now_in_zone(&self) -> Self {
+        let mut now = Self::now();
+        now.zone = self.zone;
+        now
     }
 
-    pub fn end_of(self, unit: &str) -> Self {
+    // Wall-clock components in this DateTime's zone, in the same order as
+    // `decompose_timestamp_ms`.
+    fn local_parts(&self) -> (i32, u32, u32, u32, u32, u32, u32) {
         #[cfg(feature = "chrono")]
         {
-            let dt = match unit {
-                "year" => {
-                    Utc.with_ymd_and_hms(self.inner.year(), 12, 31, 23, 59, 59)
-                        .single()
-                        .unwrap()
-                        + chrono::Duration::milliseconds(999)
-                }
-                "month" => {
-                    let next_month = if self.inner.month() == 12 {
-                        Utc.with_ymd_and_hms(self.inner.year() + 1, 1, 1, 0, 0, 0)
-                            .single()
-                            .unwrap()
-                    } else {
-                        Utc.with_ymd_and_hms(self.inner.year(), self.inner.month() + 1, 1, 0, 0, 0)
-                            .single()
-                            .unwrap()
-                    };
-                    next_month - chrono::Duration::milliseconds(1)
-                }
-                "day" => {
-                    Utc.with_ymd_and_hms(
-                        self.inner.year(),
-                        self.inner.month(),
-                        self.inner.day(),
-                        23,
-                        59,
-                        59,
-                    )
-                    .single()
-                    .unwrap()
-                        + chrono::Duration::milliseconds(999)
-                }
-                "hour" => {
-                    Utc.with_ymd_and_hms(
-                        self.inner.year(),
-                        self.inner.month(),
-                        self.inner.day(),
-                        self.inner.hour(),
-                        59,
-                        59,
-                    )
-                    .single()
-                    .unwrap()
-                        + chrono::Duration::milliseconds(999)
-                }
-                "minute" => {
-                    Utc.with_ymd_and_hms(
-                        self.inner.year(),
-                        self.inner.month(),
-                        self.inner.day(),
-                        self.inner.hour(),
-                        self.inner.minute(),
-                        59,
-                    )
-                    .single()
-                    .unwrap()
-                        + chrono::Duration::milliseconds(999)
-                }
-                _ => self.inner,
-            };
-            DateTime {
-                inner: dt,
-                #[cfg(feature = "tz")]
-                zone: self.zone,
-            }
+            let offset = self.offset_secs();
+            let local = self.inner.naive_utc() + chrono::Duration::seconds(offset as i64);
+            (
+                local.year(),
+                local.month(),
+                local.day(),
+                local.hour(),
+                local.minute(),
+                local.second(),
+                local.nanosecond() / 1_000_000,
+            )
         }
 
         #[cfg(not(feature = "chrono"))]
         {
-            let (y, m, d, h, mi, s, _) = crate::format::decompose_timestamp_ms(self.timestamp_ms);
-            let (ny, nm, nd, nh, nmi, ns, nms) = match unit {
-                "year" => (y, 12, 31, 23, 59, 59, 999),
-                "month" => {
-                    // compute last day of month
-                    let next = if m == 12 {
-                        (y + 1, 1, 1)
-                    } else {
-                        (y, m + 1, 1)
-                    };
-                    let last_day_ts =
-                        Self::compute_timestamp(next.0, next.1, next.2, 0, 0, 0, 0) - 1;
-                    let (yy, mm, dd, hh, mn, ss, ms) =
-                        crate::format::decompose_timestamp_ms(last_day_ts);
-                    (yy, mm, dd, hh, mn, ss, ms)
-                }
-                "day" => (y, m, d, 23, 59, 59, 999),
-                "hour" => (y, m, d, h, 59, 59, 999),
-                "minute" => (y, m, d, h, mi, 59, 999),
-                _ => (y, m, d, h, mi, s, 0),
-            };
-            DateTime {
-                timestamp_ms: Self::compute_timestamp(ny, nm, nd, nh, nmi, ns, nms),
-                #[cfg(not(feature = "tz"))]
-                _zone_applied: false,
-            }
+            crate::format::decompose_timestamp_ms(self.local_ms())
         }
     }
 
-    pub fn to_iso(&self) -> String {
-        #[cfg(feature = "chrono")]
-        {
-            #[cfg(feature = "tz")]
-            if let Some(tz) = self.zone {
-                return self.inner.with_timezone(&tz).to_rfc3339();
-            }
-            self.inner.to_rfc3339()
+    // The values `to_format` renders: wall-clock fields in this DateTime's
+    // zone, with its offset and zone.
+    pub(crate) fn format_fields(&self) -> crate::format::Fields {
+        let (year, month, day, hour, minute, second, millis) = self.local_parts();
+        crate::format::Fields {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+            millis,
+            weekday: crate::calendar::weekday_from_days(crate::calendar::days_from_civil(
+                year, month, day,
+            )) as u32,
+            offset_secs: self.offset_secs(),
+            zone: self.zone,
+            instant_ms: self.to_millis(),
         }
+    }
 
-        #[cfg(not(feature = "chrono"))]
-        {
-            let (y, m, d, h, mi, s, _) = crate::format::decompose_timestamp_ms(self.timestamp_ms);
-            format!("{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z", y, m, d, h, mi, s)
-        }
+    // Seconds east of UTC at this instant; 0 without a zone.
+    pub(crate) fn offset_secs(&self) -> i32 {
+        self.zone
+            .map_or(0, |zone| zone.offset_secs_at(self.to_millis()))
     }
 
-    // Serde support: serialize as ISO 8601 string and deserialize from it. This
-    // keeps the format stable and avoids pulling in chrono for serde when the
-    // `chrono` feature is not enabled.
-    #[cfg(feature = "serde")]
-    pub fn _serde_serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        serializer.serialize_str(&self.to_iso())
+    // The wall-clock time in this DateTime's zone, as milliseconds since the
+    // epoch read as if it were UTC.
+    pub(crate) fn wall_clock_ms(&self) -> i64 {
+        self.to_millis()
+            .saturating_add(self.offset_secs() as i64 * 1000)
     }
 
-    #[cfg(feature = "serde")]
-    pub fn _serde_deserialize<'de, D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        let s = String::deserialize(deserializer)?;
-        DateTime::from_iso(&s).map_err(serde::de::Error::custom)
+    fn local_ymd(&self) -> (i32, u32, u32) {
+        let (y, m, d, ..) = self.local_parts();
+        (y, m, d)
     }
 
-    pub fn to_format(&self, fmt: &str) -> String {
-        #[cfg(feature = "chrono")]
-        {
-            #[cfg(feature = "tz")]
-            if let Some(tz) = self.zone {
-                let _local_dt = self.inner.with_timezone(&tz);
-                return format_datetime(&self.inner, fmt);
-            }
-            format_datetime(&self.inner, fmt)
-        }
+    // Milliseconds to add to the instant to read it as wall-clock time.
+    #[cfg(not(feature = "chrono"))]
+    fn offset_ms(&self) -> i64 {
+        self.offset_secs() as i64 * 1000
+    }
 
-        #[cfg(not(feature = "chrono"))]
-        {
-            crate::format::format_datetime_from_ts(self.timestamp_ms, fmt)
-        }
+    #[cfg(not(feature = "chrono"))]
+    fn local_ms(&self) -> i64 {
+        self.timestamp_ms.saturating_add(self.offset_ms())
     }
 
-    /// Write formatted output directly into the provided writer (zero-allocation except the writer's buffer).
-    pub fn format_into<W: core::fmt::Write>(&self, w: &mut W, fmt: &str) -> core::fmt::Result {
+    // Instants outside years -9999 to 9999 are clamped to that range, so
+    // both backends agree and every DateTime prints as something `from_iso`
+    // reads back.
+    pub(crate) fn from_epoch_ms(ms: i64) -> Self {
+        let ms = ms.clamp(MIN_MS, MAX_MS);
+
         #[cfg(feature = "chrono")]
         {
-            #[cfg(feature = "tz")]
-            if let Some(tz) = self.zone {
-                let _local_dt = self.inner.with_timezone(&tz);
-                return crate::format::format_datetime_into(w, &self.inner, fmt);
+            DateTime {
+                inner: Utc.timestamp_millis_opt(ms).single().unwrap_or_default(),
+                zone: None,
             }
-            return crate::format::format_datetime_into(w, &self.inner, fmt);
         }
 
         #[cfg(not(feature = "chrono"))]
         {
-            return crate::format::format_datetime_from_ts_into(w, self.timestamp_ms, fmt);
+            DateTime {
+                timestamp_ms: ms,
+                zone: None,
+            }
         }
     }
 
-    pub fn to_locale_string(&self, preset: &str) -> String {
-        #[cfg(feature = "chrono")]
-        {
-            locale::to_locale_string(&self.inner, preset)
-        }
-        #[cfg(not(feature = "chrono"))]
-        {
-            locale::to_locale_string_from_ts(self.timestamp_ms, preset)
-        }
+    // The DateTime in this one's zone whose wall clock reads `local_ms`, with
+    // repeated and skipped times resolved as `Disambiguation::Shift` does.
+    fn at_wall_clock(&self, local_ms: i64) -> Self {
+        Self::from_epoch_ms(self.instant_at_wall_clock(local_ms)).with_zone_of(self)
     }
 
-    pub fn diff(&self, other: &DateTime, unit: &str) -> f64 {
-        #[cfg(feature = "chrono")]
-        let diff_ms = (self.inner.timestamp_millis() - other.inner.timestamp_millis()) as f64;
-        #[cfg(not(feature = "chrono"))]
-        let diff_ms = (self.timestamp_ms - other.timestamp_ms) as f64;
-        match unit {
-            "milliseconds" | "millisecond" => diff_ms,
-            "seconds" | "second" => diff_ms / 1000.0,
-            "minutes" | "minute" => diff_ms / (1000.0 * 60.0),
-            "hours" | "hour" => diff_ms / (1000.0 * 60.0 * 60.0),
-            "days" | "day" => diff_ms / (1000.0 * 60.0 * 60.0 * 24.0),
-            "weeks" | "week" => diff_ms / (1000.0 * 60.0 * 60.0 * 24.0 * 7.0),
-            "months" | "month" => diff_ms / (1000.0 * 60.0 * 60.0 * 24.0 * 30.0),
-            "years" | "year" => diff_ms / (1000.0 * 60.0 * 60.0 * 24.0 * 365.0),
-            _ => 0.0,
+    fn instant_at_wall_clock(&self, local_ms: i64) -> i64 {
+        match self.zone {
+            Some(zone) => zone
+                .local_to_instant_ms(local_ms, Disambiguation::Shift)
+                .unwrap_or(local_ms),
+            None => local_ms,
         }
     }
 
-    #[cfg(not(feature = "chrono"))]
     fn compute_timestamp(
         year: i32,
         month: u32,
@@ -972,9 +3395,11 @@ impl DateTime {
         second: u32,
         millis: u32,
     ) -> i64 {
-        let days = days_from_civil(year, month, day);
-        let secs = days * 86400 + hour as i64 * 3600 + minute as i64 * 60 + second as i64;
-        secs * 1000 + millis as i64
+        let days = crate::calendar::days_from_civil(year, month, day);
+        let secs = days
+            .saturating_mul(86400)
+            .saturating_add(hour as i64 * 3600 + minute as i64 * 60 + second as i64);
+        secs.saturating_mul(1000).saturating_add(millis as i64)
     }
 
     pub const DATE_SHORT: &'static str = locale::DATE_SHORT;
@@ -985,57 +3410,33 @@ impl DateTime {
     pub const DATETIME_SHORT: &'static str = locale::DATETIME_SHORT;
     pub const DATETIME_MED: &'static str = locale::DATETIME_MED;
     pub const DATETIME_FULL: &'static str = locale::DATETIME_FULL;
+    pub const DATE_MED_WITH_WEEKDAY: &'static str = locale::DATE_MED_WITH_WEEKDAY;
+    pub const DATE_HUGE: &'static str = locale::DATE_HUGE;
+    pub const TIME_WITH_SHORT_OFFSET: &'static str = locale::TIME_WITH_SHORT_OFFSET;
+    pub const TIME_WITH_LONG_OFFSET: &'static str = locale::TIME_WITH_LONG_OFFSET;
+    pub const TIME_24_SIMPLE: &'static str = locale::TIME_24_SIMPLE;
+    pub const TIME_24_WITH_SECONDS: &'static str = locale::TIME_24_WITH_SECONDS;
+    pub const TIME_24_WITH_SHORT_OFFSET: &'static str = locale::TIME_24_WITH_SHORT_OFFSET;
+    pub const TIME_24_WITH_LONG_OFFSET: &'static str = locale::TIME_24_WITH_LONG_OFFSET;
+    pub const DATETIME_MED_WITH_WEEKDAY: &'static str = locale::DATETIME_MED_WITH_WEEKDAY;
+    pub const DATETIME_HUGE: &'static str = locale::DATETIME_HUGE;
+    pub const DATETIME_SHORT_WITH_SECONDS: &'static str = locale::DATETIME_SHORT_WITH_SECONDS;
+    pub const DATETIME_MED_WITH_SECONDS: &'static str = locale::DATETIME_MED_WITH_SECONDS;
+    pub const DATETIME_FULL_WITH_SECONDS: &'static str = locale::DATETIME_FULL_WITH_SECONDS;
+    pub const DATETIME_HUGE_WITH_SECONDS: &'static str = locale::DATETIME_HUGE_WITH_SECONDS;
 }
 
-#[cfg(not(feature = "chrono"))]
-fn days_from_civil(year: i32, month: u32, day: u32) -> i64 {
-    let y = year as i64 - if month <= 2 { 1 } else { 0 };
-    let m = month as i64;
-    let d = day as i64;
-    let era = if y >= 0 { y / 400 } else { (y - 399) / 400 };
-    let yoe = y - era * 400;
-    let doy = (153 * (m + if m > 2 { -3 } else { 9 }) + 2) / 5 + d - 1;
-    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
-    era * 146097 + doe - 719468
-}
-
-#[cfg(not(feature = "chrono"))]
-fn is_leap_year(year: i32) -> bool {
-    (year % 4 == 0) && (year % 100 != 0 || year % 400 == 0)
-}
-
-#[cfg(not(feature = "chrono"))]
-fn days_in_month(year: i32, month: u32) -> u32 {
-    match month {
-        1 => 31,
-        2 => if is_leap_year(year) { 29 } else { 28 },
-        3 => 31,
-        4 => 30,
-        5 => 31,
-        6 => 30,
-        7 => 31,
-        8 => 31,
-        9 => 30,
-        10 => 31,
-        11 => 30,
-        12 => 31,
-        _ => 30,
-    }
-}
-
-#[cfg(not(feature = "chrono"))]
 fn add_months_to_ymd(year: i32, month: u32, day: u32, offset_months: i64) -> (i32, u32, u32) {
-    // Convert to zero-based month count
-    let total = year as i64 * 12 + (month as i64 - 1) + offset_months;
+    // Convert to zero-based month count, kept within a year of the supported
+    // range so the year fits an i32 and callers still see the overflow
+    let total = (year as i64 * 12 + (month as i64 - 1))
+        .saturating_add(offset_months)
+        .clamp((MIN_YEAR as i64 - 1) * 12, (MAX_YEAR as i64 + 2) * 12 - 1);
     // compute new year and month
-    let new_year = (total / 12) as i32;
-    let mut new_month = (total % 12) as i32 + 1;
-    if new_month <= 0 {
-        new_month += 12;
-    }
-    let new_month_u = new_month as u32;
+    let new_year = total.div_euclid(12) as i32;
+    let new_month_u = total.rem_euclid(12) as u32 + 1;
     // clamp day to last day of new month
-    let max_day = days_in_month(new_year, new_month_u);
+    let max_day = crate::calendar::days_in_month(new_year, new_month_u);
     let new_day = if day > max_day { max_day } else { day };
     (new_year, new_month_u, new_day)
 }
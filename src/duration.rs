@@ -1,4 +1,24 @@
 use std::collections::HashMap;
+use std::fmt;
+
+use crate::datetime::DateTime;
+use crate::error::Error;
+use crate::format::{Item, Tokens};
+use crate::options::HumanOptions;
+use crate::unit::Unit;
+
+// Fixed lengths in milliseconds, largest unit first, with 30-day months and
+// 365-day years as in `as_unit`.
+pub(crate) const UNIT_MS: [i64; 8] = [
+    31_536_000_000,
+    2_592_000_000,
+    604_800_000,
+    86_400_000,
+    3_600_000,
+    60_000,
+    1_000,
+    1,
+];
 
 /// A length of time with multiple units.
 ///
@@ -21,7 +41,24 @@ use std::collections::HashMap;
 /// let total_hours = dur.as_unit("hours");
 /// let total_days = dur.as_unit("days");
 /// ```
-#[derive(Debug, Clone, PartialEq, Default)]
+///
+/// # Comparison
+///
+/// `==`, `<` and sorting compare total lengths, counting months as 30 days
+/// and years as 365 days, so 1 hour equals 60 minutes and 1 month equals 30
+/// days. Those approximations make comparisons involving months and years
+/// rough; use [`equals`](Duration::equals) to compare unit by unit.
+///
+/// ```rust
+/// use tempotime::Duration;
+///
+/// let hour = Duration::from_object(&[("hours", 1)]);
+/// let minutes = Duration::from_object(&[("minutes", 90)]);
+/// assert!(hour < minutes);
+/// assert_eq!(hour, Duration::from_object(&[("minutes", 60)]));
+/// assert!(!hour.equals(&Duration::from_object(&[("minutes", 60)])));
+/// ```
+#[derive(Debug, Clone, Default)]
 pub struct Duration {
     years: i64,
     months: i64,
@@ -66,6 +103,702 @@ impl Duration {
         dur
     }
 
+    const ZERO: Duration = Duration {
+        years: 0,
+        months: 0,
+        weeks: 0,
+        days: 0,
+        hours: 0,
+        minutes: 0,
+        seconds: 0,
+        milliseconds: 0,
+    };
+
+    /// A duration of `years` years.
+    pub const fn years(years: i64) -> Duration {
+        Duration {
+            years,
+            ..Self::ZERO
+        }
+    }
+
+    /// A duration of `months` months.
+    pub const fn months(months: i64) -> Duration {
+        Duration {
+            months,
+            ..Self::ZERO
+        }
+    }
+
+    /// A duration of `weeks` weeks.
+    pub const fn weeks(weeks: i64) -> Duration {
+        Duration {
+            weeks,
+            ..Self::ZERO
+        }
+    }
+
+    /// A duration of `days` days. Each unit has such a constructor and an
+    /// `and_*` method to set another unit, a typed alternative to
+    /// [`from_object`](Duration::from_object) where a misspelt unit does not
+    /// compile.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::Duration;
+    ///
+    /// let dur = Duration::days(3).and_hours(2);
+    /// assert!(dur.equals(&Duration::from_object(&[("days", 3), ("hours", 2)])));
+    /// ```
+    pub const fn days(days: i64) -> Duration {
+        Duration { days, ..Self::ZERO }
+    }
+
+    /// A duration of `hours` hours.
+    pub const fn hours(hours: i64) -> Duration {
+        Duration {
+            hours,
+            ..Self::ZERO
+        }
+    }
+
+    /// A duration of `minutes` minutes.
+    pub const fn minutes(minutes: i64) -> Duration {
+        Duration {
+            minutes,
+            ..Self::ZERO
+        }
+    }
+
+    /// A duration of `seconds` seconds.
+    pub const fn seconds(seconds: i64) -> Duration {
+        Duration {
+            seconds,
+            ..Self::ZERO
+        }
+    }
+
+    /// A duration of `milliseconds` milliseconds.
+    pub const fn milliseconds(milliseconds: i64) -> Duration {
+        Duration {
+            milliseconds,
+            ..Self::ZERO
+        }
+    }
+
+    /// This duration with its years set to `years`.
+    pub const fn and_years(self, years: i64) -> Duration {
+        Duration { years, ..self }
+    }
+
+    /// This duration with its months set to `months`.
+    pub const fn and_months(self, months: i64) -> Duration {
+        Duration { months, ..self }
+    }
+
+    /// This duration with its weeks set to `weeks`.
+    pub const fn and_weeks(self, weeks: i64) -> Duration {
+        Duration { weeks, ..self }
+    }
+
+    /// This duration with its days set to `days`.
+    pub const fn and_days(self, days: i64) -> Duration {
+        Duration { days, ..self }
+    }
+
+    /// This duration with its hours set to `hours`.
+    pub const fn and_hours(self, hours: i64) -> Duration {
+        Duration { hours, ..self }
+    }
+
+    /// This duration with its minutes set to `minutes`.
+    pub const fn and_minutes(self, minutes: i64) -> Duration {
+        Duration { minutes, ..self }
+    }
+
+    /// This duration with its seconds set to `seconds`.
+    pub const fn and_seconds(self, seconds: i64) -> Duration {
+        Duration { seconds, ..self }
+    }
+
+    /// This duration with its milliseconds set to `milliseconds`.
+    pub const fn and_milliseconds(self, milliseconds: i64) -> Duration {
+        Duration {
+            milliseconds,
+            ..self
+        }
+    }
+
+    /// A duration of `millis` milliseconds, the inverse of
+    /// `as_unit("milliseconds")`. Call [`decompose`](Duration::decompose) to
+    /// split it into larger units.
+    pub const fn from_millis(millis: i64) -> Duration {
+        Duration::milliseconds(millis)
+    }
+
+    /// Creates a Duration from fractional values, carrying each fraction into
+    /// the smaller units as Luxon does: `("hours", 1.5)` is 1 hour 30 minutes.
+    ///
+    /// Fractions of a year become months; anything shorter is spread using
+    /// 30-day months and 7-day weeks, down to the nearest millisecond. Values
+    /// given for the same unit twice add up, and unknown keys are ignored as
+    /// in [`from_object`](Duration::from_object).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::Duration;
+    ///
+    /// let dur = Duration::from_object_f64(&[("hours", 1.5)]);
+    /// assert_eq!(dur.to_human(), "1 hour, 30 minutes");
+    ///
+    /// let dur = Duration::from_object_f64(&[("years", 1.25), ("seconds", 0.75)]);
+    /// assert_eq!(dur.to_human(), "1 year, 3 months, 750 milliseconds");
+    /// ```
+    pub fn from_object_f64(obj: &[(&str, f64)]) -> Self {
+        let mut dur = Duration::default();
+        for (key, value) in obj {
+            if let Ok(unit) = key.parse::<Unit>() {
+                dur.spread(unit.index(), *value);
+            }
+        }
+        dur
+    }
+
+    /// Parses an ISO-8601 duration such as `P1Y2M10DT2H30M` or `PT1.5S`.
+    ///
+    /// Any component may be negative (`P-1D`), as may the whole duration
+    /// (`-P1D`). A fractional component is spread over the smaller units, so
+    /// `PT1.5H` is 1 hour 30 minutes; below years, months count as 30 days.
+    /// Either `.` or `,` may mark the fraction, and as ISO 8601 requires only
+    /// the last component may have one.
+    ///
+    /// # Errors
+    ///
+    /// A structured [`Error`] pointing at the first problem: a missing `P`,
+    /// a component out of order or without a number, a fraction before the
+    /// last component, an empty duration or time part, or trailing input.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::Duration;
+    ///
+    /// let dur = Duration::from_iso("P1Y2M10DT2H30M").unwrap();
+    /// assert_eq!(dur.to_human(), "1 year, 2 months, 10 days, 2 hours, 30 minutes");
+    ///
+    /// let dur = Duration::from_iso("-PT1.5S").unwrap();
+    /// assert_eq!(dur.to_human(), "-1 second, -500 milliseconds");
+    ///
+    /// assert!(Duration::from_iso("P1H").is_err());
+    /// ```
+    pub fn from_iso(s: &str) -> Result<Self, Error> {
+        let bytes = s.as_bytes();
+        let mut pos = 0;
+        let sign = match bytes.first() {
+            Some(b'-') => {
+                pos += 1;
+                -1.0
+            }
+            Some(b'+') => {
+                pos += 1;
+                1.0
+            }
+            _ => 1.0,
+        };
+        match bytes.get(pos) {
+            Some(b'P' | b'p') => pos += 1,
+            Some(_) => {
+                return Err(Error::Expected {
+                    expected: 'P',
+                    position: pos,
+                })
+            }
+            None => return Err(Error::UnexpectedEnd { position: pos }),
+        }
+
+        let mut dur = Duration::default();
+        // The next unit allowed, as an index into `UNITS`, so components
+        // cannot repeat or come out of order.
+        let mut next = 0;
+        let mut in_time = false;
+        let mut time_start = None;
+        // Where a fractional component started; nothing may follow one.
+        let mut fraction = None;
+        while pos < bytes.len() {
+            if !in_time && matches!(bytes[pos], b'T' | b't') {
+                in_time = true;
+                pos += 1;
+                time_start = Some(pos);
+                next = next.max(4);
+                continue;
+            }
+            if let Some(position) = fraction {
+                return Err(Error::Invalid {
+                    what: "duration component",
+                    position,
+                });
+            }
+            let start = pos;
+            if matches!(bytes[pos], b'-' | b'+') {
+                pos += 1;
+            }
+            let digits = pos;
+            while pos < bytes.len()
+                && (bytes[pos].is_ascii_digit() || matches!(bytes[pos], b'.' | b','))
+            {
+                pos += 1;
+            }
+            if pos == digits {
+                return Err(Error::Invalid {
+                    what: "duration component",
+                    position: start,
+                });
+            }
+            let number = s[start..pos].replace(',', ".");
+            let designator = bytes
+                .get(pos)
+                .ok_or(Error::UnexpectedEnd { position: pos })?
+                .to_ascii_uppercase();
+            let unit = match (designator, in_time) {
+                (b'Y', false) => 0,
+                (b'M', false) => 1,
+                (b'W', false) => 2,
+                (b'D', false) => 3,
+                (b'H', true) => 4,
+                (b'M', true) => 5,
+                (b'S', true) => 6,
+                _ => {
+                    return Err(Error::Invalid {
+                        what: "duration unit",
+                        position: pos,
+                    })
+                }
+            };
+            if unit < next {
+                return Err(Error::Invalid {
+                    what: "duration unit",
+                    position: pos,
+                });
+            }
+            next = unit + 1;
+            pos += 1;
+            let invalid = Error::Invalid {
+                what: "duration component",
+                position: start,
+            };
+            match number.parse::<i64>() {
+                Ok(whole) if sign > 0.0 => *dur.field_mut(unit) = whole,
+                Ok(whole) => *dur.field_mut(unit) = whole.checked_neg().ok_or(invalid)?,
+                Err(_) => {
+                    let value: f64 = number.parse().map_err(|_| invalid.clone())?;
+                    if !value.is_finite() || value.abs() >= i64::MAX as f64 {
+                        return Err(invalid);
+                    }
+                    dur.spread(unit, sign * value);
+                    fraction = Some(start);
+                }
+            }
+        }
+        if next == 0 || time_start == Some(bytes.len()) {
+            return Err(Error::UnexpectedEnd {
+                position: bytes.len(),
+            });
+        }
+        Ok(dur)
+    }
+
+    /// Parses shorthand people type, such as `90s`, `2h 30m` or
+    /// `1 day, 4 hours`, for command-line flags and config files.
+    ///
+    /// Each component is a number, optionally signed or fractional, followed
+    /// by a unit: `y`/`yr`/`year`, `mo`/`month`, `w`/`wk`/`week`, `d`/`day`,
+    /// `h`/`hr`/`hour`, `m`/`min`/`minute`, `s`/`sec`/`second` or
+    /// `ms`/`millisecond`, in any case and with an optional plural `s`.
+    /// Components may be separated by spaces, commas or `and`; a unit given
+    /// twice adds up. Fractions spread into smaller units as in
+    /// [`from_object_f64`](Duration::from_object_f64).
+    ///
+    /// # Errors
+    ///
+    /// A structured [`Error`] pointing at the first problem: an empty input,
+    /// a number without a unit, or an unknown unit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::Duration;
+    ///
+    /// assert_eq!(Duration::from_human("2h 30m").unwrap().to_human(), "2 hours, 30 minutes");
+    /// assert_eq!(Duration::from_human("1 day and 4 hours").unwrap().to_human(), "1 day, 4 hours");
+    /// assert_eq!(Duration::from_human("1.5h").unwrap().to_human(), "1 hour, 30 minutes");
+    /// assert!(Duration::from_human("10 parsecs").is_err());
+    /// ```
+    pub fn from_human(s: &str) -> Result<Self, Error> {
+        let bytes = s.as_bytes();
+        let mut pos = 0;
+        let mut dur = Duration::default();
+        let mut empty = true;
+        loop {
+            while pos < bytes.len() && (bytes[pos].is_ascii_whitespace() || bytes[pos] == b',') {
+                pos += 1;
+            }
+            if !empty
+                && bytes.len() - pos > 3
+                && bytes[pos..pos + 3].eq_ignore_ascii_case(b"and")
+                && bytes[pos + 3].is_ascii_whitespace()
+            {
+                pos += 3;
+                continue;
+            }
+            if pos == bytes.len() {
+                break;
+            }
+
+            let start = pos;
+            if matches!(bytes[pos], b'-' | b'+') {
+                pos += 1;
+            }
+            let digits = pos;
+            while pos < bytes.len() && (bytes[pos].is_ascii_digit() || bytes[pos] == b'.') {
+                pos += 1;
+            }
+            if pos == digits {
+                return Err(Error::Invalid {
+                    what: "duration component",
+                    position: start,
+                });
+            }
+            let number = &s[start..pos];
+            while pos < bytes.len() && bytes[pos].is_ascii_whitespace() {
+                pos += 1;
+            }
+            let name_start = pos;
+            while pos < bytes.len() && bytes[pos].is_ascii_alphabetic() {
+                pos += 1;
+            }
+            if name_start == pos {
+                return Err(match bytes.get(pos) {
+                    Some(_) => Error::Invalid {
+                        what: "duration unit",
+                        position: pos,
+                    },
+                    None => Error::UnexpectedEnd { position: pos },
+                });
+            }
+            let unit = human_unit(&s[name_start..pos]).ok_or(Error::Invalid {
+                what: "duration unit",
+                position: name_start,
+            })?;
+
+            let invalid = Error::Invalid {
+                what: "duration component",
+                position: start,
+            };
+            match number.parse::<i64>() {
+                Ok(whole) => {
+                    let field = dur.field_mut(unit.index());
+                    *field = field.checked_add(whole).ok_or(invalid)?;
+                }
+                Err(_) => {
+                    let value: f64 = number.parse().map_err(|_| invalid.clone())?;
+                    if !value.is_finite() || value.abs() >= i64::MAX as f64 {
+                        return Err(invalid);
+                    }
+                    dur.spread(unit.index(), value);
+                }
+            }
+            empty = false;
+        }
+        if empty {
+            return Err(Error::UnexpectedEnd { position: pos });
+        }
+        Ok(dur)
+    }
+
+    /// Formats as an ISO-8601 duration such as `P1Y2M10DT2H30M`, leaving out
+    /// zero components; [`from_iso`](Duration::from_iso) reads it back.
+    ///
+    /// Milliseconds are written as a fraction of the seconds (`PT1.5S`). When
+    /// every component is negative the sign goes in front (`-P1D`), otherwise
+    /// on each negative component. A zero duration is `PT0S`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::Duration;
+    ///
+    /// let dur = Duration::from_object(&[
+    ///     ("days", 10),
+    ///     ("hours", 2),
+    ///     ("seconds", 1),
+    ///     ("milliseconds", 250),
+    /// ]);
+    /// assert_eq!(dur.to_iso(), "P10DT2H1.25S");
+    /// assert_eq!(Duration::from_object(&[("weeks", -2)]).to_iso(), "-P2W");
+    /// assert_eq!(Duration::default().to_iso(), "PT0S");
+    /// ```
+    pub fn to_iso(&self) -> String {
+        use std::fmt::Write as _;
+
+        let millis = self.seconds as i128 * 1000 + self.milliseconds as i128;
+        let date = [
+            (self.years, 'Y'),
+            (self.months, 'M'),
+            (self.weeks, 'W'),
+            (self.days, 'D'),
+        ];
+        let time = [(self.hours, 'H'), (self.minutes, 'M')];
+        let values = date
+            .iter()
+            .chain(&time)
+            .map(|&(value, _)| value as i128)
+            .chain([millis]);
+        let negative =
+            values.clone().any(|value| value < 0) && values.clone().all(|value| value <= 0);
+        let signed = |value: i128| if negative { -value } else { value };
+
+        let mut out = String::from(if negative { "-P" } else { "P" });
+        for (value, designator) in date {
+            if value != 0 {
+                let _ = write!(out, "{}{}", signed(value as i128), designator);
+            }
+        }
+        if time.iter().any(|&(value, _)| value != 0) || millis != 0 || out.ends_with('P') {
+            out.push('T');
+        }
+        for (value, designator) in time {
+            if value != 0 {
+                let _ = write!(out, "{}{}", signed(value as i128), designator);
+            }
+        }
+        if millis != 0 || out.ends_with('T') {
+            let millis = signed(millis);
+            let sign = if millis < 0 { "-" } else { "" };
+            let _ = write!(out, "{}{}", sign, millis.unsigned_abs() / 1000);
+            let fraction = millis.unsigned_abs() % 1000;
+            if fraction != 0 {
+                let _ = write!(out, ".{}", format!("{:03}", fraction).trim_end_matches('0'));
+            }
+            out.push('S');
+        }
+        out
+    }
+
+    /// Formats the duration like a stopwatch, as Luxon's `Duration.toFormat`:
+    /// `"hh:mm:ss.SSS"` gives `"01:07:04.250"`.
+    ///
+    /// The duration is first re-expressed in the units the pattern mentions,
+    /// so hours past a day keep counting (`"36:00:00"`) and anything below
+    /// the smallest of them is dropped. Tokens are `y`, `M`, `w`, `d`, `h`
+    /// (or `H`), `m`, `s` and `S` for milliseconds; repeating a letter pads
+    /// with zeros to that width. Text in single quotes is copied as is. A
+    /// negative duration gets a leading `-`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::Duration;
+    ///
+    /// let lap = Duration::hours(1).and_minutes(7).and_seconds(4).and_milliseconds(250);
+    /// assert_eq!(lap.to_format("hh:mm:ss.SSS"), "01:07:04.250");
+    /// assert_eq!(Duration::days(1).and_hours(12).to_format("hh:mm:ss"), "36:00:00");
+    /// assert_eq!(Duration::minutes(-90).to_format("h'h' mm'm'"), "-1h 30m");
+    /// ```
+    pub fn to_format(&self, fmt: &str) -> String {
+        use std::fmt::Write as _;
+
+        let mut fields = Vec::new();
+        for item in Tokens::open_ended(fmt).flatten() {
+            let field = match item {
+                Item::Field(c, width) => format_unit(c).map(|unit| (unit, width)),
+                Item::Ordinal => Some((Unit::Days, 1)),
+                Item::Char('w') => match fields.last_mut() {
+                    Some((Some((Unit::Weeks, width)), _)) => {
+                        *width += 1;
+                        continue;
+                    }
+                    _ => Some((Unit::Weeks, 1)),
+                },
+                _ => None,
+            };
+            fields.push((field, item));
+        }
+
+        let mut units: Vec<Unit> = fields
+            .iter()
+            .filter_map(|(field, _)| field.map(|(unit, _)| unit))
+            .collect();
+        units.push(Unit::Milliseconds);
+        units.sort();
+        units.dedup();
+        let shifted = self.abs().shift_to(&units);
+
+        let mut out = String::new();
+        if self.is_negative() {
+            out.push('-');
+        }
+        for (field, item) in fields {
+            match (field, item) {
+                (Some((unit, width)), item) => {
+                    let _ = write!(out, "{:0width$}", shifted.get(unit), width = width);
+                    if item == Item::Ordinal {
+                        out.push('o');
+                    }
+                }
+                (None, Item::Field(c, count)) => out.extend(std::iter::repeat_n(c, count)),
+                (None, Item::Char(c)) => out.push(c),
+                (None, Item::Literal(text)) => out.push_str(&text),
+                (None, Item::Ordinal) => {}
+            }
+        }
+        out
+    }
+
+    /// Describes the duration in English for people, like Luxon's `toHuman`:
+    /// `"2 hours, 5 minutes"`, listing every non-zero unit largest first.
+    ///
+    /// Components are shown as stored; call
+    /// [`normalize`](Duration::normalize) first to turn 90 minutes into
+    /// "1 hour, 30 minutes". A zero duration is "0 seconds".
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::Duration;
+    ///
+    /// let dur = Duration::from_object(&[("hours", 2), ("minutes", 5)]);
+    /// assert_eq!(dur.to_human(), "2 hours, 5 minutes");
+    /// assert_eq!(Duration::from_object(&[("days", 1)]).to_human(), "1 day");
+    /// ```
+    pub fn to_human(&self) -> String {
+        self.to_human_with(HumanOptions::default())
+    }
+
+    /// Like [`to_human`](Duration::to_human), with a limit on the number of
+    /// units and an optional compact style for CLIs and dashboards.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::{Duration, HumanOptions};
+    ///
+    /// let dur = Duration::from_object(&[("hours", 2), ("minutes", 5), ("seconds", 9)]);
+    /// let compact = HumanOptions { compact: true, ..Default::default() };
+    /// assert_eq!(dur.to_human_with(compact), "2h 5m 9s");
+    /// assert_eq!(dur.to_human_with(HumanOptions { max_units: Some(2), ..compact }), "2h 5m");
+    /// let one = HumanOptions { max_units: Some(1), ..Default::default() };
+    /// assert_eq!(dur.to_human_with(one), "2 hours");
+    /// ```
+    pub fn to_human_with(&self, options: HumanOptions) -> String {
+        use std::fmt::Write as _;
+
+        let (years, months, weeks, days, hours, minutes, seconds, millis) = self.components();
+        let values = [years, months, weeks, days, hours, minutes, seconds, millis];
+        let mut parts = Unit::ALL
+            .into_iter()
+            .zip(values)
+            .filter(|&(_, value)| value != 0)
+            .peekable();
+        if parts.peek().is_none() {
+            return if options.compact { "0s" } else { "0 seconds" }.to_string();
+        }
+        let mut out = String::new();
+        for (unit, value) in parts.take(options.max_units.unwrap_or(usize::MAX)) {
+            if !out.is_empty() {
+                out.push_str(if options.compact { " " } else { ", " });
+            }
+            if options.compact {
+                let _ = write!(out, "{}{}", value, unit.abbreviation());
+            } else {
+                let name = unit.name();
+                let name = if value.unsigned_abs() == 1 {
+                    &name[..name.len() - 1]
+                } else {
+                    name
+                };
+                let _ = write!(out, "{} {}", value, name);
+            }
+        }
+        out
+    }
+
+    /// The value of a single component, as stored: 90 minutes reads back as
+    /// 90 minutes and 0 hours. See [`total`](Duration::total) for the whole
+    /// length in a unit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::{Duration, Unit};
+    ///
+    /// let dur = Duration::hours(2).and_minutes(90);
+    /// assert_eq!(dur.get(Unit::Minutes), 90);
+    /// assert_eq!(dur.get_hours(), 2);
+    /// assert_eq!(dur.set(Unit::Hours, 5).get(Unit::Hours), 5);
+    /// ```
+    pub fn get(&self, unit: Unit) -> i64 {
+        match unit {
+            Unit::Years => self.years,
+            Unit::Months => self.months,
+            Unit::Weeks => self.weeks,
+            Unit::Days => self.days,
+            Unit::Hours => self.hours,
+            Unit::Minutes => self.minutes,
+            Unit::Seconds => self.seconds,
+            Unit::Milliseconds => self.milliseconds,
+        }
+    }
+
+    /// This duration with the `unit` component replaced by `value`.
+    pub fn set(&self, unit: Unit, value: i64) -> Duration {
+        let mut dur = self.clone();
+        *dur.field_mut(unit.index()) = value;
+        dur
+    }
+
+    /// The years component, as `get(Unit::Years)`. Each unit has such a
+    /// getter; the plain names (`Duration::years`) are the constructors.
+    pub const fn get_years(&self) -> i64 {
+        self.years
+    }
+
+    /// The months component.
+    pub const fn get_months(&self) -> i64 {
+        self.months
+    }
+
+    /// The weeks component.
+    pub const fn get_weeks(&self) -> i64 {
+        self.weeks
+    }
+
+    /// The days component.
+    pub const fn get_days(&self) -> i64 {
+        self.days
+    }
+
+    /// The hours component.
+    pub const fn get_hours(&self) -> i64 {
+        self.hours
+    }
+
+    /// The minutes component.
+    pub const fn get_minutes(&self) -> i64 {
+        self.minutes
+    }
+
+    /// The seconds component.
+    pub const fn get_seconds(&self) -> i64 {
+        self.seconds
+    }
+
+    /// The milliseconds component.
+    pub const fn get_milliseconds(&self) -> i64 {
+        self.milliseconds
+    }
+
     pub fn to_object(&self) -> HashMap<String, i64> {
         let mut map = HashMap::new();
         if self.years != 0 {
@@ -95,31 +828,625 @@ impl Duration {
         map
     }
 
+    /// Adds `other` unit by unit, so one month plus 30 days stays one month
+    /// and 30 days rather than being converted to a length. Components that
+    /// would overflow saturate at `i64::MIN`/`i64::MAX`; use
+    /// [`checked_plus`](Duration::checked_plus) to detect that.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::Duration;
+    ///
+    /// let mut total = Duration::default();
+    /// for lap in [("minutes", 4), ("seconds", 75), ("minutes", 3)] {
+    ///     total = total.plus(&Duration::from_object(&[lap]));
+    /// }
+    /// assert_eq!(total.to_human(), "7 minutes, 75 seconds");
+    /// ```
+    pub fn plus(&self, other: &Duration) -> Duration {
+        self.zip_with(other, i64::saturating_add)
+    }
+
+    /// Subtracts `other` unit by unit, saturating like [`plus`](Duration::plus).
+    pub fn minus(&self, other: &Duration) -> Duration {
+        self.zip_with(other, i64::saturating_sub)
+    }
+
+    /// The same duration with every component's sign flipped; `i64::MIN`
+    /// becomes `i64::MAX`.
+    pub fn negate(&self) -> Duration {
+        self.zip_with(&Duration::default(), |a, _| a.saturating_neg())
+    }
+
+    /// Like [`plus`](Duration::plus), but fails instead of saturating.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Overflow`] if any component leaves the `i64` range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::{Duration, Error};
+    ///
+    /// let big = Duration::from_object(&[("years", i64::MAX)]);
+    /// let year = Duration::from_object(&[("years", 1)]);
+    /// assert_eq!(big.checked_plus(&year), Err(Error::Overflow));
+    /// assert!(year.checked_plus(&year).is_ok());
+    /// ```
+    pub fn checked_plus(&self, other: &Duration) -> Result<Duration, Error> {
+        self.checked_zip_with(other, i64::checked_add)
+    }
+
+    /// Like [`minus`](Duration::minus), but fails instead of saturating.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Overflow`] if any component leaves the `i64` range.
+    pub fn checked_minus(&self, other: &Duration) -> Result<Duration, Error> {
+        self.checked_zip_with(other, i64::checked_sub)
+    }
+
+    /// Like [`times`](Duration::times), but fails instead of saturating.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Overflow`] if any component leaves the `i64` range.
+    pub fn checked_times(&self, factor: i64) -> Result<Duration, Error> {
+        self.checked_zip_with(&Duration::default(), |a, _| a.checked_mul(factor))
+    }
+
+    /// Whether the total length is zero, judged like `==`: 1 day minus 24
+    /// hours is zero even though its components are not.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::Duration;
+    ///
+    /// let budget = Duration::minutes(30);
+    /// let remaining = budget.minus(&Duration::minutes(45));
+    /// assert!(remaining.is_negative());
+    /// assert_eq!(remaining.signum(), -1);
+    /// assert!(Duration::days(1).and_hours(-24).is_zero());
+    /// ```
+    pub fn is_zero(&self) -> bool {
+        self.total_millis() == 0
+    }
+
+    /// Whether the total length is below zero, counting months as 30 days
+    /// and years as 365 days.
+    pub fn is_negative(&self) -> bool {
+        self.total_millis() < 0
+    }
+
+    /// The sign of the total length: -1, 0 or 1.
+    pub fn signum(&self) -> i64 {
+        self.total_millis().signum() as i64
+    }
+
+    /// This duration, negated if its total length is negative.
+    ///
+    /// The sign is that of the length with 30-day months and 365-day years,
+    /// so a duration with mixed signs like 1 day minus 2 hours is left alone
+    /// rather than having each component made positive.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::Duration;
+    ///
+    /// let late = Duration::from_object(&[("minutes", -5), ("seconds", 30)]);
+    /// assert_eq!(late.abs().to_human(), "5 minutes, -30 seconds");
+    /// ```
+    pub fn abs(&self) -> Duration {
+        if self.is_negative() {
+            self.negate()
+        } else {
+            self.clone()
+        }
+    }
+
+    /// Carries overflowing components into larger units, so 90 minutes
+    /// becomes 1 hour 30 minutes, and balances signs, so 1 hour minus 45
+    /// minutes becomes 15 minutes.
+    ///
+    /// Only exact ratios are used: milliseconds up to days, with weeks kept
+    /// as weeks only when the duration already has some, and months up to
+    /// years. Days are never turned into months, so years and months are
+    /// balanced separately from the rest; use [`shift_to`](Duration::shift_to)
+    /// to convert between them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::Duration;
+    ///
+    /// let dur = Duration::from_object(&[("minutes", 90), ("seconds", 75)]).normalize();
+    /// assert_eq!(dur.to_human(), "1 hour, 31 minutes, 15 seconds");
+    ///
+    /// let dur = Duration::from_object(&[("hours", 1), ("minutes", -45)]).normalize();
+    /// assert_eq!(dur.to_human(), "15 minutes");
+    /// ```
+    pub fn normalize(&self) -> Duration {
+        let mut out = [0i128; 8];
+        let months = self.years as i128 * 12 + self.months as i128;
+        out[Unit::Years.index()] = months / 12;
+        out[Unit::Months.index()] = months % 12;
+        let units: &[Unit] = if self.weeks != 0 {
+            &Unit::ALL[2..]
+        } else {
+            &Unit::ALL[3..]
+        };
+        distribute(&mut out, self.time_millis(), units);
+        Duration::from_parts(out)
+    }
+
+    /// Re-expresses this duration in `units` only, largest first, using
+    /// 12-month years and otherwise 365-day years, 30-day months and 7-day
+    /// weeks.
+    ///
+    /// What is left below the smallest unit is rounded to the nearest one.
+    /// With no units the duration is returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::{Duration, Unit};
+    ///
+    /// let dur = Duration::from_object(&[("days", 1), ("minutes", 150)]);
+    /// assert_eq!(dur.shift_to(&[Unit::Hours, Unit::Minutes]).to_human(), "26 hours, 30 minutes");
+    /// assert_eq!(dur.shift_to(&[Unit::Hours]).to_human(), "27 hours");
+    ///
+    /// let dur = Duration::from_object(&[("years", 1), ("days", 45)]);
+    /// assert_eq!(dur.shift_to(&[Unit::Months, Unit::Days]).to_human(), "13 months, 15 days");
+    /// ```
+    pub fn shift_to(&self, units: &[Unit]) -> Duration {
+        if units.is_empty() {
+            return self.clone();
+        }
+        let mut units = units.to_vec();
+        units.sort();
+        units.dedup();
+        let has = |unit| units.contains(&unit);
+
+        let mut out = [0i128; 8];
+        let mut rest = self.time_millis();
+        let months = self.years as i128 * 12 + self.months as i128;
+        match (has(Unit::Years), has(Unit::Months)) {
+            (true, true) => {
+                out[Unit::Years.index()] = months / 12;
+                out[Unit::Months.index()] = months % 12;
+            }
+            (true, false) => {
+                out[Unit::Years.index()] = months / 12;
+                rest += months % 12 * UNIT_MS[Unit::Months.index()] as i128;
+            }
+            (false, true) => out[Unit::Months.index()] = months,
+            (false, false) => {
+                rest += self.years as i128 * UNIT_MS[Unit::Years.index()] as i128
+                    + self.months as i128 * UNIT_MS[Unit::Months.index()] as i128;
+            }
+        }
+        distribute(&mut out, rest, &units);
+        Duration::from_parts(out)
+    }
+
+    /// The gap from `start` to `end` split into exact calendar `units`, as
+    /// Luxon's `diff` with several units.
+    ///
+    /// Years and months are counted on the calendar in `start`'s zone, so a
+    /// month is whatever lies between the same day of two months (clamped to
+    /// month ends, as [`DateTime::plus`]). The rest is split over the smaller
+    /// units, read on the wall clock when days or weeks are asked for and as
+    /// elapsed time otherwise. What is left below the smallest unit is
+    /// dropped. The result is negative when `end` is before `start`; with no
+    /// units it is in milliseconds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::{DateTime, Duration, Unit};
+    ///
+    /// let start = DateTime::from_iso("2023-01-15T00:00:00Z").unwrap();
+    /// let end = DateTime::from_iso("2024-03-28T06:00:00Z").unwrap();
+    ///
+    /// let dur = Duration::between(&start, &end, &[Unit::Years, Unit::Months, Unit::Days]);
+    /// assert_eq!(dur.to_human(), "1 year, 2 months, 13 days");
+    ///
+    /// let dur = Duration::between(&start, &end, &[Unit::Months, Unit::Hours]);
+    /// assert_eq!(dur.to_human(), "14 months, 318 hours");
+    /// ```
+    pub fn between(start: &DateTime, end: &DateTime, units: &[Unit]) -> Duration {
+        let mut units = units.to_vec();
+        units.sort();
+        units.dedup();
+        if units.is_empty() {
+            units.push(Unit::Milliseconds);
+        }
+        let has = |unit| units.contains(&unit);
+
+        let (from, to) = (
+            start.wall_clock_ms(),
+            end.with_zone_of(start).wall_clock_ms(),
+        );
+        let (sign, from, to) = if to < from {
+            (-1, to, from)
+        } else {
+            (1, from, to)
+        };
+        let mut out = [0i128; 8];
+        let mut cursor = from;
+        if has(Unit::Years) || has(Unit::Months) {
+            let (from_year, from_month, _) =
+                crate::calendar::civil_from_days(from.div_euclid(86_400_000));
+            let (to_year, to_month, _) =
+                crate::calendar::civil_from_days(to.div_euclid(86_400_000));
+            let mut months =
+                (to_year as i64 - from_year as i64) * 12 + to_month as i64 - from_month as i64;
+            if add_months_wall(from, months) > to {
+                months -= 1;
+            }
+            if !has(Unit::Months) {
+                months -= months % 12;
+            }
+            cursor = add_months_wall(from, months);
+            if has(Unit::Years) {
+                out[Unit::Years.index()] = months as i128 / 12;
+                out[Unit::Months.index()] = months as i128 % 12;
+            } else {
+                out[Unit::Months.index()] = months as i128;
+            }
+        }
+
+        let mut rest = if units.iter().any(|&unit| unit <= Unit::Days) {
+            (to - cursor) as i128
+        } else {
+            (end.to_millis() as i128 - start.to_millis() as i128).abs()
+        };
+        for unit in units.iter().filter(|&&unit| unit > Unit::Months) {
+            let length = UNIT_MS[unit.index()] as i128;
+            out[unit.index()] = rest / length;
+            rest %= length;
+        }
+        Duration::from_parts(out.map(|value| value * sign))
+    }
+
+    /// Splits the total length into `units`, largest first, each taking as
+    /// much as fits, with 30-day months and 365-day years. What is left below
+    /// the smallest unit is dropped; with no units, days down to milliseconds
+    /// are used. Unlike [`shift_to`](Duration::shift_to), which rounds, every
+    /// component is truncated toward zero and has the sign of the total.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::{Duration, Unit};
+    ///
+    /// let dur = Duration::from_millis(93_784_005);
+    /// assert_eq!(
+    ///     dur.decompose(&[]).to_human(),
+    ///     "1 day, 2 hours, 3 minutes, 4 seconds, 5 milliseconds"
+    /// );
+    /// assert_eq!(dur.decompose(&[Unit::Hours, Unit::Minutes]).to_human(), "26 hours, 3 minutes");
+    /// ```
+    pub fn decompose(&self, units: &[Unit]) -> Duration {
+        let mut units = if units.is_empty() {
+            Unit::ALL[3..].to_vec()
+        } else {
+            units.to_vec()
+        };
+        units.sort();
+        units.dedup();
+        let mut out = [0i128; 8];
+        let mut rest = self.total_millis();
+        for unit in units {
+            let length = unit.length_ms() as i128;
+            out[unit.index()] = rest / length;
+            rest %= length;
+        }
+        Duration::from_parts(out)
+    }
+
+    /// Converts to a [`std::time::Duration`], for `std::thread::sleep`, tokio
+    /// timers and the like. Months count as 30 days and years as 365 days;
+    /// lengths beyond `u64::MAX` milliseconds saturate.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NegativeDuration`] if the total length is below zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::{Duration, Error};
+    ///
+    /// let delay = Duration::from_object(&[("seconds", 1), ("milliseconds", 500)]);
+    /// assert_eq!(delay.to_std(), Ok(std::time::Duration::from_millis(1500)));
+    ///
+    /// let back = Duration::from_object(&[("minutes", -1)]);
+    /// assert_eq!(back.to_std(), Err(Error::NegativeDuration));
+    /// ```
+    pub fn to_std(&self) -> Result<std::time::Duration, Error> {
+        let ms = self.total_millis();
+        if ms < 0 {
+            return Err(Error::NegativeDuration);
+        }
+        Ok(std::time::Duration::from_millis(
+            u64::try_from(ms).unwrap_or(u64::MAX),
+        ))
+    }
+
+    /// Whether both durations have the same value in every unit, as Luxon's
+    /// `equals`. Unlike `==`, which compares lengths, 1 hour does not equal 60
+    /// minutes here.
+    pub fn equals(&self, other: &Duration) -> bool {
+        self.components() == other.components()
+    }
+
+    /// Multiplies every component by `factor`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::Duration;
+    ///
+    /// let sprint = Duration::from_object(&[("weeks", 2)]);
+    /// assert_eq!(sprint.times(3).to_human(), "6 weeks");
+    /// ```
+    pub fn times(&self, factor: i64) -> Duration {
+        self.zip_with(&Duration::default(), |a, _| a.saturating_mul(factor))
+    }
+
+    /// Multiplies every component by `factor`, carrying fractions into the
+    /// smaller units as [`from_iso`](Duration::from_iso) does and rounding
+    /// to the millisecond.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::Duration;
+    ///
+    /// let dur = Duration::from_object(&[("hours", 1)]);
+    /// assert_eq!(dur.times_f64(1.25).to_human(), "1 hour, 15 minutes");
+    /// ```
+    pub fn times_f64(&self, factor: f64) -> Duration {
+        self.scaled(|value| value * factor)
+    }
+
+    /// Divides every component by `divisor`, carrying remainders into the
+    /// smaller units, so an hour divided by 2 is 30 minutes. The division is
+    /// exact down to the millisecond, which is rounded.
+    ///
+    /// # Panics
+    ///
+    /// If `divisor` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::Duration;
+    ///
+    /// let timeout = Duration::from_object(&[("minutes", 1), ("seconds", 15)]);
+    /// assert_eq!(timeout.divided_by(2).to_human(), "37 seconds, 500 milliseconds");
+    /// ```
+    pub fn divided_by(&self, divisor: i64) -> Duration {
+        assert!(divisor != 0, "Duration::divided_by: division by zero");
+        let divisor = divisor as i128;
+        let (years, months, weeks, days, hours, minutes, seconds, millis) = self.components();
+        let mut out = [0i128; 8];
+        // Remainders carry down exactly: a year's into months, and the rest
+        // into milliseconds spread from days down, as `times_f64` does.
+        out[0] = years as i128 / divisor;
+        let months = months as i128 + years as i128 % divisor * 12;
+        out[1] = months / divisor;
+        let mut rest = months % divisor * UNIT_MS[1] as i128;
+        for (unit, value) in (2..).zip([weeks, days, hours, minutes, seconds, millis]) {
+            out[unit] = value as i128 / divisor;
+            rest += value as i128 % divisor * UNIT_MS[unit] as i128;
+        }
+        let mut rest_ms = rest / divisor;
+        if (rest % divisor).abs() * 2 >= divisor.abs() {
+            rest_ms += rest.signum() * divisor.signum();
+        }
+        distribute(
+            &mut out,
+            rest_ms,
+            &[
+                Unit::Days,
+                Unit::Hours,
+                Unit::Minutes,
+                Unit::Seconds,
+                Unit::Milliseconds,
+            ],
+        );
+        Duration::from_parts(out)
+    }
+
+    /// Divides every component by `divisor` like
+    /// [`divided_by`](Duration::divided_by).
+    ///
+    /// # Panics
+    ///
+    /// If `divisor` is zero.
+    pub fn divided_by_f64(&self, divisor: f64) -> Duration {
+        assert!(divisor != 0.0, "Duration::divided_by_f64: division by zero");
+        self.scaled(|value| value / divisor)
+    }
+
+    // Each component mapped through `f`, with fractions spread downwards.
+    fn scaled(&self, f: impl Fn(f64) -> f64) -> Duration {
+        let (years, months, weeks, days, hours, minutes, seconds, millis) = self.components();
+        let mut scaled = Duration::default();
+        for (unit, value) in [years, months, weeks, days, hours, minutes, seconds, millis]
+            .into_iter()
+            .enumerate()
+        {
+            if value != 0 {
+                scaled.spread(unit, f(value as f64));
+            }
+        }
+        scaled
+    }
+
+    fn zip_with(&self, other: &Duration, f: impl Fn(i64, i64) -> i64) -> Duration {
+        Duration {
+            years: f(self.years, other.years),
+            months: f(self.months, other.months),
+            weeks: f(self.weeks, other.weeks),
+            days: f(self.days, other.days),
+            hours: f(self.hours, other.hours),
+            minutes: f(self.minutes, other.minutes),
+            seconds: f(self.seconds, other.seconds),
+            milliseconds: f(self.milliseconds, other.milliseconds),
+        }
+    }
+
+    fn checked_zip_with(
+        &self,
+        other: &Duration,
+        f: impl Fn(i64, i64) -> Option<i64>,
+    ) -> Result<Duration, Error> {
+        let f = |a, b| f(a, b).ok_or(Error::Overflow);
+        Ok(Duration {
+            years: f(self.years, other.years)?,
+            months: f(self.months, other.months)?,
+            weeks: f(self.weeks, other.weeks)?,
+            days: f(self.days, other.days)?,
+            hours: f(self.hours, other.hours)?,
+            minutes: f(self.minutes, other.minutes)?,
+            seconds: f(self.seconds, other.seconds)?,
+            milliseconds: f(self.milliseconds, other.milliseconds)?,
+        })
+    }
+
+    /// The total length in `unit`, truncated toward zero, with 30-day months
+    /// and 365-day years; 0 for an unknown unit. Lengths beyond the `i64`
+    /// range saturate rather than wrap.
+    ///
+    /// Truncation is silent, so 90 minutes is 1 hour. Use
+    /// [`as_unit_f64`](Duration::as_unit_f64) for the exact value or
+    /// [`as_unit_floor`](Duration::as_unit_floor) to round down explicitly.
     pub fn as_unit(&self, unit: &str) -> i64 {
-        let total_ms = self.as_milliseconds();
+        unit.parse::<Unit>().map_or(0, |unit| self.total(unit))
+    }
+
+    /// The total length in `unit`, the typed form of
+    /// [`as_unit`](Duration::as_unit): truncated toward zero, with 30-day
+    /// months and 365-day years, saturating beyond the `i64` range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::{Duration, Unit};
+    ///
+    /// let dur = Duration::days(1).and_hours(12);
+    /// assert_eq!(dur.total(Unit::Hours), 36);
+    /// assert_eq!(dur.total(Unit::Days), 1);
+    /// ```
+    pub fn total(&self, unit: Unit) -> i64 {
+        saturate(self.total_millis() / unit.length_ms() as i128)
+    }
+
+    /// The total length in `unit` with its fraction, using 30-day months and
+    /// 365-day years; 0.0 for an unknown unit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::Duration;
+    ///
+    /// let dur = Duration::from_object(&[("minutes", 90)]);
+    /// assert_eq!(dur.as_unit_f64("hours"), 1.5);
+    /// assert_eq!(dur.as_unit("hours"), 1);
+    /// ```
+    pub fn as_unit_f64(&self, unit: &str) -> f64 {
+        match unit.parse::<Unit>() {
+            Ok(unit) => self.total_millis() as f64 / UNIT_MS[unit.index()] as f64,
+            Err(_) => 0.0,
+        }
+    }
+
+    /// The total length in whole `unit`s, rounded down, so -90 minutes is -2
+    /// hours where [`as_unit`](Duration::as_unit) gives -1; 0 for an unknown
+    /// unit.
+    pub fn as_unit_floor(&self, unit: &str) -> i64 {
+        match unit.parse::<Unit>() {
+            Ok(unit) => saturate(
+                self.total_millis()
+                    .div_euclid(UNIT_MS[unit.index()] as i128),
+            ),
+            Err(_) => 0,
+        }
+    }
+
+    // The whole length in milliseconds, wide enough not to overflow.
+    fn total_millis(&self) -> i128 {
+        self.years as i128 * UNIT_MS[0] as i128
+            + self.months as i128 * UNIT_MS[1] as i128
+            + self.time_millis()
+    }
+
+    // Weeks and shorter, in milliseconds.
+    fn time_millis(&self) -> i128 {
+        let (_, _, weeks, days, hours, minutes, seconds, millis) = self.components();
+        [weeks, days, hours, minutes, seconds, millis]
+            .into_iter()
+            .zip(&UNIT_MS[2..])
+            .map(|(value, &length)| value as i128 * length as i128)
+            .sum()
+    }
+
+    // Components in `Unit::ALL` order, saturated to the i64 range.
+    fn from_parts(parts: [i128; 8]) -> Duration {
+        let mut dur = Duration::default();
+        for (unit, value) in parts.into_iter().enumerate() {
+            *dur.field_mut(unit) = saturate(value);
+        }
+        dur
+    }
+
+    fn field_mut(&mut self, unit: usize) -> &mut i64 {
         match unit {
-            "milliseconds" | "millisecond" => total_ms,
-            "seconds" | "second" => total_ms / 1000,
-            "minutes" | "minute" => total_ms / (1000 * 60),
-            "hours" | "hour" => total_ms / (1000 * 60 * 60),
-            "days" | "day" => total_ms / (1000 * 60 * 60 * 24),
-            "weeks" | "week" => total_ms / (1000 * 60 * 60 * 24 * 7),
-            "months" | "month" => total_ms / (1000 * 60 * 60 * 24 * 30),
-            "years" | "year" => total_ms / (1000 * 60 * 60 * 24 * 365),
-            _ => 0,
-        }
-    }
-
-    pub(crate) fn as_milliseconds(&self) -> i64 {
-        let mut ms = self.milliseconds;
-        ms += self.seconds * 1000;
-        ms += self.minutes * 60 * 1000;
-        ms += self.hours * 60 * 60 * 1000;
-        ms += self.days * 24 * 60 * 60 * 1000;
-        ms += self.weeks * 7 * 24 * 60 * 60 * 1000;
-        ms += self.months * 30 * 24 * 60 * 60 * 1000;
-        ms += self.years * 365 * 24 * 60 * 60 * 1000;
-        ms
+            0 => &mut self.years,
+            1 => &mut self.months,
+            2 => &mut self.weeks,
+            3 => &mut self.days,
+            4 => &mut self.hours,
+            5 => &mut self.minutes,
+            6 => &mut self.seconds,
+            _ => &mut self.milliseconds,
+        }
+    }
+
+    // Adds `value` of `unit`, carrying any fraction into the smaller units:
+    // years into months, and anything shorter as whole milliseconds. Sums
+    // saturate.
+    fn spread(&mut self, unit: usize, value: f64) {
+        let mut unit = unit;
+        let mut value = value;
+        if unit == UNIT_MS.len() - 1 {
+            self.add_to(unit, value.round() as i64);
+            return;
+        }
+        if unit == 0 {
+            self.add_to(0, value.trunc() as i64);
+            value = value.fract() * 12.0;
+            unit = 1;
+        }
+        self.add_to(unit, value.trunc() as i64);
+        let mut rest = (value.fract() * UNIT_MS[unit] as f64).round() as i64;
+        let first = (unit + 1).max(3);
+        for (smaller, length) in UNIT_MS.iter().enumerate().skip(first) {
+            self.add_to(smaller, rest / length);
+            rest %= length;
+        }
+    }
+
+    fn add_to(&mut self, unit: usize, value: i64) {
+        let field = self.field_mut(unit);
+        *field = field.saturating_add(value);
     }
 
     pub(crate) fn components(&self) -> (i64, i64, i64, i64, i64, i64, i64, i64) {
@@ -136,6 +1463,125 @@ impl Duration {
     }
 }
 
+impl PartialEq for Duration {
+    fn eq(&self, other: &Self) -> bool {
+        self.total_millis() == other.total_millis()
+    }
+}
+
+impl Eq for Duration {}
+
+impl PartialOrd for Duration {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Duration {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.total_millis().cmp(&other.total_millis())
+    }
+}
+
+/// Unit-wise, the same as [`plus`](Duration::plus).
+impl std::ops::Add for Duration {
+    type Output = Duration;
+
+    fn add(self, other: Duration) -> Duration {
+        self.plus(&other)
+    }
+}
+
+/// Unit-wise, the same as [`minus`](Duration::minus).
+impl std::ops::Sub for Duration {
+    type Output = Duration;
+
+    fn sub(self, other: Duration) -> Duration {
+        self.minus(&other)
+    }
+}
+
+/// Stored as milliseconds; sub-millisecond precision is truncated and lengths
+/// beyond `i64::MAX` milliseconds saturate.
+impl From<std::time::Duration> for Duration {
+    fn from(dur: std::time::Duration) -> Self {
+        let ms = i64::try_from(dur.as_millis()).unwrap_or(i64::MAX);
+        Duration::from_object(&[("milliseconds", ms)])
+    }
+}
+
+/// Formats as ISO 8601, the same as [`to_iso`](Duration::to_iso).
+impl fmt::Display for Duration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_iso())
+    }
+}
+
+// The unit a `from_human` name stands for, ignoring case and a plural `s`.
+fn human_unit(name: &str) -> Option<Unit> {
+    let name = name.to_ascii_lowercase();
+    let singular = match name.strip_suffix('s') {
+        Some(stem) if !stem.is_empty() && name != "ms" => stem,
+        _ => &name,
+    };
+    match singular {
+        "y" | "yr" | "year" => Some(Unit::Years),
+        "mo" | "month" => Some(Unit::Months),
+        "w" | "wk" | "week" => Some(Unit::Weeks),
+        "d" | "day" => Some(Unit::Days),
+        "h" | "hr" | "hour" => Some(Unit::Hours),
+        "m" | "min" | "minute" => Some(Unit::Minutes),
+        "s" | "sec" | "second" => Some(Unit::Seconds),
+        "ms" | "msec" | "millisecond" => Some(Unit::Milliseconds),
+        _ => None,
+    }
+}
+
+// The unit a `to_format` token letter stands for.
+fn format_unit(c: char) -> Option<Unit> {
+    match c {
+        'y' => Some(Unit::Years),
+        'M' => Some(Unit::Months),
+        'd' => Some(Unit::Days),
+        'h' | 'H' => Some(Unit::Hours),
+        'm' => Some(Unit::Minutes),
+        's' => Some(Unit::Seconds),
+        'S' => Some(Unit::Milliseconds),
+        _ => None,
+    }
+}
+
+fn saturate(value: i128) -> i64 {
+    value.clamp(i64::MIN as i128, i64::MAX as i128) as i64
+}
+
+// Adds calendar months to a wall-clock time, clamping the day to the end of
+// the resulting month.
+fn add_months_wall(ms: i64, months: i64) -> i64 {
+    let (year, month, day) = crate::calendar::civil_from_days(ms.div_euclid(86_400_000));
+    let total = year as i64 * 12 + month as i64 - 1 + months;
+    let (year, month) = (total.div_euclid(12) as i32, total.rem_euclid(12) as u32 + 1);
+    let day = day.min(crate::calendar::days_in_month(year, month));
+    crate::calendar::days_from_civil(year, month, day) * 86_400_000 + ms.rem_euclid(86_400_000)
+}
+
+// Splits `millis` over `units` (largest first) into `out`, rounding what is
+// left below the smallest unit to the nearest one.
+fn distribute(out: &mut [i128; 8], millis: i128, units: &[Unit]) {
+    let mut rest = millis;
+    for unit in units {
+        let length = UNIT_MS[unit.index()] as i128;
+        out[unit.index()] += rest / length;
+        rest %= length;
+    }
+    if let Some(smallest) = units.last() {
+        let length = UNIT_MS[smallest.index()] as i128;
+        if rest.abs() * 2 >= length {
+            out[smallest.index()] += rest.signum();
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,178 @@
+// The error type shared by every fallible constructor, parser and setter, so
+// callers can match on what went wrong instead of inspecting message text.
+
+use std::fmt;
+
+use crate::calendar::Weekday;
+
+/// An error from parsing, building or adjusting a [`DateTime`](crate::DateTime).
+///
+/// Positions are byte offsets into the input string. The `Display` output is
+/// a short English sentence suitable for logs.
+///
+/// # Examples
+///
+/// ```rust
+/// use tempotime::{DateTime, Error};
+///
+/// match DateTime::from_iso("2025-13-01T00:00:00Z") {
+///     Err(Error::InvalidComponent { unit: "month", value: 13 }) => {}
+///     other => panic!("unexpected: {:?}", other),
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// A year outside the supported range of -9999 to 9999.
+    InvalidYear(i64),
+    /// A component outside its valid range, such as month 13 or April 31st.
+    InvalidComponent {
+        /// The component, e.g. `"month"` or `"day of year"`.
+        unit: &'static str,
+        /// The rejected value.
+        value: i64,
+    },
+    /// An instant (timestamp, Julian day, ...) outside years -9999 to 9999.
+    OutOfRange,
+    /// A key that is not a date or time unit, as passed to `from_object`.
+    UnknownUnit(String),
+    /// A zone name that is neither an IANA name nor a known fixed zone.
+    UnknownZone(String),
+    /// The input ended where more was expected.
+    UnexpectedEnd {
+        /// Where more input was expected.
+        position: usize,
+    },
+    /// A specific character was expected, e.g. the `T` between date and time.
+    Expected {
+        /// The character that was expected.
+        expected: char,
+        /// Where it was expected.
+        position: usize,
+    },
+    /// The input does not hold a valid value of the given kind.
+    Invalid {
+        /// What was being read, e.g. `"month"` or `"offset"`.
+        what: &'static str,
+        /// Where the value starts.
+        position: usize,
+    },
+    /// Input is left over after a complete value.
+    TrailingInput {
+        /// Where the unused input starts.
+        position: usize,
+    },
+    /// A quoted literal in a format string has no closing `'`.
+    UnterminatedLiteral,
+    /// The weekday in the input does not fall on the parsed date.
+    WeekdayMismatch {
+        /// The weekday read from the input.
+        parsed: Weekday,
+        /// The weekday of the parsed date.
+        actual: Weekday,
+    },
+    /// An interval whose end is before its start, as rejected by
+    /// `Interval::try_new`.
+    EndBeforeStart,
+    /// A `Duration` component or length that does not fit in an `i64`, as
+    /// reported by `Duration::checked_plus` and friends.
+    Overflow,
+    /// A negative duration where only non-negative ones are allowed, as in
+    /// `Duration::to_std`.
+    NegativeDuration,
+    /// The output does not fit in the buffer given to `format_to_slice` or
+    /// `iso_to_slice`.
+    BufferTooSmall {
+        /// The length in bytes the output needs.
+        needed: usize,
+    },
+    /// A wall-clock time skipped by a DST change in the zone, rejected with
+    /// `Disambiguation::Reject`.
+    NonexistentLocalTime,
+    /// A wall-clock time that happens twice in the zone because of a DST
+    /// change, rejected with `Disambiguation::Reject`.
+    AmbiguousLocalTime,
+    /// None of the formats tried by `from_formats` or `parse` matched; holds
+    /// each format with the error it produced, in the order tried.
+    NoMatchingFormat(Vec<(String, Error)>),
+}
+
+impl Error {
+    // The same error with its input position moved `offset` bytes on, for
+    // errors from parsing one part of a longer string.
+    pub(crate) fn offset_by(self, offset: usize) -> Error {
+        match self {
+            Error::UnexpectedEnd { position } => Error::UnexpectedEnd {
+                position: position + offset,
+            },
+            Error::Expected { expected, position } => Error::Expected {
+                expected,
+                position: position + offset,
+            },
+            Error::Invalid { what, position } => Error::Invalid {
+                what,
+                position: position + offset,
+            },
+            Error::TrailingInput { position } => Error::TrailingInput {
+                position: position + offset,
+            },
+            other => other,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidYear(year) => {
+                write!(
+                    f,
+                    "Invalid year: {} (supported range is -9999 to 9999)",
+                    year
+                )
+            }
+            Error::InvalidComponent { unit, value } => write!(f, "Invalid {}: {}", unit, value),
+            Error::OutOfRange => f.write_str("Instant outside years -9999 to 9999"),
+            Error::UnknownUnit(unit) => write!(f, "Unknown unit '{}'", unit),
+            Error::UnknownZone(zone) => write!(f, "Unknown zone '{}'", zone),
+            Error::UnexpectedEnd { position } => {
+                write!(f, "Unexpected end of input at position {}", position)
+            }
+            Error::Expected { expected, position } => {
+                write!(f, "Expected '{}' at position {}", expected, position)
+            }
+            Error::Invalid { what, position } => {
+                write!(f, "Invalid {} at position {}", what, position)
+            }
+            Error::TrailingInput { position } => {
+                write!(f, "Unexpected trailing input at position {}", position)
+            }
+            Error::UnterminatedLiteral => f.write_str("Unterminated literal in format string"),
+            Error::WeekdayMismatch { parsed, actual } => {
+                write!(
+                    f,
+                    "Weekday mismatch: input says {:?} but the date is a {:?}",
+                    parsed, actual
+                )
+            }
+            Error::EndBeforeStart => f.write_str("Interval ends before it starts"),
+            Error::Overflow => f.write_str("Duration overflows i64"),
+            Error::NegativeDuration => f.write_str("Duration is negative"),
+            Error::BufferTooSmall { needed } => {
+                write!(f, "Buffer too small: {} bytes needed", needed)
+            }
+            Error::NonexistentLocalTime => f.write_str("Local time skipped by a clock change"),
+            Error::AmbiguousLocalTime => f.write_str("Local time repeated by a clock change"),
+            Error::NoMatchingFormat(attempts) => {
+                f.write_str("No format matched")?;
+                for (i, (format, error)) in attempts.iter().enumerate() {
+                    let sep = if i == 0 { ": " } else { "; " };
+                    write!(f, "{}'{}' ({})", sep, format, error)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -1,276 +1,602 @@
 // core::fmt::Write is referenced fully-qualified in this module; avoid an unused import.
 
-#[cfg(feature = "chrono")]
-use chrono::{Datelike, Timelike};
+use std::borrow::Cow;
 
-#[cfg(feature = "chrono")]
-pub(crate) fn format_datetime(dt: &chrono::DateTime<chrono::Utc>, fmt: &str) -> String {
-    let mut result = String::new();
-    let _ = format_datetime_into(&mut result, dt, fmt);
-    result
+use crate::calendar::WeekRule;
+use crate::error::Error;
+use crate::locale::Locale;
+use crate::options::ParseOptions;
+use crate::settings::Settings;
+use crate::zone::Zone;
+use crate::DateTime;
+
+/// A format pattern tokenized once, for formatting or parsing many values.
+///
+/// [`to_format`](DateTime::to_format) and
+/// [`from_format`](DateTime::from_format) re-read their pattern on every
+/// call. In a hot loop, compile the pattern once instead; the results are the
+/// same as calling those methods with it.
+///
+/// # Examples
+///
+/// ```rust
+/// use tempotime::{DateTime, Format};
+///
+/// let format = Format::compile("yyyy-MM-dd HH:mm:ss").unwrap();
+///
+/// let dt = format.parse("2025-10-30 14:30:00").unwrap();
+/// assert_eq!(dt, DateTime::from_iso("2025-10-30T14:30:00Z").unwrap());
+/// assert_eq!(format.format(&dt), "2025-10-30 14:30:00");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Format {
+    pattern: String,
+    items: Vec<Item<'static>>,
 }
 
-#[cfg(feature = "chrono")]
-pub(crate) fn format_datetime_into<W: core::fmt::Write>(result: &mut W, dt: &chrono::DateTime<chrono::Utc>, fmt: &str) -> core::fmt::Result {
-    let year = dt.year();
-    let month = dt.month();
-    let day = dt.day();
-    let hour = dt.hour();
-    let minute = dt.minute();
-    let second = dt.second();
-    let millis = dt.timestamp_subsec_millis();
-    let mut chars = fmt.chars().peekable();
-
-    while let Some(ch) = chars.next() {
-        match ch {
-            'y' => {
-                let count = 1 + chars.clone().take_while(|&c| c == 'y').count();
-                for _ in 1..count {
-                    chars.next();
-                }
-                if count >= 4 {
-                    let _ = write!(result, "{:04}", year);
-                } else {
-                    let _ = write!(result, "{:02}", year % 100);
-                }
-            }
-            'M' => {
-                let count = 1 + chars.clone().take_while(|&c| c == 'M').count();
-                for _ in 1..count {
-                    chars.next();
-                }
-                if count >= 4 {
-                    let _ = result.write_str(month_name(month));
-                } else if count == 3 {
-                    let _ = result.write_str(month_short(month));
-                } else if count == 2 {
-                    let _ = write!(result, "{:02}", month);
-                } else {
-                    let _ = write!(result, "{}", month);
-                }
-            }
-            'd' => {
-                if chars.peek() == Some(&'o') {
-                    chars.next();
-                    let _ = write_ordinal(result, day);
-                } else {
-                    let count = 1 + chars.clone().take_while(|&c| c == 'd').count();
-                    for _ in 1..count {
-                        chars.next();
-                    }
-                    if count >= 2 {
-                        let _ = write!(result, "{:02}", day);
-                    } else {
-                        let _ = write!(result, "{}", day);
-                    }
-                }
-            }
-            'E' => {
-                let count = 1 + chars.clone().take_while(|&c| c == 'E').count();
-                for _ in 1..count {
-                    chars.next();
+impl Format {
+    /// Tokenizes `pattern`, which uses the same tokens as
+    /// [`to_format`](DateTime::to_format) and
+    /// [`from_format`](DateTime::from_format).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::UnterminatedLiteral`] if a quoted literal is not closed.
+    pub fn compile(pattern: &str) -> Result<Self, Error> {
+        Ok(Format {
+            pattern: pattern.to_string(),
+            items: Tokens::new(pattern)
+                .map(|item| item.map(Item::into_owned))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    /// The pattern this format was compiled from.
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Formats `dt`, like [`to_format`](DateTime::to_format).
+    pub fn format(&self, dt: &DateTime) -> String {
+        let mut result = String::new();
+        let _ = self.format_into(&mut result, dt);
+        result
+    }
+
+    /// Writes `dt` into `w`, like [`format_into`](DateTime::format_into).
+    pub fn format_into<W: core::fmt::Write>(&self, w: &mut W, dt: &DateTime) -> core::fmt::Result {
+        let fields = dt.format_fields();
+        for item in &self.items {
+            write_item(w, &fields, item, &Locale::EN)?;
+        }
+        Ok(())
+    }
+
+    /// Guesses the format of `samples` from a list of common patterns: ISO
+    /// 8601 with a `T` or a space and optional milliseconds and offset, US
+    /// `MM/dd/yyyy` and day-first `dd/MM/yyyy` dates, RFC 2822, and Unix
+    /// timestamps in seconds (`X`) or milliseconds (`x`).
+    ///
+    /// Returns the first pattern that parses every sample, or `None` when
+    /// there is none or `samples` is empty. Slashed dates that fit both orders,
+    /// such as `01/02/2025`, are read as US dates; a sample with a day above 12
+    /// settles it. Integers below 10^11 in magnitude are taken as seconds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::Format;
+    ///
+    /// let infer = |samples: &[&str]| Format::infer(samples).map(|f| f.pattern().to_string());
+    ///
+    /// assert_eq!(infer(&["2025-10-30T14:30:00Z"]).as_deref(), Some("yyyy-MM-dd'T'HH:mm:ssZ"));
+    /// assert_eq!(infer(&["01/02/2025", "25/12/2025"]).as_deref(), Some("dd/MM/yyyy"));
+    /// assert_eq!(
+    ///     infer(&["Thu, 30 Oct 2025 14:30:00 +0000"]).as_deref(),
+    ///     Some("EEE, d MMM yyyy HH:mm:ss Z")
+    /// );
+    /// assert_eq!(infer(&["1761834600"]).as_deref(), Some("X"));
+    /// assert_eq!(infer(&["yesterday"]), None);
+    /// ```
+    pub fn infer(samples: &[&str]) -> Option<Format> {
+        if samples.is_empty() {
+            return None;
+        }
+        let integers: Option<Vec<i64>> = samples.iter().map(|s| s.parse().ok()).collect();
+        let candidates: &[&str] = match integers {
+            Some(n) if n.iter().all(|n| n.unsigned_abs() < 100_000_000_000) => &["X"],
+            Some(_) => &["x"],
+            None => INFERRED_PATTERNS,
+        };
+        candidates
+            .iter()
+            .filter_map(|pattern| Format::compile(pattern).ok())
+            .find(|format| samples.iter().all(|s| format.parse(s).is_ok()))
+    }
+
+    /// Parses `s`, like [`from_format`](DateTime::from_format).
+    ///
+    /// # Errors
+    ///
+    /// As for [`from_format`](DateTime::from_format).
+    pub fn parse(&self, s: &str) -> Result<DateTime, Error> {
+        self.parse_with(s, ParseOptions::default())
+    }
+
+    /// Parses each line with this format, for bulk input such as log
+    /// timestamps.
+    ///
+    /// Lines are parsed lazily, one per item, and a failure does not stop the
+    /// rest. Parsing a line does not allocate.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::Format;
+    ///
+    /// let format = Format::compile("yyyy-MM-dd HH:mm:ss").unwrap();
+    /// let log = "2025-10-30 14:30:00\n2025-10-30 14:30:01\nnot a timestamp";
+    ///
+    /// let parsed: Vec<_> = format.parse_many(log.lines()).collect();
+    /// assert_eq!(parsed.len(), 3);
+    /// assert!(parsed[0].is_ok() && parsed[1].is_ok());
+    /// assert!(parsed[2].is_err());
+    /// ```
+    pub fn parse_many<'a, I>(
+        &'a self,
+        lines: I,
+    ) -> impl Iterator<Item = Result<DateTime, Error>> + 'a
+    where
+        I: IntoIterator<Item = &'a str>,
+        I::IntoIter: 'a,
+    {
+        lines.into_iter().map(move |line| self.parse(line))
+    }
+
+    /// Parses `s` with options, like
+    /// [`from_format_with`](DateTime::from_format_with).
+    ///
+    /// # Errors
+    ///
+    /// As for [`from_format_with`](DateTime::from_format_with).
+    pub fn parse_with(&self, s: &str, options: ParseOptions) -> Result<DateTime, Error> {
+        DateTime::from_items(s, &self.items, options)
+    }
+}
+
+// Tried in order by `Format::infer`, most specific first.
+const INFERRED_PATTERNS: &[&str] = &[
+    "yyyy-MM-dd'T'HH:mm:ss.SSSZ",
+    "yyyy-MM-dd'T'HH:mm:ssZ",
+    "yyyy-MM-dd'T'HH:mmZ",
+    "yyyy-MM-dd'T'HH:mm:ss.SSS",
+    "yyyy-MM-dd'T'HH:mm:ss",
+    "yyyy-MM-dd'T'HH:mm",
+    "yyyy-MM-dd HH:mm:ss.SSSZ",
+    "yyyy-MM-dd HH:mm:ssZ",
+    "yyyy-MM-dd HH:mm:ss.SSS",
+    "yyyy-MM-dd HH:mm:ss",
+    "yyyy-MM-dd HH:mm",
+    "yyyy-MM-dd",
+    "MM/dd/yyyy HH:mm:ss",
+    "MM/dd/yyyy h:mm a",
+    "MM/dd/yyyy",
+    "M/d/yyyy h:mm a",
+    "M/d/yyyy",
+    "dd/MM/yyyy HH:mm:ss",
+    "dd/MM/yyyy",
+    "d/M/yyyy",
+    "EEE, d MMM yyyy HH:mm:ss Z",
+    "d MMM yyyy HH:mm:ss Z",
+];
+
+// One piece of a pattern.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Item<'a> {
+    // A run of one token letter, e.g. ('M', 3) for `MMM`.
+    Field(char, usize),
+    // `do`: the day of the month with an English ordinal suffix.
+    Ordinal,
+    // A character that stands for itself, or a single-letter token like `a`.
+    Char(char),
+    // Quoted literal text, borrowed from the pattern while tokenizing. A `''`
+    // inside a literal splits it around a `Char('\'')`.
+    Literal(Cow<'a, str>),
+}
+
+impl Item<'_> {
+    pub(crate) fn into_owned(self) -> Item<'static> {
+        match self {
+            Item::Field(letter, count) => Item::Field(letter, count),
+            Item::Ordinal => Item::Ordinal,
+            Item::Char(c) => Item::Char(c),
+            Item::Literal(text) => Item::Literal(Cow::Owned(text.into_owned())),
+        }
+    }
+}
+
+// Letters whose repeat count picks a variant, so a run of them is one item.
+const RUN_LETTERS: &str = "yqGMdDoEHhmsSnikWZ";
+
+// Splits a pattern into items without allocating. Text between single quotes
+// is a literal, and `''` stands for one quote both inside and outside of one.
+// A quote that is never closed is an error, unless `open_ended`, when the
+// literal runs to the end of the pattern; output is infallible, parsing is
+// not.
+pub(crate) struct Tokens<'a> {
+    rest: &'a str,
+    open_ended: bool,
+    in_literal: bool,
+}
+
+impl<'a> Tokens<'a> {
+    pub(crate) fn new(pattern: &'a str) -> Self {
+        Tokens {
+            rest: pattern,
+            open_ended: false,
+            in_literal: false,
+        }
+    }
+
+    pub(crate) fn open_ended(pattern: &'a str) -> Self {
+        Tokens {
+            rest: pattern,
+            open_ended: true,
+            in_literal: false,
+        }
+    }
+}
+
+impl<'a> Iterator for Tokens<'a> {
+    type Item = Result<Item<'a>, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.in_literal {
+            match self.rest.find('\'') {
+                Some(0) if self.rest[1..].starts_with('\'') => {
+                    self.rest = &self.rest[2..];
+                    return Some(Ok(Item::Char('\'')));
                 }
-                let wd = dt.weekday().num_days_from_monday();
-                if count >= 4 {
-                    let _ = result.write_str(weekday_name(wd));
-                } else {
-                    let _ = result.write_str(weekday_short(wd));
+                Some(0) => {
+                    self.rest = &self.rest[1..];
+                    self.in_literal = false;
                 }
-            }
-            'H' => {
-                let count = 1 + chars.clone().take_while(|&c| c == 'H').count();
-                for _ in 1..count {
-                    chars.next();
+                Some(end) => {
+                    let (text, rest) = self.rest.split_at(end);
+                    self.rest = rest;
+                    return Some(Ok(Item::Literal(Cow::Borrowed(text))));
                 }
-                if count >= 2 {
-                    let _ = write!(result, "{:02}", hour);
-                } else {
-                    let _ = write!(result, "{}", hour);
+                None => {
+                    let text = core::mem::take(&mut self.rest);
+                    self.in_literal = false;
+                    return match (text.is_empty(), self.open_ended) {
+                        (_, false) => Some(Err(Error::UnterminatedLiteral)),
+                        (true, true) => None,
+                        (false, true) => Some(Ok(Item::Literal(Cow::Borrowed(text)))),
+                    };
                 }
             }
-            'h' => {
-                let count = 1 + chars.clone().take_while(|&c| c == 'h').count();
-                for _ in 1..count {
-                    chars.next();
-                }
-                let hour12 = match hour {
-                    0 => 12,
-                    h if h > 12 => h - 12,
-                    h => h,
-                };
-                if count >= 2 {
-                    let _ = write!(result, "{:02}", hour12);
-                } else {
-                    let _ = write!(result, "{}", hour12);
-                }
+        }
+
+        let mut chars = self.rest.chars();
+        let item = match chars.next()? {
+            '\'' if chars.as_str().starts_with('\'') => {
+                chars.next();
+                Item::Char('\'')
             }
-            'm' => {
-                let count = 1 + chars.clone().take_while(|&c| c == 'm').count();
-                for _ in 1..count {
-                    chars.next();
-                }
-                let _ = write!(result, "{:02}", minute);
+            '\'' => {
+                self.rest = chars.as_str();
+                self.in_literal = true;
+                return self.next();
             }
-            's' => {
-                let count = 1 + chars.clone().take_while(|&c| c == 's').count();
-                for _ in 1..count {
-                    chars.next();
-                }
-                let _ = write!(result, "{:02}", second);
+            'd' if chars.as_str().starts_with('o') => {
+                chars.next();
+                Item::Ordinal
             }
-            'S' => {
-                let count = 1 + chars.clone().take_while(|&c| c == 'S').count();
-                for _ in 1..count {
+            c if RUN_LETTERS.contains(c) => {
+                let mut count = 1;
+                while chars.as_str().starts_with(c) {
                     chars.next();
+                    count += 1;
                 }
-                let _ = write!(result, "{:03}", millis);
-            }
-            'a' => {
-                if hour < 12 {
-                    let _ = result.write_str("am");
-                } else {
-                    let _ = result.write_str("pm");
-                }
+                Item::Field(c, count)
             }
-            _ => {
-                let _ = write!(result, "{}", ch);
-            }
-        }
+            c => Item::Char(c),
+        };
+        self.rest = chars.as_str();
+        Some(Ok(item))
     }
+}
 
-    Ok(())
+// The wall-clock values a pattern is rendered from.
+pub(crate) struct Fields {
+    pub(crate) year: i32,
+    pub(crate) month: u32,
+    pub(crate) day: u32,
+    pub(crate) hour: u32,
+    pub(crate) minute: u32,
+    pub(crate) second: u32,
+    pub(crate) millis: u32,
+    // 0 is Monday.
+    pub(crate) weekday: u32,
+    // The offset and zone the fields were read in, for `Z` and `z`.
+    pub(crate) offset_secs: i32,
+    pub(crate) zone: Option<Zone>,
+    pub(crate) instant_ms: i64,
 }
 
-#[cfg(not(feature = "chrono"))]
-pub(crate) fn format_datetime_from_ts(ts_ms: i64, fmt: &str) -> String {
+pub(crate) fn format_datetime(fields: &Fields, fmt: &str) -> String {
     let mut result = String::new();
-    let _ = format_datetime_from_ts_into(&mut result, ts_ms, fmt);
+    let _ = write_pattern(&mut result, fields, fmt, &Locale::EN);
     result
 }
 
-#[cfg(not(feature = "chrono"))]
-pub(crate) fn format_datetime_from_ts_into<W: core::fmt::Write>(w: &mut W, ts_ms: i64, fmt: &str) -> core::fmt::Result {
-    let (year, month, day, hour, minute, second, millis) = decompose_timestamp_ms(ts_ms);
-    let mut chars = fmt.chars().peekable();
-
-    while let Some(ch) = chars.next() {
-        match ch {
-            'y' => {
-                let count = 1 + chars.clone().take_while(|&c| c == 'y').count();
-                for _ in 1..count {
-                    chars.next();
-                }
-                if count >= 4 {
-                    let _ = write!(w, "{:04}", year);
-                } else {
-                    let _ = write!(w, "{:02}", year % 100);
-                }
-            }
-            'M' => {
-                let count = 1 + chars.clone().take_while(|&c| c == 'M').count();
-                for _ in 1..count {
-                    chars.next();
-                }
-                if count >= 4 {
-                    let _ = w.write_str(month_name(month));
-                } else if count == 3 {
-                    let _ = w.write_str(month_short(month));
-                } else if count == 2 {
-                    let _ = write!(w, "{:02}", month);
-                } else {
-                    let _ = write!(w, "{}", month);
-                }
-            }
-            'd' => {
-                if chars.peek() == Some(&'o') {
-                    chars.next();
-                    let _ = write_ordinal(w, day);
-                } else {
-                    let count = 1 + chars.clone().take_while(|&c| c == 'd').count();
-                    for _ in 1..count {
-                        chars.next();
-                    }
-                    if count >= 2 {
-                        let _ = write!(w, "{:02}", day);
-                    } else {
-                        let _ = write!(w, "{}", day);
-                    }
-                }
-            }
-            'E' => {
-                let count = 1 + chars.clone().take_while(|&c| c == 'E').count();
-                for _ in 1..count {
-                    chars.next();
-                }
-                let wd = weekday_from_ymd(year, month, day);
-                if count >= 4 {
-                    let _ = w.write_str(weekday_name(wd));
-                } else {
-                    let _ = w.write_str(weekday_short(wd));
-                }
-            }
-            'H' => {
-                let count = 1 + chars.clone().take_while(|&c| c == 'H').count();
-                for _ in 1..count {
-                    chars.next();
-                }
-                if count >= 2 {
-                    let _ = write!(w, "{:02}", hour);
-                } else {
-                    let _ = write!(w, "{}", hour);
-                }
-            }
-            'h' => {
-                let count = 1 + chars.clone().take_while(|&c| c == 'h').count();
-                for _ in 1..count {
-                    chars.next();
-                }
-                let hour12 = match hour {
-                    0 => 12,
-                    h if h > 12 => h - 12,
-                    h => h,
-                };
-                if count >= 2 {
-                    let _ = write!(w, "{:02}", hour12);
-                } else {
-                    let _ = write!(w, "{}", hour12);
-                }
-            }
-            'm' => {
-                let count = 1 + chars.clone().take_while(|&c| c == 'm').count();
-                for _ in 1..count {
-                    chars.next();
-                }
-                let _ = write!(w, "{:02}", minute);
-            }
-            's' => {
-                let count = 1 + chars.clone().take_while(|&c| c == 's').count();
-                for _ in 1..count {
-                    chars.next();
-                }
-                let _ = write!(w, "{:02}", second);
-            }
-            'S' => {
-                let count = 1 + chars.clone().take_while(|&c| c == 'S').count();
-                for _ in 1..count {
-                    chars.next();
-                }
-                let _ = write!(w, "{:03}", millis);
+// The item each `render` placeholder stands for.
+const PLACEHOLDERS: &[(&str, Item<'static>)] = &[
+    ("year", Item::Field('y', 4)),
+    ("quarter", Item::Field('q', 1)),
+    ("month", Item::Field('M', 1)),
+    ("month_name", Item::Field('M', 4)),
+    ("month_short", Item::Field('M', 3)),
+    ("day", Item::Field('d', 1)),
+    ("day_ordinal", Item::Ordinal),
+    ("weekday", Item::Field('E', 4)),
+    ("weekday_short", Item::Field('E', 3)),
+    ("hour", Item::Field('H', 2)),
+    ("hour12", Item::Field('h', 1)),
+    ("minute", Item::Field('m', 2)),
+    ("second", Item::Field('s', 2)),
+    ("millisecond", Item::Field('S', 3)),
+    ("meridiem", Item::Char('a')),
+    ("offset", Item::Field('Z', 2)),
+    ("zone", Item::Char('z')),
+    ("zone_abbreviation", Item::Field('Z', 4)),
+];
+
+// Appends `template` to `out` with each `{name}` replaced by that field of
+// `fields`; `{{` and `}}` stand for braces.
+pub(crate) fn render_template(
+    out: &mut String,
+    fields: &Fields,
+    template: &str,
+) -> Result<(), Error> {
+    let mut rest = template;
+    while let Some(brace) = rest.find(['{', '}']) {
+        out.push_str(&rest[..brace]);
+        let position = template.len() - rest.len() + brace;
+        let (open, after) = rest[brace..].split_at(1);
+        if after.starts_with(open) {
+            out.push_str(open);
+            rest = &after[1..];
+            continue;
+        }
+        if open == "}" {
+            return Err(Error::Invalid {
+                what: "placeholder",
+                position,
+            });
+        }
+        let close = after.find('}').ok_or(Error::UnexpectedEnd {
+            position: template.len(),
+        })?;
+        let item = PLACEHOLDERS
+            .iter()
+            .find(|(name, _)| *name == after[..close].trim())
+            .map(|(_, item)| item)
+            .ok_or(Error::Invalid {
+                what: "placeholder",
+                position,
+            })?;
+        let _ = write_item(out, fields, item, &Locale::EN);
+        rest = &after[close + 1..];
+    }
+    out.push_str(rest);
+    Ok(())
+}
+
+// A `fmt::Write` into a byte slice that never fails: it keeps what fits and
+// counts the rest, so the caller learns how large a buffer would have been
+// needed.
+pub(crate) struct SliceWriter<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+impl<'a> SliceWriter<'a> {
+    pub(crate) fn new(buf: &'a mut [u8]) -> Self {
+        SliceWriter { buf, len: 0 }
+    }
+
+    // The number of bytes written, or the error if they did not all fit.
+    pub(crate) fn finish(self) -> Result<usize, Error> {
+        if self.len > self.buf.len() {
+            Err(Error::BufferTooSmall { needed: self.len })
+        } else {
+            Ok(self.len)
+        }
+    }
+}
+
+impl core::fmt::Write for SliceWriter<'_> {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        if let Some(dest) = self.buf.get_mut(self.len..self.len + s.len()) {
+            dest.copy_from_slice(s.as_bytes());
+        }
+        self.len += s.len();
+        Ok(())
+    }
+}
+
+pub(crate) fn write_pattern<W: core::fmt::Write>(
+    w: &mut W,
+    fields: &Fields,
+    fmt: &str,
+    locale: &Locale,
+) -> core::fmt::Result {
+    for item in Tokens::open_ended(fmt).flatten() {
+        write_item(w, fields, &item, locale)?;
+    }
+    Ok(())
+}
+
+fn write_item<W: core::fmt::Write>(
+    w: &mut W,
+    f: &Fields,
+    item: &Item,
+    locale: &Locale,
+) -> core::fmt::Result {
+    let (letter, count) = match *item {
+        Item::Field(letter, count) => (letter, count),
+        Item::Ordinal => {
+            write_padded(w, f.day as u64, 1)?;
+            return w.write_str(locale.ordinal_suffix(f.day));
+        }
+        Item::Char('a') => return w.write_str(locale.meridiem[(f.hour >= 12) as usize]),
+        Item::Char('A') => {
+            return locale.meridiem[(f.hour >= 12) as usize]
+                .chars()
+                .flat_map(char::to_uppercase)
+                .try_for_each(|c| w.write_char(c))
+        }
+        Item::Char('X') => return write_signed(w, f.instant_ms.div_euclid(1000)),
+        Item::Char('x') => return write_signed(w, f.instant_ms),
+        Item::Char('z') => return w.write_str(f.zone.as_ref().map_or("UTC", Zone::name)),
+        Item::Char(c) => return w.write_char(c),
+        Item::Literal(ref literal) => return w.write_str(literal),
+    };
+    match letter {
+        'y' if count >= 4 => crate::iso::write_year(w, f.year),
+        'y' => write_padded(w, (f.year.unsigned_abs() % 100) as u64, 2),
+        'q' => write_number(w, (f.month - 1) / 3 + 1, count),
+        'G' => w.write_str(era_name(f.year, count)),
+        'M' if count >= 4 => w.write_str(locale.months[f.month as usize - 1]),
+        'M' if count == 3 => w.write_str(locale.months_short[f.month as usize - 1]),
+        'M' => write_number(w, f.month, count),
+        'd' => write_number(w, f.day, count),
+        'D' | 'o' if count == 3 => write_padded(
+            w,
+            crate::calendar::day_of_year(f.year, f.month, f.day) as u64,
+            3,
+        ),
+        'o' => write_padded(
+            w,
+            crate::calendar::day_of_year(f.year, f.month, f.day) as u64,
+            1,
+        ),
+        'E' if count >= 4 => w.write_str(locale.weekdays[f.weekday as usize]),
+        'E' => w.write_str(locale.weekdays_short[f.weekday as usize]),
+        'H' => write_number(w, f.hour, count),
+        'h' => {
+            let hour12 = match f.hour {
+                0 => 12,
+                h if h > 12 => h - 12,
+                h => h,
+            };
+            write_number(w, hour12, count)
+        }
+        'm' => write_padded(w, f.minute as u64, 2),
+        's' => write_padded(w, f.second as u64, 2),
+        'S' => write_padded(w, f.millis as u64, 3),
+        'Z' if count >= 4 => match f
+            .zone
+            .and_then(|zone| zone.write_abbreviation(w, f.instant_ms))
+        {
+            Some(result) => result,
+            None if f.offset_secs == 0 => w.write_str("UTC"),
+            None => {
+                w.write_str("UTC")?;
+                write_offset(w, f.offset_secs, 1)
             }
-            'a' => {
-                if hour < 12 {
-                    let _ = w.write_str("am");
-                } else {
-                    let _ = w.write_str("pm");
-                }
+        },
+        'Z' => write_offset(w, f.offset_secs, count),
+        'n' | 'i' | 'W' | 'k' => {
+            let rule = if matches!(letter, 'W' | 'k') {
+                WeekRule::ISO
+            } else {
+                Settings::week_rule()
+            };
+            let (week_year, week) = rule.week_date(f.year, f.month, f.day);
+            match letter {
+                'n' | 'W' => write_number(w, week, count),
+                _ if count >= 4 => crate::iso::write_year(w, week_year),
+                _ => write_padded(w, (week_year.unsigned_abs() % 100) as u64, 2),
             }
-            _ => {
-                let _ = write!(w, "{}", ch);
+        }
+        // Letters without a meaning at this count print as themselves.
+        other => {
+            for _ in 0..count {
+                w.write_char(other)?;
             }
+            Ok(())
         }
     }
+}
 
-    Ok(())
+// `+5` or `+5:30` for `Z`, `+05:00` for `ZZ` and `+0500` for `ZZZ`.
+fn write_offset<W: core::fmt::Write>(
+    w: &mut W,
+    offset_secs: i32,
+    count: usize,
+) -> core::fmt::Result {
+    let sign = if offset_secs < 0 { '-' } else { '+' };
+    let minutes = offset_secs.unsigned_abs() / 60;
+    let (hours, minutes) = (minutes / 60, minutes % 60);
+    w.write_char(sign)?;
+    write_padded(w, hours as u64, if count == 1 { 1 } else { 2 })?;
+    match count {
+        1 if minutes == 0 => return Ok(()),
+        1 | 2 => w.write_char(':')?,
+        _ => {}
+    }
+    write_padded(w, minutes as u64, 2)
+}
+
+// A number at its natural width for a single letter, two digits otherwise.
+fn write_number<W: core::fmt::Write>(w: &mut W, value: u32, count: usize) -> core::fmt::Result {
+    write_padded(w, value as u64, count.min(2))
+}
+
+// "00" to "99" back to back, to turn two digits at a time into text.
+const DIGIT_PAIRS: &[u8; 200] = b"\
+    0001020304050607080910111213141516171819\
+    2021222324252627282930313233343536373839\
+    4041424344454647484950515253545556575859\
+    6061626364656667686970717273747576777879\
+    8081828384858687888990919293949596979899";
+
+// `value` in decimal, zero-padded to at least `width` digits. This is the
+// formatter's hot path, so it avoids `core::fmt`'s padding machinery.
+pub(crate) fn write_padded<W: core::fmt::Write>(
+    w: &mut W,
+    value: u64,
+    width: usize,
+) -> core::fmt::Result {
+    let mut buf = [b'0'; 20];
+    let mut pos = buf.len();
+    let mut n = value;
+    while n >= 100 {
+        let pair = (n % 100) as usize * 2;
+        n /= 100;
+        pos -= 2;
+        buf[pos..pos + 2].copy_from_slice(&DIGIT_PAIRS[pair..pair + 2]);
+    }
+    if n >= 10 {
+        pos -= 2;
+        buf[pos..pos + 2].copy_from_slice(&DIGIT_PAIRS[n as usize * 2..n as usize * 2 + 2]);
+    } else {
+        pos -= 1;
+        buf[pos] = b'0' + n as u8;
+    }
+    // `buf` starts out as zeros, so the padding is already in place.
+    let start = pos.min(buf.len().saturating_sub(width));
+    w.write_str(core::str::from_utf8(&buf[start..]).map_err(|_| core::fmt::Error)?)
+}
+
+fn write_signed<W: core::fmt::Write>(w: &mut W, value: i64) -> core::fmt::Result {
+    if value < 0 {
+        w.write_char('-')?;
+    }
+    write_padded(w, value.unsigned_abs(), 1)
 }
 
 #[cfg(not(feature = "chrono"))]
@@ -285,71 +611,99 @@ pub(crate) fn decompose_timestamp_ms(ts_ms: i64) -> (i32, u32, u32, u32, u32, u3
     let second = (rem / 1000) as u32;
     let millis = (rem % 1000) as u32;
 
-    let (year, month, day) = civil_from_days(days);
+    let (year, month, day) = crate::calendar::civil_from_days(days);
     (year, month, day, hour, minute, second, millis)
 }
 
-#[cfg(not(feature = "chrono"))]
-fn civil_from_days(mut z: i64) -> (i32, u32, u32) {
-    z = z + 719468;
-    let era = if z >= 0 {
-        z / 146097
-    } else {
-        (z - 146096) / 146097
-    };
-    let doe = z - era * 146097;
-    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
-    let y = yoe + era * 400;
-    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
-    let mp = (5 * doy + 2) / 153;
-    let d = doy - (153 * mp + 2) / 5 + 1;
-    let m = mp + if mp < 10 { 3 } else { -9 };
-    let year = (y + (m <= 2) as i64) as i32;
-    let month = m as u32;
-    (year, month, d as u32)
-}
-
-#[cfg(not(feature = "chrono"))]
-fn weekday_from_ymd(y: i32, m: u32, d: u32) -> u32 {
-    let y = y as i32;
-    let m = m as i32;
-    let d = d as i32;
-    let (y, m) = if m < 3 { (y - 1, m + 12) } else { (y, m) };
-    let k = y % 100;
-    let j = y / 100;
-    let h = (d + (13 * (m + 1)) / 5 + k + k / 4 + j / 4 + 5 * j) % 7;
-    let dow = match h {
-        0 => 5,
-        1 => 6,
-        2 => 0,
-        3 => 1,
-        4 => 2,
-        5 => 3,
-        6 => 4,
-        _ => 0,
-    };
-    dow
+// Checks a `to_format` pattern at compile time for `tempo_format!`: every
+// ASCII letter outside quotes must be a known token at a length it has, and
+// quotes must be balanced. Returns what is wrong with the first bad token, if
+// anything.
+pub(crate) const fn check_format(fmt: &str) -> Option<&'static str> {
+    let bytes = fmt.as_bytes();
+    let mut quoted = false;
+    let mut i = 0;
+    while i < bytes.len() {
+        let b = bytes[i];
+        if b == b'\'' {
+            // `''` is an escaped quote both inside and outside a literal.
+            if i + 1 < bytes.len() && bytes[i + 1] == b'\'' {
+                i += 2;
+                continue;
+            }
+            quoted = !quoted;
+        } else if !quoted && b == b'd' && i + 1 < bytes.len() && bytes[i + 1] == b'o' {
+            i += 2;
+            continue;
+        } else if !quoted && b.is_ascii_alphabetic() {
+            // Letters in `RUN_LETTERS` are read as a run, like `Tokens`.
+            let mut count = 1;
+            if matches!(
+                b,
+                b'y' | b'q'
+                    | b'G'
+                    | b'M'
+                    | b'd'
+                    | b'D'
+                    | b'o'
+                    | b'E'
+                    | b'H'
+                    | b'h'
+                    | b'm'
+                    | b's'
+                    | b'S'
+                    | b'n'
+                    | b'i'
+                    | b'k'
+                    | b'W'
+                    | b'Z'
+            ) {
+                while i + count < bytes.len() && bytes[i + count] == b {
+                    count += 1;
+                }
+            }
+            let known = match b {
+                b'y' | b'i' | b'k' => count == 2 || count == 4,
+                b'G' => count <= 2 || count == 5,
+                b'M' | b'Z' => count <= 4,
+                b'D' | b'S' => count == 3,
+                b'o' => count == 1 || count == 3,
+                b'E' => count == 3 || count == 4,
+                b'q' | b'd' | b'H' | b'h' | b'm' | b's' | b'n' | b'W' => count <= 2,
+                b'a' | b'A' | b'z' | b'X' | b'x' => true,
+                _ => return Some("unknown format token; quote literal text, e.g. 'T'"),
+            };
+            if !known {
+                return Some(
+                    "format token has an unsupported length, e.g. `DD` instead of `dd` or `DDD`",
+                );
+            }
+            i += count;
+            continue;
+        }
+        i += 1;
+    }
+    if quoted {
+        return Some("unterminated literal in format string");
+    }
+    None
 }
 
-fn month_name(month: u32) -> &'static str {
-    match month {
-        1 => "January",
-        2 => "February",
-        3 => "March",
-        4 => "April",
-        5 => "May",
-        6 => "June",
-        7 => "July",
-        8 => "August",
-        9 => "September",
-        10 => "October",
-        11 => "November",
-        12 => "December",
-        _ => "",
+// Era for an astronomical year: year 0 and earlier are BC. `G` gives the
+// abbreviation, `GG` the long form and `GGGGG` a single letter, as in Luxon.
+fn era_name(year: i32, count: usize) -> &'static str {
+    let bc = year <= 0;
+    match (count, bc) {
+        (5.., true) => "B",
+        (5.., false) => "A",
+        (2..=4, true) => "Before Christ",
+        (2..=4, false) => "Anno Domini",
+        (_, true) => "BC",
+        (_, false) => "AD",
     }
 }
 
-fn month_short(month: u32) -> &'static str {
+pub(crate) fn month_short(month: u32) -> &'static str {
     match month {
         1 => "Jan",
         2 => "Feb",
@@ -367,7 +721,7 @@ fn month_short(month: u32) -> &'static str {
     }
 }
 
-fn weekday_name(day: u32) -> &'static str {
+pub(crate) fn weekday_name(day: u32) -> &'static str {
     match day {
         0 => "Monday",
         1 => "Tuesday",
@@ -380,7 +734,7 @@ fn weekday_name(day: u32) -> &'static str {
     }
 }
 
-fn weekday_short(day: u32) -> &'static str {
+pub(crate) fn weekday_short(day: u32) -> &'static str {
     match day {
         0 => "Mon",
         1 => "Tue",
@@ -393,29 +747,15 @@ fn weekday_short(day: u32) -> &'static str {
     }
 }
 
-fn write_ordinal<W: core::fmt::Write>(w: &mut W, day: u32) -> core::fmt::Result {
-    let suffix = match day {
-        1 | 21 | 31 => "st",
-        2 | 22 => "nd",
-        3 | 23 => "rd",
-        _ => "th",
-    };
-    write!(w, "{}", day)?;
-    w.write_str(suffix)
-}
-
-// `ordinal` helper removed — keep formatting helpers minimal to avoid dead code.
-
-#[cfg(all(test, feature = "chrono"))]
+#[cfg(test)]
 mod tests {
     use super::*;
-    use chrono::TimeZone;
 
     #[test]
     fn test_format_tokens() {
-        let dt = chrono::Utc
-            .with_ymd_and_hms(2025, 10, 29, 14, 5, 9)
-            .unwrap();
+        let dt = DateTime::from_iso("2025-10-29T14:05:09Z")
+            .unwrap()
+            .format_fields();
         assert_eq!(format_datetime(&dt, "yyyy"), "2025");
         assert_eq!(format_datetime(&dt, "yy"), "25");
         assert_eq!(format_datetime(&dt, "MMMM"), "October");
@@ -429,4 +769,81 @@ mod tests {
         assert_eq!(format_datetime(&dt, "ss"), "09");
         assert_eq!(format_datetime(&dt, "a"), "pm");
     }
+
+    #[test]
+    fn test_write_padded() {
+        let padded = |value, width| {
+            let mut out = String::new();
+            write_padded(&mut out, value, width).unwrap();
+            out
+        };
+        assert_eq!(padded(0, 1), "0");
+        assert_eq!(padded(7, 3), "007");
+        assert_eq!(padded(42, 2), "42");
+        assert_eq!(padded(12345, 2), "12345");
+        assert_eq!(padded(100, 4), "0100");
+        assert_eq!(padded(u64::MAX, 0), u64::MAX.to_string());
+    }
+
+    #[test]
+    fn test_tokens_borrow_literals() {
+        let items = Tokens::new("h 'o''clock' a''")
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(
+            items,
+            [
+                Item::Field('h', 1),
+                Item::Char(' '),
+                Item::Literal(Cow::Borrowed("o")),
+                Item::Char('\''),
+                Item::Literal(Cow::Borrowed("clock")),
+                Item::Char(' '),
+                Item::Char('a'),
+                Item::Char('\''),
+            ]
+        );
+        assert!(items
+            .iter()
+            .all(|item| !matches!(item, Item::Literal(Cow::Owned(_)))));
+        assert_eq!(
+            Tokens::new("do 'é'")
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap()[2],
+            Item::Literal(Cow::Borrowed("é"))
+        );
+        assert_eq!(
+            Tokens::new("yyyy 'T").last(),
+            Some(Err(Error::UnterminatedLiteral))
+        );
+        assert_eq!(
+            Tokens::open_ended("yyyy 'T").last(),
+            Some(Ok(Item::Literal(Cow::Borrowed("T"))))
+        );
+        assert_eq!(Tokens::open_ended("yyyy '").count(), 2);
+    }
+
+    #[test]
+    fn test_check_format() {
+        assert_eq!(check_format("yyyy-MM-dd'T'HH:mm:ss.SSS ZZ"), None);
+        assert_eq!(
+            check_format("EEEE, MMMM do yy G DDD o q kkkk-WW iiii-nn h a z X x"),
+            None
+        );
+        assert_eq!(check_format("ddo"), None);
+        assert!(check_format("YYYY").is_some());
+        assert!(check_format("'unterminated").is_some());
+        for pattern in [
+            "yyyy-MM-DD",
+            "yyyy-MM-D",
+            "y",
+            "yyy",
+            "SS",
+            "E",
+            "mmm",
+            "ZZZZZ",
+        ] {
+            assert!(check_format(pattern).is_some(), "{}", pattern);
+        }
+    }
 }
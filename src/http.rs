@@ -0,0 +1,325 @@
+// HTTP dates (RFC 9110 §5.6.7). Output is always the preferred IMF-fixdate;
+// input also accepts the two obsolete forms senders may still use. The more
+// general RFC 2822 (email) date-time, which IMF-fixdate is a profile of, is
+// parsed here too.
+
+use crate::error::Error;
+use crate::format::{month_short, weekday_name, weekday_short};
+
+// UTC fields of a parsed HTTP date: (year, month, day, hour, minute, second).
+pub(crate) type HttpFields = (i32, u32, u32, u32, u32, u32);
+
+// `Sun, 06 Nov 1994 08:49:37 GMT`
+pub(crate) fn write<W: core::fmt::Write>(
+    w: &mut W,
+    (year, month, day, hour, minute, second): HttpFields,
+    weekday: u32,
+) -> core::fmt::Result {
+    write!(
+        w,
+        "{}, {:02} {} {:04} {:02}:{:02}:{:02} GMT",
+        weekday_short(weekday),
+        day,
+        month_short(month),
+        year,
+        hour,
+        minute,
+        second
+    )
+}
+
+// Parses IMF-fixdate, RFC 850 (`Sunday, 06-Nov-94 08:49:37 GMT`) and asctime
+// (`Sun Nov  6 08:49:37 1994`). The weekday name must be well-formed but is not
+// checked against the date. Two-digit RFC 850 years resolve to the latest year
+// not more than 50 years after `current_year`, as RFC 9110 requires.
+//
+// Every part is a subslice of the trimmed input, so error positions are found
+// from the part's address relative to it.
+pub(crate) fn parse(s: &str, current_year: i32) -> Result<HttpFields, Error> {
+    let s = s.trim();
+    if let Some((name, rest)) = s.split_once(", ") {
+        if weekday_index(name, weekday_short).is_some() {
+            return parse_imf_fixdate(s, rest);
+        }
+        if weekday_index(name, weekday_name).is_some() {
+            return parse_rfc850(s, rest, current_year);
+        }
+        return Err(Error::Invalid {
+            what: "weekday",
+            position: 0,
+        });
+    }
+    parse_asctime(s)
+}
+
+// `06 Nov 1994 08:49:37 GMT`
+fn parse_imf_fixdate(base: &str, s: &str) -> Result<HttpFields, Error> {
+    let mut parts = s.split(' ');
+    let day = number(base, parts.next(), 2, "day")?;
+    let month = month_number(base, parts.next())?;
+    let year = number(base, parts.next(), 4, "year")? as i32;
+    let (hour, minute, second) = time_of_day(base, parts.next())?;
+    expect_gmt(base, parts.next())?;
+    expect_end(base, parts.next())?;
+    Ok((year, month, day, hour, minute, second))
+}
+
+// `06-Nov-94 08:49:37 GMT`
+fn parse_rfc850(base: &str, s: &str, current_year: i32) -> Result<HttpFields, Error> {
+    let mut parts = s.split(' ');
+    let mut date = parts.next().unwrap_or("").split('-');
+    let day = number(base, date.next(), 2, "day")?;
+    let month = month_number(base, date.next())?;
+    let yy = number(base, date.next(), 2, "year")? as i32;
+    expect_end(base, date.next())?;
+    let (hour, minute, second) = time_of_day(base, parts.next())?;
+    expect_gmt(base, parts.next())?;
+    expect_end(base, parts.next())?;
+
+    let mut year = current_year - current_year.rem_euclid(100) + yy;
+    if year > current_year + 50 {
+        year -= 100;
+    }
+    Ok((year, month, day, hour, minute, second))
+}
+
+// `Sun Nov  6 08:49:37 1994` (single-digit days are space padded)
+fn parse_asctime(s: &str) -> Result<HttpFields, Error> {
+    let mut parts = s.split(' ').filter(|p| !p.is_empty());
+    if weekday_index(parts.next().unwrap_or(""), weekday_short).is_none() {
+        return Err(Error::Invalid {
+            what: "HTTP date",
+            position: 0,
+        });
+    }
+    let month = month_number(s, parts.next())?;
+    let day = match parts.next() {
+        Some(d) if (1..=2).contains(&d.len()) && d.bytes().all(|b| b.is_ascii_digit()) => {
+            d.parse().unwrap_or(0)
+        }
+        d => return Err(invalid(s, d, "day")),
+    };
+    let (hour, minute, second) = time_of_day(s, parts.next())?;
+    let year = number(s, parts.next(), 4, "year")? as i32;
+    expect_end(s, parts.next())?;
+    Ok((year, month, day, hour, minute, second))
+}
+
+// RFC 2822 §3.3 date-time as used in email headers:
+// `[Thu, ]30 Oct 2025 14:30[:00] +0000`, with any whitespace between parts.
+// Also accepts the obsolete syntax: two-digit years (00–49 are 20xx, 50–99
+// 19xx), `UT`/`GMT` and the US zone names, and single-letter military zones,
+// which the RFC says to read as -0000. Returns the wall-clock fields and the
+// UTC offset in seconds.
+pub(crate) fn parse_rfc2822(s: &str) -> Result<(HttpFields, i32), Error> {
+    let base = s.trim();
+    let mut parts = base.split_whitespace().peekable();
+    if let Some(name) = parts.peek().and_then(|p| p.strip_suffix(',')) {
+        if weekday_index(name, weekday_short).is_none() {
+            return Err(invalid(base, parts.next(), "weekday"));
+        }
+        parts.next();
+    }
+
+    let day = match parts.next() {
+        Some(d) if (1..=2).contains(&d.len()) && d.bytes().all(|b| b.is_ascii_digit()) => {
+            d.parse().unwrap_or(0)
+        }
+        d => return Err(invalid(base, d, "day")),
+    };
+    let month = month_number(base, parts.next())?;
+    let year = match parts.next() {
+        Some(y) if y.len() == 4 && y.bytes().all(|b| b.is_ascii_digit()) => y.parse().unwrap_or(0),
+        Some(y) if y.len() == 2 && y.bytes().all(|b| b.is_ascii_digit()) => {
+            let yy: i32 = y.parse().unwrap_or(0);
+            if yy < 50 {
+                2000 + yy
+            } else {
+                1900 + yy
+            }
+        }
+        y => return Err(invalid(base, y, "year")),
+    };
+
+    let mut clock = parts.next().unwrap_or("").split(':');
+    let hour = number(base, clock.next(), 2, "hour")?;
+    let minute = number(base, clock.next(), 2, "minute")?;
+    let second = match clock.next() {
+        None => 0,
+        part => number(base, part, 2, "second")?,
+    };
+    expect_end(base, clock.next())?;
+
+    let zone = parts.next();
+    let offset = match zone {
+        Some("UT" | "GMT") => 0,
+        Some("EDT") => -4 * 3600,
+        Some("EST" | "CDT") => -5 * 3600,
+        Some("CST" | "MDT") => -6 * 3600,
+        Some("MST" | "PDT") => -7 * 3600,
+        Some("PST") => -8 * 3600,
+        Some(z) if z.len() == 1 && z.bytes().all(|b| b.is_ascii_alphabetic()) => 0,
+        // Checked as bytes, so a non-ASCII token is rejected rather than
+        // sliced mid-character.
+        Some(z)
+            if matches!(z.as_bytes(), [b'+' | b'-', digits @ ..]
+                if digits.len() == 4 && digits.iter().all(u8::is_ascii_digit)) =>
+        {
+            let (hh, mm): (i32, i32) = (z[1..3].parse().unwrap_or(0), z[3..].parse().unwrap_or(0));
+            if mm > 59 {
+                return Err(invalid(base, zone, "zone"));
+            }
+            let sign = if z.starts_with('-') { -1 } else { 1 };
+            sign * (hh * 3600 + mm * 60)
+        }
+        _ => return Err(invalid(base, zone, "zone")),
+    };
+    expect_end(base, parts.next())?;
+    Ok(((year, month, day, hour, minute, second), offset))
+}
+
+fn weekday_index(name: &str, names: fn(u32) -> &'static str) -> Option<u32> {
+    (0..7).find(|&i| names(i) == name)
+}
+
+// An `Invalid` error at `part`, or `UnexpectedEnd` if the input ran out.
+fn invalid(base: &str, part: Option<&str>, what: &'static str) -> Error {
+    match part.and_then(|p| position(base, p)) {
+        Some(position) if position < base.len() => Error::Invalid { what, position },
+        _ => Error::UnexpectedEnd {
+            position: base.len(),
+        },
+    }
+}
+
+// Byte offset of `part` in `base`, if it is a subslice of it. Missing parts
+// are replaced by a static `""`, which is not.
+fn position(base: &str, part: &str) -> Option<usize> {
+    let offset = (part.as_ptr() as usize).checked_sub(base.as_ptr() as usize)?;
+    (offset <= base.len()).then_some(offset)
+}
+
+fn month_number(base: &str, part: Option<&str>) -> Result<u32, Error> {
+    let name = part.unwrap_or("");
+    (1..=12)
+        .find(|&m| month_short(m) == name)
+        .ok_or_else(|| invalid(base, part, "month"))
+}
+
+fn number(base: &str, part: Option<&str>, digits: usize, what: &'static str) -> Result<u32, Error> {
+    match part {
+        Some(p) if p.len() == digits && p.bytes().all(|b| b.is_ascii_digit()) => {
+            Ok(p.parse().unwrap_or(0))
+        }
+        _ => Err(invalid(base, part, what)),
+    }
+}
+
+fn time_of_day(base: &str, part: Option<&str>) -> Result<(u32, u32, u32), Error> {
+    let mut fields = part.unwrap_or("").split(':');
+    let hour = number(base, fields.next(), 2, "hour")?;
+    let minute = number(base, fields.next(), 2, "minute")?;
+    let second = number(base, fields.next(), 2, "second")?;
+    expect_end(base, fields.next())?;
+    Ok((hour, minute, second))
+}
+
+fn expect_gmt(base: &str, part: Option<&str>) -> Result<(), Error> {
+    match part {
+        Some("GMT") => Ok(()),
+        _ => Err(invalid(base, part, "zone")),
+    }
+}
+
+fn expect_end(base: &str, part: Option<&str>) -> Result<(), Error> {
+    match part {
+        None => Ok(()),
+        Some(extra) => Err(Error::TrailingInput {
+            position: position(base, extra).unwrap_or(base.len()),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXPECTED: HttpFields = (1994, 11, 6, 8, 49, 37);
+
+    #[test]
+    fn test_parse_all_three_forms() {
+        assert_eq!(parse("Sun, 06 Nov 1994 08:49:37 GMT", 2025), Ok(EXPECTED));
+        assert_eq!(parse("Sunday, 06-Nov-94 08:49:37 GMT", 2025), Ok(EXPECTED));
+        assert_eq!(parse("Sun Nov  6 08:49:37 1994", 2025), Ok(EXPECTED));
+    }
+
+    #[test]
+    fn test_rfc850_two_digit_years() {
+        assert_eq!(
+            parse("Sunday, 06-Nov-94 08:49:37 GMT", 2045).unwrap().0,
+            2094
+        );
+        assert_eq!(
+            parse("Sunday, 06-Nov-94 08:49:37 GMT", 2043).unwrap().0,
+            1994
+        );
+        assert_eq!(
+            parse("Friday, 01-Jan-27 00:00:00 GMT", 2025).unwrap().0,
+            2027
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_malformed() {
+        assert!(parse("Sun, 06 Nov 1994 08:49:37 UTC", 2025).is_err());
+        assert!(parse("Sun, 6 Nov 1994 08:49:37 GMT", 2025).is_err());
+        assert!(parse("Sun, 06 November 1994 08:49:37 GMT", 2025).is_err());
+        assert!(parse("Sonntag, 06-Nov-94 08:49:37 GMT", 2025).is_err());
+        assert!(parse("2025-10-30T14:30:00Z", 2025).is_err());
+    }
+
+    #[test]
+    fn test_parse_rfc2822() {
+        assert_eq!(
+            parse_rfc2822("Sun, 06 Nov 1994 08:49:37 GMT"),
+            Ok((EXPECTED, 0))
+        );
+        assert_eq!(
+            parse_rfc2822("6 Nov 1994 03:49:37 -0500"),
+            Ok(((1994, 11, 6, 3, 49, 37), -5 * 3600))
+        );
+        assert_eq!(
+            parse_rfc2822("Sun,  6 Nov 94 00:49 PST").map(|(f, o)| (f.0, f.3, f.5, o)),
+            Ok((1994, 0, 0, -8 * 3600))
+        );
+        assert_eq!(
+            parse_rfc2822("06 Nov 1994 08:49:37 +0530").unwrap().1,
+            19800
+        );
+        assert_eq!(parse_rfc2822("06 Nov 1994 08:49:37 Z").unwrap().1, 0);
+
+        assert!(parse_rfc2822("Sunday, 06 Nov 1994 08:49:37 GMT").is_err());
+        assert!(parse_rfc2822("06 Nov 1994 08:49:37").is_err());
+        assert!(parse_rfc2822("06 Nov 1994 08:49:37 +05:30").is_err());
+        assert!(parse_rfc2822("06 Nov 1994 08:49:37 +0560").is_err());
+    }
+
+    #[test]
+    fn test_error_positions() {
+        assert_eq!(
+            parse("Sun, 06 Nov 1994 08:49:37 UTC", 2025),
+            Err(Error::Invalid {
+                what: "zone",
+                position: 26
+            })
+        );
+        assert_eq!(
+            parse("Sun, 06 Nov 1994 08:49:37 GMT x", 2025),
+            Err(Error::TrailingInput { position: 30 })
+        );
+        assert_eq!(
+            parse("Sunday, 06-Nov-94", 2025),
+            Err(Error::UnexpectedEnd { position: 17 })
+        );
+    }
+}
@@ -0,0 +1,201 @@
+// A small grammar of relative date expressions for `DateTime::from_human`:
+//
+//   now
+//   (today | tomorrow | yesterday | [next | last] <weekday>) [at <time>]
+//   at <time>
+//   in <n> <unit>        <n> <unit> ago
+//
+// where <time> is `3pm`, `3:30 pm`, `15:00`, `noon` or `midnight`, <n> is a
+// number or `a`/`an`, and <unit> is any Duration key (`hour`, `days`, ...).
+// Words are case-insensitive and separated by whitespace. Dates are calendar
+// dates in the zone of the reference DateTime, which the result keeps.
+
+use crate::calendar::{civil_from_days, days_from_civil};
+use crate::error::Error;
+use crate::format::{weekday_name, weekday_short};
+use crate::{DateTime, Duration};
+
+pub(crate) fn parse(s: &str, now: &DateTime) -> Result<DateTime, Error> {
+    let words = words(s);
+    let mut rest = words.as_slice();
+    let end = s.trim_end().len();
+
+    let day = match word(rest, 0) {
+        None => return Err(Error::UnexpectedEnd { position: end }),
+        Some("now") => return expect_end(&rest[1..], now),
+        Some("in") => {
+            let (n, unit) = amount(&rest[1..], end)?;
+            let dt = now.checked_plus(&Duration::from_object(&[(unit, n)]));
+            return expect_end(&rest[3..], &dt.ok_or(Error::OutOfRange)?);
+        }
+        Some(_) if word(rest, 2) == Some("ago") => {
+            let (n, unit) = amount(rest, end)?;
+            let dt = now.checked_minus(&Duration::from_object(&[(unit, n)]));
+            return expect_end(&rest[3..], &dt.ok_or(Error::OutOfRange)?);
+        }
+        Some("at") => *now,
+        Some("today") => {
+            rest = &rest[1..];
+            *now
+        }
+        Some("tomorrow") => {
+            rest = &rest[1..];
+            shift_days(now, 1)?
+        }
+        Some("yesterday") => {
+            rest = &rest[1..];
+            shift_days(now, -1)?
+        }
+        Some(w) => {
+            let last = w == "last";
+            if w == "next" || last {
+                rest = &rest[1..];
+            }
+            let (position, name) = rest.first().ok_or(Error::UnexpectedEnd { position: end })?;
+            let target = weekday(name).ok_or(Error::Invalid {
+                what: "expression",
+                position: *position,
+            })?;
+            rest = &rest[1..];
+            let today = now.weekday().number() as i64;
+            let target = target as i64 + 1;
+            let delta = if last {
+                -((today - target + 6).rem_euclid(7) + 1)
+            } else {
+                (target - today + 6).rem_euclid(7) + 1
+            };
+            shift_days(now, delta)?
+        }
+    };
+
+    let (hour, minute) = match rest.first() {
+        None => (0, 0),
+        Some((_, w)) if w == "at" => {
+            let (time, used) = time_of_day(&rest[1..], end)?;
+            rest = &rest[1 + used..];
+            time
+        }
+        Some((position, _)) => {
+            return Err(Error::Invalid {
+                what: "expression",
+                position: *position,
+            })
+        }
+    };
+    let result = day.set(&[
+        ("hour", hour),
+        ("minute", minute),
+        ("second", 0),
+        ("millisecond", 0),
+    ])?;
+    expect_end(rest, &result)
+}
+
+// Lowercased words with their byte offsets in `s`.
+fn words(s: &str) -> Vec<(usize, String)> {
+    s.split_whitespace()
+        .map(|w| {
+            (
+                w.as_ptr() as usize - s.as_ptr() as usize,
+                w.to_ascii_lowercase(),
+            )
+        })
+        .collect()
+}
+
+fn word(words: &[(usize, String)], i: usize) -> Option<&str> {
+    words.get(i).map(|(_, w)| w.as_str())
+}
+
+fn expect_end(rest: &[(usize, String)], result: &DateTime) -> Result<DateTime, Error> {
+    match rest.first() {
+        None => Ok(*result),
+        Some((position, _)) => Err(Error::TrailingInput {
+            position: *position,
+        }),
+    }
+}
+
+// `<n> <unit>`, e.g. `2 hours` or `a day`.
+fn amount(words: &[(usize, String)], end: usize) -> Result<(i64, &'static str), Error> {
+    let [(n_pos, n), (_, unit), ..] = words else {
+        return Err(Error::UnexpectedEnd { position: end });
+    };
+    let n = match n.as_str() {
+        "a" | "an" => 1,
+        n => n.parse().map_err(|_| Error::Invalid {
+            what: "number",
+            position: *n_pos,
+        })?,
+    };
+    const UNITS: [&str; 8] = [
+        "year",
+        "month",
+        "week",
+        "day",
+        "hour",
+        "minute",
+        "second",
+        "millisecond",
+    ];
+    let singular = unit.strip_suffix('s').unwrap_or(unit);
+    let unit = UNITS
+        .into_iter()
+        .find(|u| *u == singular)
+        .ok_or_else(|| Error::UnknownUnit(unit.clone()))?;
+    Ok((n, unit))
+}
+
+// `noon`, `midnight`, `15:00`, `3pm`, `3:30pm` or `3:30 pm`. Returns the hour
+// and minute and how many words were used.
+fn time_of_day(words: &[(usize, String)], end: usize) -> Result<((i64, i64), usize), Error> {
+    let (position, word) = words
+        .first()
+        .ok_or(Error::UnexpectedEnd { position: end })?;
+    let invalid = Error::Invalid {
+        what: "time",
+        position: *position,
+    };
+    match word.as_str() {
+        "noon" => return Ok(((12, 0), 1)),
+        "midnight" => return Ok(((0, 0), 1)),
+        _ => {}
+    }
+
+    let (clock, meridiem, used) = if let Some(clock) = word.strip_suffix("am") {
+        (clock, Some(false), 1)
+    } else if let Some(clock) = word.strip_suffix("pm") {
+        (clock, Some(true), 1)
+    } else {
+        match words.get(1).map(|(_, w)| w.as_str()) {
+            Some("am") => (word.as_str(), Some(false), 2),
+            Some("pm") => (word.as_str(), Some(true), 2),
+            _ => (word.as_str(), None, 1),
+        }
+    };
+    let (hour, minute) = clock.split_once(':').unwrap_or((clock, "00"));
+    let short_number =
+        |s: &str| (1..=2).contains(&s.len()) && s.bytes().all(|b| b.is_ascii_digit());
+    if !short_number(hour) || minute.len() != 2 || !short_number(minute) {
+        return Err(invalid);
+    }
+    let (hour, minute): (i64, i64) = (hour.parse().unwrap_or(0), minute.parse().unwrap_or(0));
+    let hour = match meridiem {
+        Some(_) if !(1..=12).contains(&hour) => return Err(invalid),
+        Some(pm) => hour % 12 + if pm { 12 } else { 0 },
+        None => hour,
+    };
+    Ok(((hour, minute), used))
+}
+
+fn weekday(name: &str) -> Option<u32> {
+    (0..7).find(|&i| {
+        name.eq_ignore_ascii_case(weekday_name(i)) || name.eq_ignore_ascii_case(weekday_short(i))
+    })
+}
+
+// The same wall-clock time `days` calendar days away, in `dt`'s zone.
+fn shift_days(dt: &DateTime, days: i64) -> Result<DateTime, Error> {
+    let (y, m, d) = civil_from_days(days_from_civil(dt.year(), dt.month(), dt.day()) + days);
+    dt.set(&[("year", y as i64), ("month", m as i64), ("day", d as i64)])
+}
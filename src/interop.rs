@@ -0,0 +1,86 @@
+// Conversions to and from types of other date/time crates, each behind its own
+// feature so none of them is pulled into the default zero-deps build.
+
+#[cfg(feature = "time")]
+mod time_crate {
+    use crate::{DateTime, Duration};
+
+    /// Keeps the instant, truncated to whole milliseconds. The source offset is
+    /// not carried over; the result has no zone, as with [`DateTime::from_millis`].
+    impl From<time::OffsetDateTime> for DateTime {
+        fn from(dt: time::OffsetDateTime) -> Self {
+            let ms = dt.unix_timestamp_nanos().div_euclid(1_000_000);
+            DateTime::from_epoch_ms(ms as i64)
+        }
+    }
+
+    /// Keeps the instant and uses the zone's UTC offset at that instant, so the
+    /// wall-clock fields match. Instants outside years -9999 to 9999 are
+    /// saturated to that range, which is all `time` can represent by default.
+    impl From<DateTime> for time::OffsetDateTime {
+        fn from(dt: DateTime) -> Self {
+            let ms = dt
+                .to_millis()
+                .clamp(crate::datetime::MIN_MS, crate::datetime::MAX_MS);
+            let utc = time::OffsetDateTime::from_unix_timestamp_nanos(ms as i128 * 1_000_000)
+                .unwrap_or(time::OffsetDateTime::UNIX_EPOCH);
+            let offset = dt.offset_secs();
+            match time::UtcOffset::from_whole_seconds(offset) {
+                Ok(offset) => utc.to_offset(offset),
+                Err(_) => utc,
+            }
+        }
+    }
+
+    /// Stored as milliseconds; sub-millisecond precision is truncated.
+    impl From<time::Duration> for Duration {
+        fn from(dur: time::Duration) -> Self {
+            let ms = dur
+                .whole_milliseconds()
+                .clamp(i64::MIN as i128, i64::MAX as i128);
+            Duration::from_object(&[("milliseconds", ms as i64)])
+        }
+    }
+
+    /// Uses the same fixed lengths as [`Duration::as_unit`]: 30-day months and
+    /// 365-day years. Lengths beyond `i64::MAX` milliseconds saturate.
+    impl From<Duration> for time::Duration {
+        fn from(dur: Duration) -> Self {
+            time::Duration::milliseconds(dur.as_unit("milliseconds"))
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+mod prost_types_crate {
+    use crate::DateTime;
+    use prost_types::Timestamp;
+
+    /// Splits the instant into whole seconds and a non-negative nanosecond
+    /// remainder, as the protobuf `Timestamp` contract requires.
+    impl From<DateTime> for Timestamp {
+        fn from(dt: DateTime) -> Self {
+            let ms = dt.to_millis();
+            Timestamp {
+                seconds: ms.div_euclid(1000),
+                nanos: (ms.rem_euclid(1000) * 1_000_000) as i32,
+            }
+        }
+    }
+
+    /// Truncates to whole milliseconds. Fails for instants outside years -9999
+    /// to 9999, which also covers the protobuf range of years 1 to 9999.
+    impl TryFrom<Timestamp> for DateTime {
+        type Error = crate::Error;
+
+        fn try_from(mut ts: Timestamp) -> Result<Self, Self::Error> {
+            ts.normalize();
+            let ms = ts
+                .seconds
+                .checked_mul(1000)
+                .and_then(|ms| ms.checked_add((ts.nanos / 1_000_000) as i64))
+                .ok_or(crate::Error::OutOfRange)?;
+            DateTime::from_millis(ms)
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{DateTime, Duration};
+use crate::{Bounds, DateTime, Duration, Error, Unit};
 
 /// A range of time between two DateTimes.
 ///
@@ -8,7 +8,7 @@ use crate::{DateTime, Duration};
 /// use tempotime::{dt, Duration, Interval};
 ///
 /// let start = dt();
-/// let end = start.clone().plus(&Duration::from_object(&[("days", 30)]));
+/// let end = start.plus(&Duration::from_object(&[("days", 30)]));
 /// let interval = Interval::from_date_times(start, end);
 ///
 /// let check = dt().plus(&Duration::from_object(&[("days", 15)]));
@@ -21,17 +21,567 @@ pub struct Interval {
 }
 
 impl Interval {
+    /// An interval from `start` to `end`, unchecked: an end before the start
+    /// gives an interval that [`is_valid`](Self::is_valid) rejects and that
+    /// contains nothing. Prefer [`try_new`](Self::try_new) for input that may
+    /// be out of order.
     pub fn from_date_times(start: DateTime, end: DateTime) -> Self {
         Interval { start, end }
     }
 
+    /// An interval from `start` to `end`, checking that they are in order.
+    /// Equal endpoints give an empty interval.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::EndBeforeStart`] if `end` is before `start`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::{DateTime, Error, Interval};
+    ///
+    /// let monday = DateTime::from_iso("2025-10-27T00:00:00Z").unwrap();
+    /// let friday = DateTime::from_iso("2025-10-31T00:00:00Z").unwrap();
+    /// assert!(Interval::try_new(monday, friday).is_ok());
+    /// assert_eq!(Interval::try_new(friday, monday).unwrap_err(), Error::EndBeforeStart);
+    /// assert_eq!(Interval::from_unordered(friday, monday).start(), &monday);
+    /// ```
+    pub fn try_new(start: DateTime, end: DateTime) -> Result<Self, Error> {
+        if end < start {
+            return Err(Error::EndBeforeStart);
+        }
+        Ok(Interval { start, end })
+    }
+
+    /// The interval of length `dur` starting at `start`, as Luxon's `after`.
+    /// A negative duration gives an interval that is not
+    /// [`valid`](Self::is_valid).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::{DateTime, Duration, Interval};
+    ///
+    /// let launch = DateTime::from_iso("2025-10-01T00:00:00Z").unwrap();
+    /// let trial = Interval::after(launch, &Duration::days(30));
+    /// assert_eq!(trial.end().to_format("yyyy-MM-dd"), "2025-10-31");
+    ///
+    /// let lead_up = Interval::before(launch, &Duration::weeks(1));
+    /// assert_eq!(lead_up.start().to_format("yyyy-MM-dd"), "2025-09-24");
+    /// ```
+    pub fn after(start: DateTime, dur: &Duration) -> Self {
+        Interval {
+            start,
+            end: start.plus(dur),
+        }
+    }
+
+    /// The interval of length `dur` ending at `end`, as Luxon's `before`.
+    pub fn before(end: DateTime, dur: &Duration) -> Self {
+        Interval {
+            start: end.minus(dur),
+            end,
+        }
+    }
+
+    /// An interval between `a` and `b` in whichever order they come.
+    pub fn from_unordered(a: DateTime, b: DateTime) -> Self {
+        Interval {
+            start: a.min(b),
+            end: a.max(b),
+        }
+    }
+
+    /// Parses an ISO-8601 interval: two instants (`start/end`), or an
+    /// instant and a duration (`start/P30D` or `P30D/end`).
+    ///
+    /// # Errors
+    ///
+    /// A missing `/`, an unparsable side (with its position within the whole
+    /// string), two durations, or an end before the start.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::{Interval, Unit};
+    ///
+    /// let october = Interval::from_iso("2025-10-01T00:00:00Z/2025-11-01T00:00:00Z").unwrap();
+    /// assert_eq!(october.count(Unit::Days), 31);
+    ///
+    /// let sprint = Interval::from_iso("2025-10-01T00:00:00Z/P2W").unwrap();
+    /// assert_eq!(sprint.end().to_format("yyyy-MM-dd"), "2025-10-15");
+    /// ```
+    pub fn from_iso(s: &str) -> Result<Self, Error> {
+        let slash = s.find('/').ok_or(Error::Expected {
+            expected: '/',
+            position: s.len(),
+        })?;
+        let (first, second) = (&s[..slash], &s[slash + 1..]);
+        let is_duration = |part: &str| part.starts_with(['P', 'p']);
+        let instant =
+            |part: &str, offset: usize| DateTime::from_iso(part).map_err(|e| e.offset_by(offset));
+        let duration =
+            |part: &str, offset: usize| Duration::from_iso(part).map_err(|e| e.offset_by(offset));
+
+        let (start, end) = match (is_duration(first), is_duration(second)) {
+            (false, false) => (instant(first, 0)?, instant(second, slash + 1)?),
+            (false, true) => {
+                let start = instant(first, 0)?;
+                (start, start.plus(&duration(second, slash + 1)?))
+            }
+            (true, false) => {
+                let end = instant(second, slash + 1)?;
+                (end.minus(&duration(first, 0)?), end)
+            }
+            (true, true) => {
+                return Err(Error::Invalid {
+                    what: "interval",
+                    position: 0,
+                })
+            }
+        };
+        Interval::try_new(start, end)
+    }
+
+    /// Formats as an ISO-8601 interval, `start/end`, each end as
+    /// [`DateTime::to_iso`] writes it; [`from_iso`](Self::from_iso) reads it
+    /// back.
+    pub fn to_iso(&self) -> String {
+        format!("{}/{}", self.start.to_iso(), self.end.to_iso())
+    }
+
+    /// Formats both ends with `fmt`, as [`DateTime::to_format`] does, joined
+    /// by an en dash: `"Oct 1 – Oct 31"` for `"MMM d"`. See
+    /// [`to_locale_string`](Self::to_locale_string) for a range that leaves
+    /// out what the ends share.
+    pub fn to_format(&self, fmt: &str) -> String {
+        format!(
+            "{} – {}",
+            self.start.to_format(fmt),
+            self.end.to_format(fmt)
+        )
+    }
+
+    /// A readable English date range that states shared parts once, like
+    /// `Intl.DateTimeFormat.formatRange`: `"Oct 1 – 31, 2025"` within a
+    /// month, `"Oct 30 – Nov 2, 2025"` within a year, and both dates in full
+    /// otherwise. Both ends are read in the start's zone.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::{DateTime, Interval};
+    ///
+    /// let start = DateTime::from_iso("2025-10-30T00:00:00Z").unwrap();
+    /// let month_end = DateTime::from_iso("2025-10-31T00:00:00Z").unwrap();
+    /// let next_month = DateTime::from_iso("2025-11-02T00:00:00Z").unwrap();
+    /// let next_year = DateTime::from_iso("2026-01-02T00:00:00Z").unwrap();
+    ///
+    /// let range = Interval::from_date_times(start, month_end);
+    /// assert_eq!(range.to_locale_string(), "Oct 30 – 31, 2025");
+    /// let range = Interval::from_date_times(start, next_month);
+    /// assert_eq!(range.to_locale_string(), "Oct 30 – Nov 2, 2025");
+    /// let range = Interval::from_date_times(start, next_year);
+    /// assert_eq!(range.to_locale_string(), "Oct 30, 2025 – Jan 2, 2026");
+    /// let range = Interval::from_date_times(start, start);
+    /// assert_eq!(range.to_locale_string(), "Oct 30, 2025");
+    /// ```
+    pub fn to_locale_string(&self) -> String {
+        let start = self.start;
+        let end = self.end.with_zone_of(&start);
+        let (same_year, same_month, same_day) = (
+            start.year() == end.year(),
+            start.year() == end.year() && start.month() == end.month(),
+            start.year() == end.year() && start.month() == end.month() && start.day() == end.day(),
+        );
+        if same_day {
+            start.to_format("MMM d, yyyy")
+        } else if same_month {
+            format!(
+                "{} – {}",
+                start.to_format("MMM d"),
+                end.to_format("d, yyyy")
+            )
+        } else if same_year {
+            format!(
+                "{} – {}",
+                start.to_format("MMM d"),
+                end.to_format("MMM d, yyyy")
+            )
+        } else {
+            format!(
+                "{} – {}",
+                start.to_format("MMM d, yyyy"),
+                end.to_format("MMM d, yyyy")
+            )
+        }
+    }
+
+    /// Whether the end is not before the start. Only intervals built with
+    /// [`from_date_times`](Self::from_date_times) can fail this.
+    pub fn is_valid(&self) -> bool {
+        self.start <= self.end
+    }
+
+    /// Whether `dt` lies in the interval, both ends included. Use
+    /// [`contains_with`](Self::contains_with) and [`Bounds::HalfOpen`] when
+    /// chaining back-to-back intervals, so the instant where one ends and the
+    /// next starts belongs to only one of them.
     pub fn contains(&self, dt: &DateTime) -> bool {
-        dt >= &self.start && dt <= &self.end
+        self.contains_with(dt, Bounds::Closed)
     }
 
+    /// Whether `dt` lies in the interval, with `bounds` choosing whether the
+    /// end is included.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::{Bounds, DateTime, Interval};
+    ///
+    /// let eight = DateTime::from_iso("2025-10-01T08:00:00Z").unwrap();
+    /// let noon = DateTime::from_iso("2025-10-01T12:00:00Z").unwrap();
+    /// let morning = Interval::from_date_times(eight, noon);
+    /// assert!(morning.contains_with(&noon, Bounds::Closed));
+    /// assert!(!morning.contains_with(&noon, Bounds::HalfOpen));
+    /// ```
+    pub fn contains_with(&self, dt: &DateTime, bounds: Bounds) -> bool {
+        match bounds {
+            Bounds::Closed => dt >= &self.start && dt <= &self.end,
+            Bounds::HalfOpen => dt >= &self.start && dt < &self.end,
+        }
+    }
+
+    /// The length in whole `unit`s, as a Duration in that unit; empty for an
+    /// unknown unit. See [`length_in`](Self::length_in) for the typed form.
     pub fn length(&self, unit: &str) -> Duration {
-        let diff = self.start.diff(&self.end, unit).abs() as i64;
-        Duration::from_object(&[(unit, diff)])
+        unit.parse::<Unit>()
+            .map_or_else(|_| Duration::default(), |unit| self.length_in(unit))
+    }
+
+    /// The length in whole `unit`s, truncated, as a Duration in that unit.
+    /// Months count as 30 days and years as 365.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::{DateTime, Interval, Unit};
+    ///
+    /// let interval = Interval::from_date_times(
+    ///     DateTime::from_iso("2025-10-01T00:00:00Z").unwrap(),
+    ///     DateTime::from_iso("2025-10-11T12:00:00Z").unwrap(),
+    /// );
+    /// assert_eq!(interval.length_in(Unit::Days).total(Unit::Days), 10);
+    /// ```
+    pub fn length_in(&self, unit: Unit) -> Duration {
+        let diff = self.start.diff_in(&self.end, unit).abs() as i64;
+        Duration::from_object(&[(unit.name(), diff)])
+    }
+
+    /// The number of calendar `unit`s the interval touches, as Luxon's
+    /// `count`: a range from 23:00 to 01:00 the next day counts two days,
+    /// though it lasts two hours. The end is exclusive, so a range ending at
+    /// midnight does not count the day that starts there.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::{DateTime, Interval, Unit};
+    ///
+    /// let night = Interval::from_date_times(
+    ///     DateTime::from_iso("2025-10-01T23:00:00Z").unwrap(),
+    ///     DateTime::from_iso("2025-10-02T01:00:00Z").unwrap(),
+    /// );
+    /// assert_eq!(night.count(Unit::Days), 2);
+    /// assert_eq!(night.count(Unit::Hours), 2);
+    ///
+    /// let week = Interval::from_date_times(
+    ///     DateTime::from_iso("2025-10-01T00:00:00Z").unwrap(),
+    ///     DateTime::from_iso("2025-10-08T00:00:00Z").unwrap(),
+    /// );
+    /// assert_eq!(week.count(Unit::Days), 7);
+    /// ```
+    pub fn count(&self, unit: Unit) -> i64 {
+        if self.end < self.start {
+            return 0;
+        }
+        let start = self.start.start_of_unit(unit);
+        let end = self.end.start_of_unit(unit);
+        let whole = Duration::between(&start, &end, &[unit]).get(unit);
+        whole + i64::from(end != self.end)
+    }
+
+    /// Whether the interval has no length: its end is not after its start.
+    pub fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    /// Fraction of the interval elapsed at `dt`, clamped to `0.0..=1.0`.
+    ///
+    /// Returns `0.0` before the start and `1.0` from the end onwards, which makes
+    /// it directly usable for progress bars.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::{DateTime, Interval};
+    ///
+    /// let sprint = Interval::from_date_times(
+    ///     DateTime::from_iso("2025-10-01T00:00:00Z").unwrap(),
+    ///     DateTime::from_iso("2025-10-11T00:00:00Z").unwrap(),
+    /// );
+    /// let day4 = DateTime::from_iso("2025-10-04T00:00:00Z").unwrap();
+    /// assert!((sprint.progress(&day4) - 0.3).abs() < 1e-9);
+    /// ```
+    pub fn progress(&self, dt: &DateTime) -> f64 {
+        let total = self.end.diff(&self.start, "milliseconds");
+        if total <= 0.0 {
+            return if dt >= &self.end { 1.0 } else { 0.0 };
+        }
+        (dt.diff(&self.start, "milliseconds") / total).clamp(0.0, 1.0)
+    }
+
+    /// Time left in the interval as seen from `dt`.
+    ///
+    /// Before the start this is the whole length of the interval; from the end
+    /// onwards it is zero. The result is expressed in milliseconds.
+    pub fn remaining(&self, dt: &DateTime) -> Duration {
+        let from = if dt > &self.start { dt } else { &self.start };
+        let ms = self.end.diff(from, "milliseconds").max(0.0) as i64;
+        Duration::from_object(&[("milliseconds", ms)])
+    }
+
+    /// Splits the interval at each of `points` that falls strictly inside
+    /// it, giving consecutive sub-intervals that cover it exactly. Points
+    /// outside the interval or repeated are ignored, and their order does not
+    /// matter.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::{DateTime, Interval};
+    ///
+    /// let day = Interval::from_date_times(
+    ///     DateTime::from_iso("2025-10-01T00:00:00Z").unwrap(),
+    ///     DateTime::from_iso("2025-10-02T00:00:00Z").unwrap(),
+    /// );
+    /// let nine = DateTime::from_iso("2025-10-01T09:00:00Z").unwrap();
+    /// let six = DateTime::from_iso("2025-10-01T18:00:00Z").unwrap();
+    /// let parts = day.split_at(&[six, nine]);
+    /// assert_eq!(parts.len(), 3);
+    /// assert_eq!(parts[1].start(), &nine);
+    /// ```
+    pub fn split_at(&self, points: &[DateTime]) -> Vec<Interval> {
+        let mut cuts: Vec<DateTime> = points
+            .iter()
+            .copied()
+            .filter(|dt| dt > &self.start && dt < &self.end)
+            .collect();
+        cuts.sort();
+        cuts.dedup();
+
+        let mut parts = Vec::with_capacity(cuts.len() + 1);
+        let mut from = self.start;
+        for cut in cuts.into_iter().chain(std::iter::once(self.end)) {
+            if cut > from {
+                parts.push(Interval::from_date_times(from, cut));
+                from = cut;
+            }
+        }
+        parts
+    }
+
+    /// Splits the interval into consecutive pieces of `dur`, the last one cut
+    /// short at the end, as Luxon's `splitBy`. Each boundary is `dur` times
+    /// its index added to the start, so month steps do not drift at month
+    /// ends. A duration that is not positive gives no pieces.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::{DateTime, Duration, Interval};
+    ///
+    /// let shift = Interval::from_date_times(
+    ///     DateTime::from_iso("2025-10-01T08:00:00Z").unwrap(),
+    ///     DateTime::from_iso("2025-10-01T18:00:00Z").unwrap(),
+    /// );
+    /// let blocks = shift.split_by(&Duration::hours(4));
+    /// assert_eq!(blocks.len(), 3);
+    /// assert_eq!(blocks[2].length_in(tempotime::Unit::Hours).get_hours(), 2);
+    /// ```
+    pub fn split_by(&self, dur: &Duration) -> Vec<Interval> {
+        if dur.is_zero() || dur.is_negative() {
+            return Vec::new();
+        }
+        let mut parts = Vec::new();
+        let mut from = self.start;
+        let mut index = 1;
+        while from < self.end {
+            let next = self.start.plus(&dur.times(index)).min(self.end);
+            if next <= from {
+                break;
+            }
+            parts.push(Interval::from_date_times(from, next));
+            from = next;
+            index += 1;
+        }
+        parts
+    }
+
+    /// Whether the two intervals share some time. Intervals are half-open
+    /// here, as in Luxon: one ending exactly where the other starts does not
+    /// overlap it, it [`abuts`](Self::abuts) it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::{DateTime, Interval};
+    ///
+    /// let nine = DateTime::from_iso("2025-10-01T09:00:00Z").unwrap();
+    /// let half_past = DateTime::from_iso("2025-10-01T09:30:00Z").unwrap();
+    /// let ten = DateTime::from_iso("2025-10-01T10:00:00Z").unwrap();
+    /// let eleven = DateTime::from_iso("2025-10-01T11:00:00Z").unwrap();
+    ///
+    /// let booked = Interval::from_date_times(nine, ten);
+    /// let request = Interval::from_date_times(half_past, eleven);
+    /// let next = Interval::from_date_times(ten, eleven);
+    /// assert!(booked.overlaps(&request));
+    /// assert!(!booked.overlaps(&next));
+    /// assert!(booked.abuts(&next));
+    /// ```
+    pub fn overlaps(&self, other: &Interval) -> bool {
+        self.start < other.end && other.start < self.end
+    }
+
+    /// Whether this interval covers all of `other`, endpoints included.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::{DateTime, Interval};
+    ///
+    /// let one = DateTime::from_iso("2025-10-01T01:00:00Z").unwrap();
+    /// let two = DateTime::from_iso("2025-10-01T02:00:00Z").unwrap();
+    /// let five = DateTime::from_iso("2025-10-01T05:00:00Z").unwrap();
+    /// let maintenance = Interval::from_date_times(one, five);
+    /// let deploy = Interval::from_date_times(two, five);
+    /// assert!(maintenance.engulfs(&deploy));
+    /// assert!(deploy.is_subset_of(&maintenance));
+    /// assert!(!deploy.engulfs(&maintenance));
+    /// ```
+    pub fn engulfs(&self, other: &Interval) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    /// Whether `other` covers all of this interval; the reverse of
+    /// [`engulfs`](Self::engulfs).
+    pub fn is_subset_of(&self, other: &Interval) -> bool {
+        other.engulfs(self)
+    }
+
+    /// Whether one interval ends exactly where the other starts.
+    pub fn abuts(&self, other: &Interval) -> bool {
+        self.end == other.start || other.end == self.start
+    }
+
+    /// The time both intervals share, or `None` if they do not
+    /// [`overlap`](Self::overlaps).
+    pub fn intersection(&self, other: &Interval) -> Option<Interval> {
+        let start = self.start.max(other.start);
+        let end = self.end.min(other.end);
+        (start < end).then(|| Interval::from_date_times(start, end))
+    }
+
+    /// The interval from the earlier start to the later end, as Luxon's
+    /// `union`. Any gap between two disjoint intervals is included.
+    pub fn union(&self, other: &Interval) -> Interval {
+        Interval::from_date_times(self.start.min(other.start), self.end.max(other.end))
+    }
+
+    /// The parts of this interval not covered by `other`: none, one or two
+    /// pieces in order. An empty `other` covers nothing, so this interval
+    /// comes back whole.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::{DateTime, Interval};
+    ///
+    /// let day = Interval::from_date_times(
+    ///     DateTime::from_iso("2025-10-01T09:00:00Z").unwrap(),
+    ///     DateTime::from_iso("2025-10-01T17:00:00Z").unwrap(),
+    /// );
+    /// let lunch = Interval::from_date_times(
+    ///     DateTime::from_iso("2025-10-01T12:00:00Z").unwrap(),
+    ///     DateTime::from_iso("2025-10-01T13:00:00Z").unwrap(),
+    /// );
+    /// let free = day.difference(&lunch);
+    /// assert_eq!(free.len(), 2);
+    /// assert_eq!(free[0].end(), lunch.start());
+    /// assert_eq!(free[1].start(), lunch.end());
+    /// ```
+    pub fn difference(&self, other: &Interval) -> Vec<Interval> {
+        if other.is_empty() || !self.overlaps(other) {
+            return if self.start < self.end {
+                vec![self.clone()]
+            } else {
+                Vec::new()
+            };
+        }
+        let mut parts = Vec::with_capacity(2);
+        if self.start < other.start {
+            parts.push(Interval::from_date_times(self.start, other.start));
+        }
+        if other.end < self.end {
+            parts.push(Interval::from_date_times(other.end, self.end));
+        }
+        parts
+    }
+
+    /// This interval with its start replaced. Like
+    /// [`from_date_times`](Self::from_date_times), it does not check that
+    /// the ends stay in order.
+    pub fn with_start(&self, start: DateTime) -> Interval {
+        Interval {
+            start,
+            end: self.end,
+        }
+    }
+
+    /// This interval with its end replaced, unchecked like
+    /// [`with_start`](Self::with_start).
+    pub fn with_end(&self, end: DateTime) -> Interval {
+        Interval {
+            start: self.start,
+            end,
+        }
+    }
+
+    /// Applies `f` to both ends, as Luxon's `mapEndpoints`: shift a window
+    /// by a duration or snap it to day boundaries in one call.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::{DateTime, Duration, Interval};
+    ///
+    /// let window = Interval::from_date_times(
+    ///     DateTime::from_iso("2025-10-01T09:30:00Z").unwrap(),
+    ///     DateTime::from_iso("2025-10-03T17:45:00Z").unwrap(),
+    /// );
+    ///
+    /// let next_week = window.map_endpoints(|dt| dt + Duration::weeks(1));
+    /// assert_eq!(next_week.start().to_format("yyyy-MM-dd HH:mm"), "2025-10-08 09:30");
+    ///
+    /// let days = window.map_endpoints(|dt| dt.start_of("day"));
+    /// assert_eq!(days.end().to_format("yyyy-MM-dd HH:mm"), "2025-10-03 00:00");
+    /// ```
+    pub fn map_endpoints(&self, f: impl Fn(DateTime) -> DateTime) -> Interval {
+        Interval {
+            start: f(self.start),
+            end: f(self.end),
+        }
     }
 
     pub fn start(&self) -> &DateTime {
@@ -47,6 +597,16 @@ impl Interval {
 mod tests {
     use super::*;
 
+    fn at(iso: &str) -> DateTime {
+        DateTime::from_iso(iso).unwrap()
+    }
+
+    fn span(start_hour: u32, end_hour: u32) -> Interval {
+        let start = DateTime::from_ymd_hms(2025, 10, 1, start_hour, 0, 0).unwrap();
+        let end = DateTime::from_ymd_hms(2025, 10, 1, end_hour, 0, 0).unwrap();
+        Interval::from_date_times(start, end)
+    }
+
     #[test]
     fn test_interval_contains() {
         let start = DateTime::from_iso("2025-10-01T00:00:00Z").unwrap();
@@ -60,6 +620,22 @@ mod tests {
         assert!(!interval.contains(&before));
     }
 
+    #[test]
+    fn test_interval_contains_bounds() {
+        let first =
+            Interval::from_date_times(at("2025-10-01T00:00:00Z"), at("2025-10-02T00:00:00Z"));
+        let second =
+            Interval::from_date_times(at("2025-10-02T00:00:00Z"), at("2025-10-03T00:00:00Z"));
+        let midnight = at("2025-10-02T00:00:00Z");
+
+        assert!(first.contains(&midnight) && second.contains(&midnight));
+        assert!(!first.contains_with(&midnight, Bounds::HalfOpen));
+        assert!(second.contains_with(&midnight, Bounds::HalfOpen));
+        assert!(first.contains_with(first.start(), Bounds::HalfOpen));
+        assert!(!first.contains_with(&at("2025-09-30T23:59:59Z"), Bounds::HalfOpen));
+        assert_eq!(Bounds::default(), Bounds::Closed);
+    }
+
     #[test]
     fn test_interval_length() {
         let start = DateTime::from_iso("2025-10-01T00:00:00Z").unwrap();
@@ -69,4 +645,316 @@ mod tests {
         let dur = interval.length("days");
         assert_eq!(dur.as_unit("days"), 7);
     }
+
+    #[test]
+    fn test_interval_progress() {
+        let start = DateTime::from_iso("2025-10-01T00:00:00Z").unwrap();
+        let end = DateTime::from_iso("2025-10-11T00:00:00Z").unwrap();
+        let interval = Interval::from_date_times(start, end);
+
+        let before = DateTime::from_iso("2025-09-01T00:00:00Z").unwrap();
+        let mid = DateTime::from_iso("2025-10-06T00:00:00Z").unwrap();
+        let after = DateTime::from_iso("2025-11-01T00:00:00Z").unwrap();
+        assert_eq!(interval.progress(&before), 0.0);
+        assert!((interval.progress(&mid) - 0.5).abs() < 1e-9);
+        assert_eq!(interval.progress(&after), 1.0);
+    }
+
+    #[test]
+    fn test_interval_split_at() {
+        let interval =
+            Interval::from_date_times(at("2025-10-01T00:00:00Z"), at("2025-10-10T00:00:00Z"));
+
+        let parts = interval.split_at(&[
+            at("2025-10-05T00:00:00Z"),
+            at("2025-09-01T00:00:00Z"),
+            at("2025-10-03T00:00:00Z"),
+            at("2025-10-05T00:00:00Z"),
+            at("2025-10-10T00:00:00Z"),
+        ]);
+        let bounds: Vec<(String, String)> = parts
+            .iter()
+            .map(|p| (p.start().to_format("dd"), p.end().to_format("dd")))
+            .collect();
+        let expected = [("01", "03"), ("03", "05"), ("05", "10")];
+        assert_eq!(
+            bounds,
+            expected.map(|(a, b)| (a.to_string(), b.to_string()))
+        );
+
+        assert_eq!(interval.split_at(&[]).len(), 1);
+        let empty =
+            Interval::from_date_times(at("2025-10-01T00:00:00Z"), at("2025-10-01T00:00:00Z"));
+        assert!(empty.split_at(&[at("2025-10-01T00:00:00Z")]).is_empty());
+    }
+
+    #[test]
+    fn test_interval_split_by() {
+        let interval =
+            Interval::from_date_times(at("2025-01-31T00:00:00Z"), at("2025-05-15T00:00:00Z"));
+
+        let months = interval.split_by(&Duration::months(1));
+        let starts: Vec<String> = months
+            .iter()
+            .map(|p| p.start().to_format("MM-dd"))
+            .collect();
+        assert_eq!(starts, ["01-31", "02-28", "03-31", "04-30"]);
+        assert_eq!(months.last().unwrap().end(), &at("2025-05-15T00:00:00Z"));
+
+        let days =
+            Interval::from_date_times(at("2025-10-01T00:00:00Z"), at("2025-10-03T00:00:00Z"))
+                .split_by(&Duration::days(1));
+        assert_eq!(days.len(), 2);
+        assert_eq!(days[0].end(), days[1].start());
+
+        assert!(interval.split_by(&Duration::default()).is_empty());
+        assert!(interval.split_by(&Duration::hours(-1)).is_empty());
+    }
+
+    #[test]
+    fn test_interval_set_algebra() {
+        let hours = |i: &Interval| (i.start().hour(), i.end().hour());
+
+        let a = span(9, 12);
+        assert!(a.overlaps(&span(11, 14)));
+        assert!(a.overlaps(&span(10, 11)));
+        assert!(!a.overlaps(&span(12, 14)));
+        assert!(!a.overlaps(&span(6, 9)));
+        assert!(a.abuts(&span(12, 14)) && a.abuts(&span(6, 9)));
+        assert!(!a.abuts(&span(13, 14)));
+
+        assert_eq!(
+            a.intersection(&span(11, 14)).map(|i| hours(&i)),
+            Some((11, 12))
+        );
+        assert_eq!(
+            a.intersection(&span(10, 11)).map(|i| hours(&i)),
+            Some((10, 11))
+        );
+        assert!(a.intersection(&span(12, 14)).is_none());
+
+        assert_eq!(hours(&a.union(&span(11, 14))), (9, 14));
+        assert_eq!(hours(&a.union(&span(15, 16))), (9, 16));
+
+        let diff = |other: Interval| a.difference(&other).iter().map(hours).collect::<Vec<_>>();
+        assert_eq!(diff(span(10, 11)), [(9, 10), (11, 12)]);
+        assert_eq!(diff(span(11, 14)), [(9, 11)]);
+        assert_eq!(diff(span(6, 10)), [(10, 12)]);
+        assert_eq!(diff(span(8, 13)), []);
+        assert_eq!(diff(span(12, 13)), [(9, 12)]);
+        assert_eq!(diff(span(10, 10)), [(9, 12)]);
+        assert_eq!(diff(span(11, 10)), [(9, 12)]);
+    }
+
+    #[test]
+    fn test_interval_engulfs() {
+        let window = span(1, 5);
+        assert!(window.engulfs(&span(2, 4)));
+        assert!(window.engulfs(&span(1, 5)));
+        assert!(window.engulfs(&span(3, 3)));
+        assert!(!window.engulfs(&span(0, 4)));
+        assert!(!window.engulfs(&span(4, 6)));
+        assert!(!window.engulfs(&span(6, 7)));
+
+        assert!(span(2, 4).is_subset_of(&window));
+        assert!(window.is_subset_of(&window));
+        assert!(!span(0, 2).is_subset_of(&window));
+    }
+
+    #[test]
+    fn test_interval_count() {
+        let range = |a, b| Interval::from_date_times(at(a), at(b));
+
+        let night = range("2025-10-01T23:00:00Z", "2025-10-02T01:00:00Z");
+        assert_eq!(night.count(Unit::Days), 2);
+        assert_eq!(night.count(Unit::Hours), 2);
+        assert_eq!(night.count(Unit::Months), 1);
+
+        let quarter = range("2025-01-15T00:00:00Z", "2025-03-01T00:00:00Z");
+        assert_eq!(quarter.count(Unit::Months), 2);
+        assert_eq!(quarter.count(Unit::Years), 1);
+        assert_eq!(
+            range("2024-12-31T00:00:00Z", "2025-01-01T12:00:00Z").count(Unit::Years),
+            2
+        );
+        assert_eq!(
+            range("2025-10-01T00:00:00Z", "2025-10-15T00:00:00Z").count(Unit::Weeks),
+            3
+        );
+        assert_eq!(
+            range("2025-10-01T00:00:00Z", "2025-10-01T00:00:00.005Z").count(Unit::Milliseconds),
+            5
+        );
+        assert_eq!(
+            range("2025-10-02T00:00:00Z", "2025-10-01T00:00:00Z").count(Unit::Days),
+            0
+        );
+    }
+
+    #[test]
+    fn test_interval_is_empty() {
+        let noon = at("2025-10-01T12:00:00Z");
+        assert!(Interval::from_date_times(noon, noon).is_empty());
+        assert!(!Interval::from_date_times(noon, at("2025-10-01T12:00:00.001Z")).is_empty());
+    }
+
+    #[test]
+    fn test_interval_validation() {
+        let early = DateTime::from_iso("2025-10-01T00:00:00Z").unwrap();
+        let late = DateTime::from_iso("2025-10-02T00:00:00Z").unwrap();
+
+        let interval = Interval::try_new(early, late).unwrap();
+        assert!(interval.is_valid());
+        assert!(Interval::try_new(early, early).unwrap().is_empty());
+        assert_eq!(
+            Interval::try_new(late, early).unwrap_err(),
+            Error::EndBeforeStart
+        );
+
+        let backwards = Interval::from_date_times(late, early);
+        assert!(!backwards.is_valid());
+        let swapped = Interval::from_unordered(late, early);
+        assert!(swapped.is_valid());
+        assert_eq!((swapped.start(), swapped.end()), (&early, &late));
+    }
+
+    #[test]
+    fn test_interval_iso() {
+        let interval = Interval::from_iso("2025-10-01T00:00:00Z/2025-10-31T23:59:59Z").unwrap();
+        assert_eq!(
+            (interval.start(), interval.end()),
+            (&at("2025-10-01T00:00:00Z"), &at("2025-10-31T23:59:59Z"))
+        );
+
+        let interval = Interval::from_iso("2025-10-01T00:00:00Z/P30D").unwrap();
+        assert_eq!(interval.end(), &at("2025-10-31T00:00:00Z"));
+        let interval = Interval::from_iso("PT2H/2025-10-01T12:00:00Z").unwrap();
+        assert_eq!(interval.start(), &at("2025-10-01T10:00:00Z"));
+
+        let round_trip = Interval::from_iso(&interval.to_iso()).unwrap();
+        assert_eq!(
+            (round_trip.start(), round_trip.end()),
+            (interval.start(), interval.end())
+        );
+    }
+
+    #[test]
+    fn test_interval_iso_errors() {
+        assert_eq!(
+            Interval::from_iso("2025-10-01T00:00:00Z").unwrap_err(),
+            Error::Expected {
+                expected: '/',
+                position: 20
+            }
+        );
+        assert_eq!(
+            Interval::from_iso("P1D/P2D").unwrap_err(),
+            Error::Invalid {
+                what: "interval",
+                position: 0
+            }
+        );
+        assert_eq!(
+            Interval::from_iso("2025-10-02T00:00:00Z/2025-10-01T00:00:00Z").unwrap_err(),
+            Error::EndBeforeStart
+        );
+        assert_eq!(
+            Interval::from_iso("2025-10-01T00:00:00Z/P-1D").unwrap_err(),
+            Error::EndBeforeStart
+        );
+        assert_eq!(
+            Interval::from_iso("2025-10-01T00:00:00Z/PT").unwrap_err(),
+            Error::UnexpectedEnd { position: 23 }
+        );
+        assert_eq!(
+            Interval::from_iso("2025-10-01T00:00:00Z/2025-13-01T00:00:00Z").unwrap_err(),
+            Error::InvalidComponent {
+                unit: "month",
+                value: 13
+            }
+        );
+        assert_eq!(
+            Interval::from_iso("2025-10-01T00:00:00Z/2025-10-01X00:00:00Z").unwrap_err(),
+            Error::Expected {
+                expected: 'T',
+                position: 31
+            }
+        );
+    }
+
+    #[test]
+    fn test_interval_after_before() {
+        let jan31 = at("2025-01-31T12:00:00Z");
+
+        let after = Interval::after(jan31, &Duration::months(1));
+        assert_eq!(
+            (after.start(), after.end()),
+            (&jan31, &at("2025-02-28T12:00:00Z"))
+        );
+        let before = Interval::before(jan31, &Duration::hours(36));
+        assert_eq!(
+            (before.start(), before.end()),
+            (&at("2025-01-30T00:00:00Z"), &jan31)
+        );
+
+        assert!(Interval::after(jan31, &Duration::default()).is_empty());
+        assert!(!Interval::after(jan31, &Duration::days(-1)).is_valid());
+    }
+
+    #[test]
+    fn test_interval_endpoint_setters() {
+        let interval =
+            Interval::from_date_times(at("2025-10-01T00:00:00Z"), at("2025-10-10T00:00:00Z"));
+
+        let later = interval.with_start(at("2025-10-05T00:00:00Z"));
+        assert_eq!(
+            (later.start(), later.end()),
+            (&at("2025-10-05T00:00:00Z"), interval.end())
+        );
+        let shorter = interval.with_end(at("2025-10-02T00:00:00Z"));
+        assert_eq!(
+            (shorter.start(), shorter.end()),
+            (interval.start(), &at("2025-10-02T00:00:00Z"))
+        );
+        assert!(!interval.with_start(at("2025-11-01T00:00:00Z")).is_valid());
+
+        let shifted = interval.map_endpoints(|dt| dt - Duration::days(1));
+        assert_eq!(
+            (shifted.start(), shifted.end()),
+            (&at("2025-09-30T00:00:00Z"), &at("2025-10-09T00:00:00Z"))
+        );
+        assert_eq!(shifted.length_in(Unit::Days).get_days(), 9);
+    }
+
+    #[test]
+    fn test_interval_formatting() {
+        let interval =
+            Interval::from_date_times(at("2025-10-01T09:00:00Z"), at("2025-10-31T17:30:00Z"));
+        assert_eq!(interval.to_format("MMM d"), "Oct 1 – Oct 31");
+        assert_eq!(interval.to_format("HH:mm"), "09:00 – 17:30");
+        assert_eq!(interval.to_locale_string(), "Oct 1 – 31, 2025");
+
+        // Read in the start's zone, the end falls on the next day in Tokyo.
+        let tokyo = Interval::from_date_times(
+            at("2025-10-01T00:00:00Z").set_zone("Asia/Tokyo"),
+            at("2025-10-01T20:00:00Z"),
+        );
+        assert_eq!(tokyo.to_locale_string(), "Oct 1 – 2, 2025");
+    }
+
+    #[test]
+    fn test_interval_remaining() {
+        let start = DateTime::from_iso("2025-10-01T00:00:00Z").unwrap();
+        let end = DateTime::from_iso("2025-10-11T00:00:00Z").unwrap();
+        let interval = Interval::from_date_times(start, end);
+
+        let mid = DateTime::from_iso("2025-10-07T12:00:00Z").unwrap();
+        assert_eq!(interval.remaining(&mid).as_unit("hours"), 84);
+
+        let before = DateTime::from_iso("2025-09-01T00:00:00Z").unwrap();
+        assert_eq!(interval.remaining(&before).as_unit("days"), 10);
+
+        let after = DateTime::from_iso("2025-11-01T00:00:00Z").unwrap();
+        assert_eq!(interval.remaining(&after).as_unit("milliseconds"), 0);
+    }
 }
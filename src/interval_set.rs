@@ -0,0 +1,294 @@
+// Normalised collections of intervals, for availability calendars and other
+// multi-range bookkeeping built on `Interval`.
+
+use crate::{DateTime, Duration, Interval};
+
+/// A set of disjoint intervals, kept sorted by start.
+///
+/// Overlapping or abutting intervals are merged as they are added, so the set
+/// always holds the fewest intervals covering the same time. Empty and
+/// invalid intervals are ignored. Like [`Interval::overlaps`], intervals are
+/// treated as half-open.
+///
+/// # Examples
+///
+/// ```rust
+/// use tempotime::{DateTime, Interval, IntervalSet};
+///
+/// let morning = Interval::from_date_times(
+///     DateTime::from_iso("2025-10-01T09:00:00Z").unwrap(),
+///     DateTime::from_iso("2025-10-01T12:00:00Z").unwrap(),
+/// );
+/// let afternoon = Interval::from_date_times(
+///     DateTime::from_iso("2025-10-01T13:00:00Z").unwrap(),
+///     DateTime::from_iso("2025-10-01T17:00:00Z").unwrap(),
+/// );
+/// let meeting = Interval::from_date_times(
+///     DateTime::from_iso("2025-10-01T10:00:00Z").unwrap(),
+///     DateTime::from_iso("2025-10-01T11:00:00Z").unwrap(),
+/// );
+///
+/// let mut free = IntervalSet::new();
+/// free.insert(morning);
+/// free.insert(afternoon);
+/// free.remove(&meeting);
+///
+/// assert_eq!(free.len(), 3);
+/// assert_eq!(free.total().as_unit("hours"), 6);
+/// assert!(free.contains(&DateTime::from_iso("2025-10-01T14:00:00Z").unwrap()));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>,
+}
+
+impl IntervalSet {
+    /// An empty set.
+    pub fn new() -> Self {
+        IntervalSet::default()
+    }
+
+    /// The set covering all of `intervals`, merged as Luxon's
+    /// `Interval.merge`.
+    pub fn from_intervals(intervals: impl IntoIterator<Item = Interval>) -> Self {
+        let mut set = IntervalSet {
+            intervals: intervals.into_iter().collect(),
+        };
+        set.normalize();
+        set
+    }
+
+    /// The time covered by exactly one of `intervals`, as Luxon's
+    /// `Interval.xor`: overlapping stretches cancel out.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::{DateTime, Interval, IntervalSet};
+    ///
+    /// let first = Interval::from_date_times(
+    ///     DateTime::from_iso("2025-10-01T09:00:00Z").unwrap(),
+    ///     DateTime::from_iso("2025-10-01T12:00:00Z").unwrap(),
+    /// );
+    /// let second = Interval::from_date_times(
+    ///     DateTime::from_iso("2025-10-01T11:00:00Z").unwrap(),
+    ///     DateTime::from_iso("2025-10-01T14:00:00Z").unwrap(),
+    /// );
+    /// let set = IntervalSet::xor(&[first, second]);
+    /// let hours: Vec<_> = set.iter().map(|i| (i.start().hour(), i.end().hour())).collect();
+    /// assert_eq!(hours, [(9, 11), (12, 14)]);
+    /// ```
+    pub fn xor(intervals: &[Interval]) -> Self {
+        let mut events: Vec<(DateTime, i32)> = intervals
+            .iter()
+            .filter(|interval| !interval.is_empty())
+            .flat_map(|interval| [(*interval.start(), 1), (*interval.end(), -1)])
+            .collect();
+        events.sort();
+
+        let mut pieces = Vec::new();
+        let mut depth = 0;
+        let mut from: Option<DateTime> = None;
+        for (at, change) in events {
+            if let Some(start) = from {
+                if depth == 1 && start < at {
+                    pieces.push(Interval::from_date_times(start, at));
+                }
+            }
+            depth += change;
+            from = Some(at);
+        }
+        IntervalSet::from_intervals(pieces)
+    }
+
+    /// Adds `interval`, merging it with any interval it overlaps or abuts.
+    pub fn insert(&mut self, interval: Interval) {
+        self.intervals.push(interval);
+        self.normalize();
+    }
+
+    /// Removes the time in `interval`, splitting intervals it cuts through.
+    pub fn remove(&mut self, interval: &Interval) {
+        self.intervals = self
+            .intervals
+            .iter()
+            .flat_map(|kept| kept.difference(interval))
+            .collect();
+    }
+
+    /// Adds every interval of `other`.
+    pub fn merge(&mut self, other: &IntervalSet) {
+        self.intervals.extend(other.intervals.iter().cloned());
+        self.normalize();
+    }
+
+    /// Whether `dt` falls in one of the intervals, ends excluded.
+    pub fn contains(&self, dt: &DateTime) -> bool {
+        let after = self
+            .intervals
+            .partition_point(|interval| interval.start() <= dt);
+        after > 0 && dt < self.intervals[after - 1].end()
+    }
+
+    /// The total time covered, in milliseconds.
+    pub fn total(&self) -> Duration {
+        let millis = self
+            .intervals
+            .iter()
+            .map(|interval| interval.end().to_millis() - interval.start().to_millis())
+            .sum();
+        Duration::from_millis(millis)
+    }
+
+    /// The gaps between consecutive intervals, in order.
+    pub fn gaps(&self) -> Vec<Interval> {
+        self.intervals
+            .windows(2)
+            .map(|pair| Interval::from_date_times(*pair[0].end(), *pair[1].start()))
+            .collect()
+    }
+
+    /// The intervals, sorted and disjoint.
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    /// Iterates over the intervals in order.
+    pub fn iter(&self) -> std::slice::Iter<'_, Interval> {
+        self.intervals.iter()
+    }
+
+    /// The number of disjoint intervals.
+    pub fn len(&self) -> usize {
+        self.intervals.len()
+    }
+
+    /// Whether the set covers no time at all.
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    // Sorts, drops empty intervals and merges those that overlap or abut.
+    fn normalize(&mut self) {
+        let mut intervals = std::mem::take(&mut self.intervals);
+        intervals.retain(|interval| !interval.is_empty());
+        intervals.sort_by(|a, b| a.start().cmp(b.start()));
+        for interval in intervals {
+            match self.intervals.last_mut() {
+                Some(last) if interval.start() <= last.end() => {
+                    if interval.end() > last.end() {
+                        *last = last.with_end(*interval.end());
+                    }
+                }
+                _ => self.intervals.push(interval),
+            }
+        }
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(iter: I) -> Self {
+        IntervalSet::from_intervals(iter)
+    }
+}
+
+impl<'a> IntoIterator for &'a IntervalSet {
+    type Item = &'a Interval;
+    type IntoIter = std::slice::Iter<'a, Interval>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.intervals.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(hour: u32) -> DateTime {
+        DateTime::from_ymd_hms(2025, 10, 1, hour, 0, 0).unwrap()
+    }
+
+    fn span(start: u32, end: u32) -> Interval {
+        Interval::from_date_times(at(start), at(end))
+    }
+
+    fn hours(set: &IntervalSet) -> Vec<(u32, u32)> {
+        set.iter()
+            .map(|interval| (interval.start().hour(), interval.end().hour()))
+            .collect()
+    }
+
+    #[test]
+    fn test_merges_on_insert() {
+        let set = IntervalSet::from_intervals([
+            span(13, 15),
+            span(9, 10),
+            span(10, 11),
+            span(14, 16),
+            span(20, 20),
+            span(18, 17),
+        ]);
+        assert_eq!(hours(&set), [(9, 11), (13, 16)]);
+
+        let mut set = set;
+        set.insert(span(11, 13));
+        assert_eq!(hours(&set), [(9, 16)]);
+        set.insert(span(10, 12));
+        assert_eq!(hours(&set), [(9, 16)]);
+    }
+
+    #[test]
+    fn test_remove_and_merge() {
+        let mut set: IntervalSet = [span(8, 12), span(13, 18)].into_iter().collect();
+        set.remove(&span(11, 14));
+        assert_eq!(hours(&set), [(8, 11), (14, 18)]);
+        set.remove(&span(15, 16));
+        assert_eq!(hours(&set), [(8, 11), (14, 15), (16, 18)]);
+        set.remove(&span(17, 17));
+        assert_eq!(hours(&set), [(8, 11), (14, 15), (16, 18)]);
+        set.remove(&span(0, 23));
+        assert!(set.is_empty());
+
+        let mut set = IntervalSet::from_intervals([span(1, 2)]);
+        set.merge(&IntervalSet::from_intervals([span(2, 3), span(5, 6)]));
+        assert_eq!(hours(&set), [(1, 3), (5, 6)]);
+    }
+
+    #[test]
+    fn test_xor() {
+        assert_eq!(
+            hours(&IntervalSet::xor(&[span(9, 12), span(11, 14)])),
+            [(9, 11), (12, 14)]
+        );
+        assert_eq!(hours(&IntervalSet::xor(&[span(9, 12), span(9, 12)])), []);
+        assert_eq!(
+            hours(&IntervalSet::xor(&[span(9, 10), span(10, 11)])),
+            [(9, 11)]
+        );
+        assert_eq!(
+            hours(&IntervalSet::xor(&[span(8, 16), span(9, 15), span(10, 14)])),
+            [(8, 9), (15, 16)]
+        );
+    }
+
+    #[test]
+    fn test_total_gaps_and_contains() {
+        let set = IntervalSet::from_intervals([span(9, 11), span(13, 14), span(16, 20)]);
+        assert_eq!(set.total().as_unit("hours"), 7);
+        let gaps: Vec<(u32, u32)> = set
+            .gaps()
+            .iter()
+            .map(|i| (i.start().hour(), i.end().hour()))
+            .collect();
+        assert_eq!(gaps, [(11, 13), (14, 16)]);
+
+        assert!(set.contains(&at(9)));
+        assert!(set.contains(&at(10)));
+        assert!(!set.contains(&at(11)));
+        assert!(!set.contains(&at(8)));
+        assert!(set.contains(&at(19)));
+        assert!(!set.contains(&at(20)));
+        assert!(IntervalSet::new().total().is_zero());
+    }
+}
@@ -0,0 +1,526 @@
+// ISO-8601 parsing and writing shared by the chrono and zero-deps backends, so both accept
+// exactly the same strings. The parser splits the text into calendar fields,
+// resolving ordinal and week dates on the way; the remaining range checks
+// happen when a DateTime is built from them.
+
+use crate::calendar::{WeekRule, Weekday};
+use crate::error::Error;
+use crate::format::write_padded;
+use crate::options::{IsoFormat, IsoOffset, IsoOptions};
+
+// Fields of an ISO-8601 date-time. `offset_secs` is `None` when the string
+// carries no designator, which callers treat as UTC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct IsoFields {
+    pub(crate) year: i32,
+    pub(crate) month: u32,
+    pub(crate) day: u32,
+    pub(crate) hour: u32,
+    pub(crate) minute: u32,
+    pub(crate) second: u32,
+    pub(crate) millis: u32,
+    pub(crate) offset_secs: Option<i32>,
+}
+
+// Parses the extended format `[±]YYYY-MM-DD[THH:MM:SS[.fff][Z|±HH:MM]]`. Years
+// are four digits, or four or more when signed (`-0044`, `+12345`); the date
+// may be ordinal (`YYYY-DDD`) or a week date (`YYYY-Www-D`), a space may
+// replace the `T`, and the fraction may be 1–9 digits (kept to millisecond
+// precision). A bare date is midnight.
+//
+// The basic format (`YYYYMMDD[THHMMSS[.fff][Z|±HHMM]]`, `YYYYDDD`, `YYYYWwwD`)
+// is accepted too, with a four-digit year. Date and time must then both be
+// basic; mixing the two forms is an error, as ISO-8601 requires.
+pub(crate) fn parse(s: &str) -> Result<IsoFields, Error> {
+    let mut cur = Cursor {
+        s: s.trim().as_bytes(),
+        pos: 0,
+    };
+
+    let basic = cur.is_basic();
+    let (year, month, day) = if basic {
+        cur.basic_date()?
+    } else {
+        cur.date()?
+    };
+    let (mut hour, mut minute, mut second, mut millis) = (0, 0, 0, 0);
+    let mut offset_secs = None;
+    if cur.peek().is_some() {
+        match cur.next() {
+            Some(b'T' | b't' | b' ') => {}
+            _ => {
+                return Err(Error::Expected {
+                    expected: 'T',
+                    position: cur.pos - 1,
+                })
+            }
+        }
+        (hour, minute, second, millis) = if basic {
+            cur.basic_time()?
+        } else {
+            cur.time()?
+        };
+        offset_secs = cur.offset()?;
+    }
+    cur.end()?;
+
+    Ok(IsoFields {
+        year,
+        month,
+        day,
+        hour,
+        minute,
+        second,
+        millis,
+        offset_secs,
+    })
+}
+
+// Parses SQL timestamp literals as printed by Postgres and MySQL:
+// `YYYY-MM-DD[ HH:MM:SS[.fff]][[ ]Z|±HH[:MM]]`. The time defaults to midnight.
+pub(crate) fn parse_sql(s: &str) -> Result<IsoFields, Error> {
+    let mut cur = Cursor {
+        s: s.trim().as_bytes(),
+        pos: 0,
+    };
+
+    let (year, month, day) = cur.date()?;
+    let (mut hour, mut minute, mut second, mut millis) = (0, 0, 0, 0);
+    let mut offset_secs = None;
+    if cur.peek().is_some() {
+        cur.expect(b' ')?;
+        (hour, minute, second, millis) = cur.time()?;
+        if cur.peek() == Some(b' ') {
+            cur.pos += 1;
+        }
+        offset_secs = cur.offset()?;
+    }
+    cur.end()?;
+
+    Ok(IsoFields {
+        year,
+        month,
+        day,
+        hour,
+        minute,
+        second,
+        millis,
+        offset_secs,
+    })
+}
+
+// UTC offset as `±HH:MM`.
+pub(crate) fn write_offset<W: core::fmt::Write>(w: &mut W, offset_secs: i32) -> core::fmt::Result {
+    let abs = offset_secs.unsigned_abs();
+    w.write_char(if offset_secs < 0 { '-' } else { '+' })?;
+    write_padded(w, (abs / 3600) as u64, 2)?;
+    w.write_char(':')?;
+    write_padded(w, (abs % 3600 / 60) as u64, 2)
+}
+
+// Wall-clock `parts` at `offset_secs` east of UTC, laid out per `options`.
+pub(crate) fn write_datetime<W: core::fmt::Write>(
+    w: &mut W,
+    (year, month, day, hour, minute, second, millis): (i32, u32, u32, u32, u32, u32, u32),
+    offset_secs: i32,
+    options: &IsoOptions,
+) -> core::fmt::Result {
+    let extended = options.format == IsoFormat::Extended;
+    let (date_sep, time_sep) = if extended { ("-", ":") } else { ("", "") };
+    write_year(w, year)?;
+    for (sep, value) in [
+        (date_sep, month),
+        (date_sep, day),
+        ("T", hour),
+        (time_sep, minute),
+    ] {
+        w.write_str(sep)?;
+        write_padded(w, value as u64, 2)?;
+    }
+    if !(options.suppress_seconds && second == 0 && millis == 0) {
+        w.write_str(time_sep)?;
+        write_padded(w, second as u64, 2)?;
+        if !(options.suppress_milliseconds && millis == 0) {
+            w.write_char('.')?;
+            write_padded(w, millis as u64, 3)?;
+        }
+    }
+    match options.offset {
+        IsoOffset::Omit => Ok(()),
+        IsoOffset::Z if offset_secs == 0 => w.write_char('Z'),
+        _ => {
+            let abs = offset_secs.unsigned_abs();
+            w.write_char(if offset_secs < 0 { '-' } else { '+' })?;
+            write_padded(w, (abs / 3600) as u64, 2)?;
+            w.write_str(time_sep)?;
+            write_padded(w, (abs % 3600 / 60) as u64, 2)
+        }
+    }
+}
+
+// Year as written in ISO-8601: zero-padded to four digits, with a leading `-`
+// for years before 1 CE in astronomical numbering (`-0044` is 45 BCE) and a
+// `+` past 9999, which only a zone ahead of UTC can show (`+10000`).
+pub(crate) fn write_year<W: core::fmt::Write>(w: &mut W, year: i32) -> core::fmt::Result {
+    if year < 0 {
+        w.write_char('-')?;
+    } else if year > 9999 {
+        w.write_char('+')?;
+    }
+    write_padded(w, year.unsigned_abs() as u64, 4)
+}
+
+struct Cursor<'a> {
+    s: &'a [u8],
+    pos: usize,
+}
+
+impl Cursor<'_> {
+    fn next(&mut self) -> Option<u8> {
+        let b = self.s.get(self.pos).copied();
+        if b.is_some() {
+            self.pos += 1;
+        }
+        b
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.s.get(self.pos).copied()
+    }
+
+    fn expect(&mut self, want: u8) -> Result<(), Error> {
+        match self.next() {
+            Some(b) if b == want => Ok(()),
+            Some(_) => Err(Error::Expected {
+                expected: want as char,
+                position: self.pos - 1,
+            }),
+            None => Err(Error::UnexpectedEnd { position: self.pos }),
+        }
+    }
+
+    fn digits(&mut self, n: usize, what: &'static str) -> Result<u32, Error> {
+        let end = self.pos + n;
+        match self.s.get(self.pos..end) {
+            Some(run) if run.iter().all(u8::is_ascii_digit) => {
+                self.pos = end;
+                Ok(run.iter().fold(0, |acc, b| acc * 10 + (b - b'0') as u32))
+            }
+            Some(_) => Err(Error::Invalid {
+                what,
+                position: self.pos,
+            }),
+            None => Err(Error::UnexpectedEnd {
+                position: self.s.len(),
+            }),
+        }
+    }
+
+    // Number of consecutive digits from the current position.
+    fn run_of_digits(&self) -> usize {
+        self.s[self.pos..]
+            .iter()
+            .take_while(|b| b.is_ascii_digit())
+            .count()
+    }
+
+    fn end(&self) -> Result<(), Error> {
+        if self.pos == self.s.len() {
+            Ok(())
+        } else {
+            Err(Error::TrailingInput { position: self.pos })
+        }
+    }
+
+    // Whether the input starts with a basic-format date: an optionally signed
+    // run of seven or eight digits, or four digits followed by a week `W`.
+    fn is_basic(&self) -> bool {
+        let start = usize::from(matches!(self.peek(), Some(b'-' | b'+')));
+        let digits = self.s[start..]
+            .iter()
+            .take_while(|b| b.is_ascii_digit())
+            .count();
+        matches!(digits, 7 | 8) || (digits == 4 && self.s.get(start + 4) == Some(&b'W'))
+    }
+
+    // `[±]YYYY-MM-DD`, the ordinal `[±]YYYY-DDD` or the week date `[±]YYYY-Www-D`
+    fn date(&mut self) -> Result<(i32, u32, u32), Error> {
+        let year = self.year()?;
+        self.expect(b'-')?;
+        self.date_after_year(year, true)
+    }
+
+    // `[±]YYYYMMDD`, the ordinal `[±]YYYYDDD` or the week date `[±]YYYYWwwD`
+    fn basic_date(&mut self) -> Result<(i32, u32, u32), Error> {
+        let sign = match self.peek() {
+            Some(b'-') => -1,
+            Some(b'+') => 1,
+            _ => 0,
+        };
+        if sign != 0 {
+            self.pos += 1;
+        }
+        let year = self.digits(4, "year")? as i32;
+        self.date_after_year(if sign < 0 { -year } else { year }, false)
+    }
+
+    // The part of a date after the year, with `-` separators when `extended`.
+    fn date_after_year(&mut self, year: i32, extended: bool) -> Result<(i32, u32, u32), Error> {
+        if self.peek() == Some(b'W') {
+            self.pos += 1;
+            let week = self.digits(2, "week")?;
+            if extended {
+                self.expect(b'-')?;
+            }
+            let weekday = match self.digits(1, "weekday")? {
+                n @ 1..=7 => Weekday::from_monday0(n - 1),
+                n => {
+                    return Err(Error::InvalidComponent {
+                        unit: "weekday",
+                        value: n as i64,
+                    })
+                }
+            };
+            return WeekRule::ISO.date_from_week(year, week, weekday).ok_or(
+                Error::InvalidComponent {
+                    unit: "week",
+                    value: week as i64,
+                },
+            );
+        }
+        if self.run_of_digits() == 3 {
+            let ordinal = self.digits(3, "day of year")?;
+            let (month, day) = crate::calendar::month_day_from_ordinal(year, ordinal).ok_or(
+                Error::InvalidComponent {
+                    unit: "day of year",
+                    value: ordinal as i64,
+                },
+            )?;
+            return Ok((year, month, day));
+        }
+        let month = self.digits(2, "month")?;
+        if extended {
+            self.expect(b'-')?;
+        }
+        let day = self.digits(2, "day")?;
+        Ok((year, month, day))
+    }
+
+    // `HH:MM:SS[.fff]`
+    fn time(&mut self) -> Result<(u32, u32, u32, u32), Error> {
+        let hour = self.digits(2, "hour")?;
+        self.expect(b':')?;
+        let minute = self.digits(2, "minute")?;
+        self.expect(b':')?;
+        let second = self.digits(2, "second")?;
+        let millis = self.fraction()?;
+        Ok((hour, minute, second, millis))
+    }
+
+    // `HHMMSS[.fff]`
+    fn basic_time(&mut self) -> Result<(u32, u32, u32, u32), Error> {
+        let hour = self.digits(2, "hour")?;
+        let minute = self.digits(2, "minute")?;
+        let second = self.digits(2, "second")?;
+        let millis = self.fraction()?;
+        Ok((hour, minute, second, millis))
+    }
+
+    fn year(&mut self) -> Result<i32, Error> {
+        let sign = match self.peek() {
+            Some(b'-') => Some(-1i64),
+            Some(b'+') => Some(1),
+            _ => None,
+        };
+        if sign.is_some() {
+            self.pos += 1;
+        }
+        let start = self.pos;
+        while self.peek().is_some_and(|b| b.is_ascii_digit()) {
+            self.pos += 1;
+        }
+        let len = self.pos - start;
+        if len < 4 || (sign.is_none() && len > 4) || len > 9 {
+            return Err(Error::Invalid {
+                what: "year",
+                position: start,
+            });
+        }
+        let value = self.s[start..self.pos]
+            .iter()
+            .fold(0i64, |acc, b| acc * 10 + (b - b'0') as i64);
+        Ok((sign.unwrap_or(1) * value) as i32)
+    }
+
+    fn fraction(&mut self) -> Result<u32, Error> {
+        if !matches!(self.peek(), Some(b'.' | b',')) {
+            return Ok(0);
+        }
+        self.pos += 1;
+        let start = self.pos;
+        while self.peek().is_some_and(|b| b.is_ascii_digit()) {
+            self.pos += 1;
+        }
+        let len = self.pos - start;
+        if len == 0 || len > 9 {
+            return Err(Error::Invalid {
+                what: "fraction",
+                position: start,
+            });
+        }
+        // Keep the first three digits, right-padded: ".5" is 500 ms.
+        let millis = self.s[start..start + len.min(3)]
+            .iter()
+            .fold(0, |acc, b| acc * 10 + (b - b'0') as u32);
+        Ok(millis * 10u32.pow(3 - len.min(3) as u32))
+    }
+
+    fn offset(&mut self) -> Result<Option<i32>, Error> {
+        let sign = match self.peek() {
+            None => return Ok(None),
+            Some(b'Z' | b'z') => {
+                self.pos += 1;
+                return Ok(Some(0));
+            }
+            Some(b'+') => 1,
+            Some(b'-') => -1,
+            Some(_) => {
+                return Err(Error::Invalid {
+                    what: "offset",
+                    position: self.pos,
+                })
+            }
+        };
+        let start = self.pos;
+        self.pos += 1;
+        let hours = self.digits(2, "offset")?;
+        let minutes = match self.peek() {
+            Some(b':') => {
+                self.pos += 1;
+                self.digits(2, "offset")?
+            }
+            Some(b'0'..=b'9') => self.digits(2, "offset")?,
+            _ => 0,
+        };
+        if hours > 23 || minutes > 59 {
+            return Err(Error::Invalid {
+                what: "offset",
+                position: start,
+            });
+        }
+        Ok(Some(sign * (hours * 3600 + minutes * 60) as i32))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_fields() {
+        let f = parse("2025-10-30T14:30:05.1234+05:30").unwrap();
+        assert_eq!((f.year, f.month, f.day), (2025, 10, 30));
+        assert_eq!((f.hour, f.minute, f.second, f.millis), (14, 30, 5, 123));
+        assert_eq!(f.offset_secs, Some(5 * 3600 + 30 * 60));
+
+        let f = parse("-0044-03-15 00:00:00").unwrap();
+        assert_eq!(f.year, -44);
+        assert_eq!(f.offset_secs, None);
+
+        assert_eq!(parse("2025-10-30T14:30:00.5Z").unwrap().millis, 500);
+        assert_eq!(
+            parse("2025-10-30T14:30:00-0800").unwrap().offset_secs,
+            Some(-8 * 3600)
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_malformed() {
+        assert!(parse("25-10-30T14:30:00Z").is_err());
+        assert!(parse("12025-10-30T14:30:00Z").is_err());
+        assert!(parse("2025-10-30T").is_err());
+        assert!(parse("2025-366").is_err());
+        assert!(parse("2025-10-30T14:30").is_err());
+        assert!(parse("2025-10-30T14:30:00Zjunk").is_err());
+        assert!(parse("2025-10-30T14:30:00.Z").is_err());
+        assert!(parse("2025-10-30T14:30:00+25:00").is_err());
+    }
+
+    #[test]
+    fn test_parse_dates_without_time() {
+        let f = parse("2025-10-30").unwrap();
+        assert_eq!(
+            (f.year, f.month, f.day, f.hour, f.offset_secs),
+            (2025, 10, 30, 0, None)
+        );
+
+        let f = parse("2024-366T12:00:00Z").unwrap();
+        assert_eq!((f.month, f.day, f.hour), (12, 31, 12));
+        assert_eq!(parse("2025-300").unwrap().day, 27);
+
+        // Week 1 of 2025 starts on Monday 2024-12-30
+        let f = parse("2025-W01-1").unwrap();
+        assert_eq!((f.year, f.month, f.day), (2024, 12, 30));
+        assert!(parse("2025-W53-1").is_err());
+        assert!(parse("2020-W53-7").is_ok());
+        assert!(parse("2025-W44-8").is_err());
+    }
+
+    #[test]
+    fn test_parse_basic_format() {
+        let f = parse("20251030T143005.25+0530").unwrap();
+        assert_eq!((f.year, f.month, f.day), (2025, 10, 30));
+        assert_eq!((f.hour, f.minute, f.second, f.millis), (14, 30, 5, 250));
+        assert_eq!(f.offset_secs, Some(5 * 3600 + 30 * 60));
+
+        assert_eq!(parse("20251030T143000Z").unwrap().offset_secs, Some(0));
+        assert_eq!(parse("20251030").unwrap().hour, 0);
+        assert_eq!(parse("-00440315").unwrap().year, -44);
+        assert_eq!(parse("2025303").unwrap().day, 30);
+        assert_eq!(parse("2025W441").unwrap().day, 27);
+
+        assert!(parse("20251030T14:30:00Z").is_err());
+        assert!(parse("2025-10-30T143000Z").is_err());
+        assert!(parse("202510301").is_err());
+    }
+
+    #[test]
+    fn test_parse_sql() {
+        let f = parse_sql("2025-10-30").unwrap();
+        assert_eq!(
+            (f.year, f.month, f.day, f.hour, f.offset_secs),
+            (2025, 10, 30, 0, None)
+        );
+
+        let f = parse_sql("2025-10-30 14:30:00.123").unwrap();
+        assert_eq!((f.hour, f.minute, f.millis), (14, 30, 123));
+
+        assert_eq!(
+            parse_sql("2025-10-30 14:30:00+02").unwrap().offset_secs,
+            Some(7200)
+        );
+        assert_eq!(
+            parse_sql("2025-10-30 14:30:00.000 -04:00")
+                .unwrap()
+                .offset_secs,
+            Some(-14400)
+        );
+        assert!(parse_sql("2025-10-30T14:30:00").is_err());
+        assert!(parse_sql("2025-10-30 14:30").is_err());
+    }
+
+    #[test]
+    fn test_write_year() {
+        let mut s = String::new();
+        write_year(&mut s, -44).unwrap();
+        s.push(' ');
+        write_year(&mut s, 7).unwrap();
+        s.push(' ');
+        write_year(&mut s, 10000).unwrap();
+        assert_eq!(s, "-0044 0007 +10000");
+
+        let mut s = String::new();
+        write_offset(&mut s, -(5 * 3600 + 30 * 60)).unwrap();
+        assert_eq!(s, "-05:30");
+    }
+}
@@ -66,6 +66,9 @@
 //! | `chrono` | Accurate month/year math | +~2MB | Need precise date arithmetic |
 //! | `tz` | IANA timezone database | +~2MB | Need timezone conversions |
 //! | `serde` | JSON serialization | +~100KB | Need to serialize/deserialize |
+//! | `time` | `From` conversions with the `time` crate | +`time` | Interoperating with `time` users |
+//! | `prost` | Conversions with `prost_types::Timestamp` | +`prost-types` | Building gRPC services |
+//! | `locales` | Month and weekday names in 14 more languages | None | Localized output |
 //!
 //! ## 📚 Examples
 //!
@@ -80,11 +83,11 @@
 //! let formatted = DateTime::from_format("Oct 30, 2025", "MMM dd, yyyy").unwrap();
 //!
 //! // Add or subtract durations
-//! let tomorrow = now.clone().plus(&Duration::from_object(&[("days", 1)]));
-//! let last_week = now.clone().minus(&Duration::from_object(&[("weeks", 1)]));
+//! let tomorrow = now.plus(&Duration::from_object(&[("days", 1)]));
+//! let last_week = now.minus(&Duration::from_object(&[("weeks", 1)]));
 //!
 //! // Round to start/end of units
-//! let start_of_day = now.clone().start_of("day");     // 00:00:00
+//! let start_of_day = now.start_of("day");     // 00:00:00
 //! let end_of_month = now.end_of("month");     // Last millisecond of month
 //! ```
 //!
@@ -98,7 +101,7 @@
 //!     .plus(&Duration::from_object(&[("days", 3), ("hours", 2)]))
 //!     .start_of("day")
 //!     .to_format("yyyy-MM-dd HH:mm:ss");
-//! 
+//!
 //! println!("3 days from now (start of day): {}", result);
 //! ```
 //!
@@ -132,8 +135,8 @@
 //!
 //! // Convert to different timezones
 //! let utc = dt();
-//! let ny = utc.clone().set_zone("America/New_York");
-//! let tokyo = utc.clone().set_zone("Asia/Tokyo");
+//! let ny = utc.set_zone("America/New_York");
+//! let tokyo = utc.set_zone("Asia/Tokyo");
 //!
 //! println!("UTC:      {}", utc.to_format("h:mm a"));
 //! println!("New York: {}", ny.to_format("h:mm a"));
@@ -168,7 +171,7 @@
 //!
 //! // Define a time interval
 //! let start = dt();
-//! let end = start.clone().plus(&Duration::from_object(&[("days", 30)]));
+//! let end = start.plus(&Duration::from_object(&[("days", 30)]));
 //! let interval = Interval::from_date_times(start, end);
 //!
 //! // Check if a datetime falls within the interval
@@ -199,8 +202,9 @@
 //!
 //! | Token | Output | Description |
 //! |-------|--------|-------------|
-//! | `yyyy` | 2025 | 4-digit year |
+//! | `yyyy` | 2025 | 4-digit year (`-0044` before 1 CE) |
 //! | `yy` | 25 | 2-digit year |
+//! | `G` / `GG` / `GGGGG` | AD / Anno Domini / A | Era |
 //! | `MMMM` | October | Full month name |
 //! | `MMM` | Oct | Short month name |
 //! | `MM` | 10 | 2-digit month |
@@ -208,6 +212,7 @@
 //! | `dd` | 30 | 2-digit day |
 //! | `d` | 30 | Day (no padding) |
 //! | `do` | 30th | Day with ordinal suffix |
+//! | `DDD` | 303 | Day of year (padded) |
 //! | `EEEE` | Wednesday | Full weekday name |
 //! | `EEE` | Wed | Short weekday name |
 //! | `HH` | 14 | 24-hour (padded) |
@@ -218,6 +223,10 @@
 //! | `ss` | 00 | Seconds (padded) |
 //! | `SSS` | 123 | Milliseconds |
 //! | `a` | pm | AM/PM lowercase |
+//! | `n` / `nn` | 44 | Week number under [`Settings::week_rule`] |
+//! | `ii` / `iiii` | 25 / 2025 | Week-year under [`Settings::week_rule`] |
+//! | `W` / `WW` | 44 | ISO week number |
+//! | `kk` / `kkkk` | 25 / 2025 | ISO week-year |
 //!
 //! Escape literal text with single quotes: `'at'` → "at"
 //!
@@ -308,15 +317,46 @@ pub fn add(left: u64, right: u64) -> u64 {
     left + right
 }
 
+mod calendar;
 mod datetime;
 mod duration;
+mod error;
 mod format;
+mod http;
+mod human;
+#[cfg(any(feature = "time", feature = "prost"))]
+mod interop;
 mod interval;
+mod interval_set;
+mod iso;
 mod locale;
+mod options;
+mod provider;
+mod relative;
+mod settings;
+mod tzfile;
+mod unit;
+mod zone;
 
+pub use calendar::{WeekRule, Weekday, Weekend};
 pub use datetime::DateTime;
 pub use duration::Duration;
+pub use error::Error;
+pub use format::Format;
 pub use interval::Interval;
+pub use interval_set::IntervalSet;
+pub use locale::Locale;
+pub use options::{
+    Bounds, Disambiguation, HourCycle, HumanOptions, IsoFormat, IsoOffset, IsoOptions,
+    LocaleOptions, LocaleStyle, Overflow, ParseOptions, RelativeOptions, SetOptions,
+    SetZoneOptions,
+};
+#[cfg(feature = "tz")]
+pub use provider::ChronoTzProvider;
+pub use provider::{StaticProvider, TimeZoneProvider};
+pub use settings::Settings;
+pub use tzfile::TzfileProvider;
+pub use unit::Unit;
 
 /// Convenience function to get the current DateTime.
 ///
@@ -334,6 +374,92 @@ pub fn dt() -> DateTime {
     DateTime::now()
 }
 
+/// Builds a UTC [`DateTime`] from a date literal at compile time.
+///
+/// Accepts `yyyy-MM-dd`, optionally followed by `HH:mm` or `HH:mm:ss`. The
+/// value is evaluated in a `const` block, so an invalid date such as
+/// `2025-02-30` fails to compile.
+///
+/// # Examples
+///
+/// ```rust
+/// use tempotime::{datetime, DateTime};
+///
+/// const RELEASE_CUTOFF: DateTime = datetime!(2025-10-30 14:30);
+/// assert_eq!(RELEASE_CUTOFF, DateTime::from_iso("2025-10-30T14:30:00Z").unwrap());
+///
+/// let new_year = datetime!(2026-01-01);
+/// assert_eq!(new_year.to_format("yyyy-MM-dd HH:mm:ss"), "2026-01-01 00:00:00");
+/// ```
+///
+/// ```compile_fail
+/// let _ = tempotime::datetime!(2025-02-30);
+/// ```
+#[macro_export]
+macro_rules! datetime {
+    ($y:literal - $m:literal - $d:literal) => {
+        $crate::datetime!($y - $m - $d 0:0:0)
+    };
+    ($y:literal - $m:literal - $d:literal $h:literal : $mi:literal) => {
+        $crate::datetime!($y - $m - $d $h:$mi:0)
+    };
+    ($y:literal - $m:literal - $d:literal $h:literal : $mi:literal : $s:literal) => {{
+        const DT: $crate::DateTime = $crate::DateTime::__from_literal_parts([
+            stringify!($y),
+            stringify!($m),
+            stringify!($d),
+            stringify!($h),
+            stringify!($mi),
+            stringify!($s),
+        ]);
+        DT
+    }};
+}
+
+/// Checks a [`to_format`](DateTime::to_format) pattern at compile time and
+/// returns it as a `&'static str`.
+///
+/// Every letter outside single quotes must be a known token at a length it
+/// has, so a typo such as `YYYY` (instead of `yyyy`), `DD` (instead of `dd`)
+/// or an unquoted `T` fails to compile instead of silently printing the
+/// letters. Unbalanced quotes are rejected too.
+///
+/// # Examples
+///
+/// ```rust
+/// use tempotime::{tempo_format, DateTime};
+///
+/// const LOG_FORMAT: &str = tempo_format!("yyyy-MM-dd'T'HH:mm:ss.SSS");
+///
+/// let dt = DateTime::from_iso("2025-10-30T14:30:00Z").unwrap();
+/// assert_eq!(dt.to_format(tempo_format!("MMMM do, yyyy")), "October 30th, 2025");
+/// ```
+///
+/// ```compile_fail
+/// let _ = tempotime::tempo_format!("YYYY-MM-dd");
+/// ```
+///
+/// ```compile_fail
+/// let _ = tempotime::tempo_format!("yyyy-MM-DD");
+/// ```
+#[macro_export]
+macro_rules! tempo_format {
+    ($fmt:literal) => {{
+        const FORMAT: &str = $crate::__check_format($fmt);
+        FORMAT
+    }};
+}
+
+// Support for `tempo_format!`; panics, and so fails const evaluation, on an
+// invalid pattern.
+#[doc(hidden)]
+pub const fn __check_format(fmt: &'static str) -> &'static str {
+    if let Some(problem) = format::check_format(fmt) {
+        panic!("{}", problem);
+    }
+    fmt
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -341,7 +467,7 @@ mod tests {
     #[test]
     fn test_dt_convenience() {
         let now = dt();
-        assert!(now.to_iso().len() > 0);
+        assert!(!now.to_iso().is_empty());
     }
 
     #[test]
@@ -1,5 +1,6 @@
-#[cfg(feature = "chrono")]
-use crate::format::format_datetime;
+use std::sync::RwLock;
+
+use crate::options::{HourCycle, LocaleOptions, LocaleStyle};
 
 pub const DATE_SHORT: &str = "M/d/yyyy";
 pub const DATE_MED: &str = "MMM d, yyyy";
@@ -9,53 +10,718 @@ pub const TIME_WITH_SECONDS: &str = "h:mm:ss a";
 pub const DATETIME_SHORT: &str = "M/d/yyyy, h:mm a";
 pub const DATETIME_MED: &str = "MMM d, yyyy, h:mm a";
 pub const DATETIME_FULL: &str = "MMMM d, yyyy, h:mm a";
+pub const DATE_MED_WITH_WEEKDAY: &str = "EEE, MMM d, yyyy";
+pub const DATE_HUGE: &str = "EEEE, MMMM d, yyyy";
+pub const TIME_WITH_SHORT_OFFSET: &str = "h:mm:ss a ZZZZ";
+pub const TIME_WITH_LONG_OFFSET: &str = "h:mm:ss a z";
+pub const TIME_24_SIMPLE: &str = "HH:mm";
+pub const TIME_24_WITH_SECONDS: &str = "HH:mm:ss";
+pub const TIME_24_WITH_SHORT_OFFSET: &str = "HH:mm:ss ZZZZ";
+pub const TIME_24_WITH_LONG_OFFSET: &str = "HH:mm:ss z";
+pub const DATETIME_MED_WITH_WEEKDAY: &str = "EEE, MMM d, yyyy, h:mm a";
+pub const DATETIME_HUGE: &str = "EEEE, MMMM d, yyyy 'at' h:mm a z";
+pub const DATETIME_SHORT_WITH_SECONDS: &str = "M/d/yyyy, h:mm:ss a";
+pub const DATETIME_MED_WITH_SECONDS: &str = "MMM d, yyyy, h:mm:ss a";
+pub const DATETIME_FULL_WITH_SECONDS: &str = "MMMM d, yyyy, h:mm:ss a";
+pub const DATETIME_HUGE_WITH_SECONDS: &str = "EEEE, MMMM d, yyyy 'at' h:mm:ss a z";
 
-#[cfg(feature = "chrono")]
-pub(crate) fn to_locale_string(dt: &chrono::DateTime<chrono::Utc>, preset: &str) -> String {
-    let format = match preset {
-        "DATE_SHORT" => DATE_SHORT,
-        "DATE_MED" => DATE_MED,
-        "DATE_FULL" => DATE_FULL,
-        "TIME_SIMPLE" => TIME_SIMPLE,
-        "TIME_WITH_SECONDS" => TIME_WITH_SECONDS,
-        "DATETIME_SHORT" => DATETIME_SHORT,
-        "DATETIME_MED" => DATETIME_MED,
-        "DATETIME_FULL" => DATETIME_FULL,
-        _ => preset,
+/// Names used when formatting: months, weekdays, the meridiem and day
+/// ordinals.
+///
+/// [`Locale::EN`] is always available. The `locales` feature adds German,
+/// Spanish, French, Italian, Portuguese, Dutch, Swedish, Norwegian, Danish,
+/// Polish, Turkish, Russian, Japanese and Chinese, and
+/// [`register`](Locale::register) adds your own. Pass a locale to
+/// [`to_format_with_locale`](crate::DateTime::to_format_with_locale).
+///
+/// # Examples
+///
+/// ```rust
+/// use tempotime::{DateTime, Locale};
+///
+/// const EO: Locale = Locale {
+///     code: "eo",
+///     months: [
+///         "januaro", "februaro", "marto", "aprilo", "majo", "junio",
+///         "julio", "aŭgusto", "septembro", "oktobro", "novembro", "decembro",
+///     ],
+///     months_short: [
+///         "jan", "feb", "mar", "apr", "maj", "jun", "jul", "aŭg", "sep", "okt", "nov", "dec",
+///     ],
+///     weekdays: ["lundo", "mardo", "merkredo", "ĵaŭdo", "vendredo", "sabato", "dimanĉo"],
+///     weekdays_short: ["lu", "ma", "me", "ĵa", "ve", "sa", "di"],
+///     meridiem: ["atm", "ptm"],
+///     ordinal_suffixes: ["-a", "-a", "-a", "-a"],
+/// };
+/// Locale::register(EO);
+///
+/// let dt = DateTime::from_iso("2025-10-30T14:30:00Z").unwrap();
+/// let eo = Locale::get("eo").unwrap();
+/// assert_eq!(dt.to_format_with_locale("EEEE, do 'de' MMMM", &eo), "ĵaŭdo, 30-a de oktobro");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Locale {
+    /// The language tag, such as `"en"` or `"pt-BR"`.
+    pub code: &'static str,
+    /// Month names for `MMMM`, January first.
+    pub months: [&'static str; 12],
+    /// Abbreviated month names for `MMM`.
+    pub months_short: [&'static str; 12],
+    /// Weekday names for `EEEE`, Monday first.
+    pub weekdays: [&'static str; 7],
+    /// Abbreviated weekday names for `EEE`.
+    pub weekdays_short: [&'static str; 7],
+    /// Before and after noon for `a`; `A` prints them in uppercase.
+    pub meridiem: [&'static str; 2],
+    /// Suffixes `do` appends to days ending in 1, 2, 3 and anything else, in
+    /// that order. Days 11 to 13 take the last one, as in English.
+    pub ordinal_suffixes: [&'static str; 4],
+}
+
+static REGISTERED: RwLock<Vec<Locale>> = RwLock::new(Vec::new());
+
+#[cfg(feature = "locales")]
+const BUILT_IN: &[Locale] = &[
+    Locale::EN,
+    Locale::DE,
+    Locale::ES,
+    Locale::FR,
+    Locale::IT,
+    Locale::PT,
+    Locale::NL,
+    Locale::SV,
+    Locale::NB,
+    Locale::DA,
+    Locale::PL,
+    Locale::TR,
+    Locale::RU,
+    Locale::JA,
+    Locale::ZH,
+];
+#[cfg(not(feature = "locales"))]
+const BUILT_IN: &[Locale] = &[Locale::EN];
+
+impl Locale {
+    /// English, the default for [`to_format`](crate::DateTime::to_format).
+    pub const EN: Locale = Locale {
+        code: "en",
+        months: [
+            "January",
+            "February",
+            "March",
+            "April",
+            "May",
+            "June",
+            "July",
+            "August",
+            "September",
+            "October",
+            "November",
+            "December",
+        ],
+        months_short: [
+            "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+        ],
+        weekdays: [
+            "Monday",
+            "Tuesday",
+            "Wednesday",
+            "Thursday",
+            "Friday",
+            "Saturday",
+            "Sunday",
+        ],
+        weekdays_short: ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"],
+        meridiem: ["am", "pm"],
+        ordinal_suffixes: ["st", "nd", "rd", "th"],
+    };
+
+    /// German.
+    #[cfg(feature = "locales")]
+    pub const DE: Locale = Locale {
+        code: "de",
+        months: [
+            "Januar",
+            "Februar",
+            "März",
+            "April",
+            "Mai",
+            "Juni",
+            "Juli",
+            "August",
+            "September",
+            "Oktober",
+            "November",
+            "Dezember",
+        ],
+        months_short: [
+            "Jan.", "Feb.", "März", "Apr.", "Mai", "Juni", "Juli", "Aug.", "Sept.", "Okt.", "Nov.",
+            "Dez.",
+        ],
+        weekdays: [
+            "Montag",
+            "Dienstag",
+            "Mittwoch",
+            "Donnerstag",
+            "Freitag",
+            "Samstag",
+            "Sonntag",
+        ],
+        weekdays_short: ["Mo.", "Di.", "Mi.", "Do.", "Fr.", "Sa.", "So."],
+        meridiem: ["AM", "PM"],
+        ordinal_suffixes: [".", ".", ".", "."],
+    };
+
+    /// Spanish.
+    #[cfg(feature = "locales")]
+    pub const ES: Locale = Locale {
+        code: "es",
+        months: [
+            "enero",
+            "febrero",
+            "marzo",
+            "abril",
+            "mayo",
+            "junio",
+            "julio",
+            "agosto",
+            "septiembre",
+            "octubre",
+            "noviembre",
+            "diciembre",
+        ],
+        months_short: [
+            "ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic",
+        ],
+        weekdays: [
+            "lunes",
+            "martes",
+            "miércoles",
+            "jueves",
+            "viernes",
+            "sábado",
+            "domingo",
+        ],
+        weekdays_short: ["lun", "mar", "mié", "jue", "vie", "sáb", "dom"],
+        meridiem: ["a. m.", "p. m."],
+        ordinal_suffixes: ["", "", "", ""],
+    };
+
+    /// French.
+    #[cfg(feature = "locales")]
+    pub const FR: Locale = Locale {
+        code: "fr",
+        months: [
+            "janvier",
+            "février",
+            "mars",
+            "avril",
+            "mai",
+            "juin",
+            "juillet",
+            "août",
+            "septembre",
+            "octobre",
+            "novembre",
+            "décembre",
+        ],
+        months_short: [
+            "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.",
+            "nov.", "déc.",
+        ],
+        weekdays: [
+            "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi", "dimanche",
+        ],
+        weekdays_short: ["lun.", "mar.", "mer.", "jeu.", "ven.", "sam.", "dim."],
+        meridiem: ["AM", "PM"],
+        ordinal_suffixes: ["", "", "", ""],
+    };
+
+    /// Italian.
+    #[cfg(feature = "locales")]
+    pub const IT: Locale = Locale {
+        code: "it",
+        months: [
+            "gennaio",
+            "febbraio",
+            "marzo",
+            "aprile",
+            "maggio",
+            "giugno",
+            "luglio",
+            "agosto",
+            "settembre",
+            "ottobre",
+            "novembre",
+            "dicembre",
+        ],
+        months_short: [
+            "gen", "feb", "mar", "apr", "mag", "giu", "lug", "ago", "set", "ott", "nov", "dic",
+        ],
+        weekdays: [
+            "lunedì",
+            "martedì",
+            "mercoledì",
+            "giovedì",
+            "venerdì",
+            "sabato",
+            "domenica",
+        ],
+        weekdays_short: ["lun", "mar", "mer", "gio", "ven", "sab", "dom"],
+        meridiem: ["AM", "PM"],
+        ordinal_suffixes: ["", "", "", ""],
+    };
+
+    /// Portuguese.
+    #[cfg(feature = "locales")]
+    pub const PT: Locale = Locale {
+        code: "pt",
+        months: [
+            "janeiro",
+            "fevereiro",
+            "março",
+            "abril",
+            "maio",
+            "junho",
+            "julho",
+            "agosto",
+            "setembro",
+            "outubro",
+            "novembro",
+            "dezembro",
+        ],
+        months_short: [
+            "jan.", "fev.", "mar.", "abr.", "mai.", "jun.", "jul.", "ago.", "set.", "out.", "nov.",
+            "dez.",
+        ],
+        weekdays: [
+            "segunda-feira",
+            "terça-feira",
+            "quarta-feira",
+            "quinta-feira",
+            "sexta-feira",
+            "sábado",
+            "domingo",
+        ],
+        weekdays_short: ["seg.", "ter.", "qua.", "qui.", "sex.", "sáb.", "dom."],
+        meridiem: ["AM", "PM"],
+        ordinal_suffixes: ["", "", "", ""],
+    };
+
+    /// Dutch.
+    #[cfg(feature = "locales")]
+    pub const NL: Locale = Locale {
+        code: "nl",
+        months: [
+            "januari",
+            "februari",
+            "maart",
+            "april",
+            "mei",
+            "juni",
+            "juli",
+            "augustus",
+            "september",
+            "oktober",
+            "november",
+            "december",
+        ],
+        months_short: [
+            "jan", "feb", "mrt", "apr", "mei", "jun", "jul", "aug", "sep", "okt", "nov", "dec",
+        ],
+        weekdays: [
+            "maandag",
+            "dinsdag",
+            "woensdag",
+            "donderdag",
+            "vrijdag",
+            "zaterdag",
+            "zondag",
+        ],
+        weekdays_short: ["ma", "di", "wo", "do", "vr", "za", "zo"],
+        meridiem: ["a.m.", "p.m."],
+        ordinal_suffixes: ["", "", "", ""],
+    };
+
+    /// Swedish.
+    #[cfg(feature = "locales")]
+    pub const SV: Locale = Locale {
+        code: "sv",
+        months: [
+            "januari",
+            "februari",
+            "mars",
+            "april",
+            "maj",
+            "juni",
+            "juli",
+            "augusti",
+            "september",
+            "oktober",
+            "november",
+            "december",
+        ],
+        months_short: [
+            "jan.", "feb.", "mars", "apr.", "maj", "juni", "juli", "aug.", "sep.", "okt.", "nov.",
+            "dec.",
+        ],
+        weekdays: [
+            "måndag", "tisdag", "onsdag", "torsdag", "fredag", "lördag", "söndag",
+        ],
+        weekdays_short: ["mån", "tis", "ons", "tors", "fre", "lör", "sön"],
+        meridiem: ["fm", "em"],
+        ordinal_suffixes: ["", "", "", ""],
+    };
+
+    /// Norwegian Bokmål.
+    #[cfg(feature = "locales")]
+    pub const NB: Locale = Locale {
+        code: "nb",
+        months: [
+            "januar",
+            "februar",
+            "mars",
+            "april",
+            "mai",
+            "juni",
+            "juli",
+            "august",
+            "september",
+            "oktober",
+            "november",
+            "desember",
+        ],
+        months_short: [
+            "jan.", "feb.", "mar.", "apr.", "mai", "jun.", "jul.", "aug.", "sep.", "okt.", "nov.",
+            "des.",
+        ],
+        weekdays: [
+            "mandag", "tirsdag", "onsdag", "torsdag", "fredag", "lørdag", "søndag",
+        ],
+        weekdays_short: ["man.", "tir.", "ons.", "tor.", "fre.", "lør.", "søn."],
+        meridiem: ["a.m.", "p.m."],
+        ordinal_suffixes: [".", ".", ".", "."],
+    };
+
+    /// Danish.
+    #[cfg(feature = "locales")]
+    pub const DA: Locale = Locale {
+        code: "da",
+        months: [
+            "januar",
+            "februar",
+            "marts",
+            "april",
+            "maj",
+            "juni",
+            "juli",
+            "august",
+            "september",
+            "oktober",
+            "november",
+            "december",
+        ],
+        months_short: [
+            "jan.", "feb.", "mar.", "apr.", "maj", "jun.", "jul.", "aug.", "sep.", "okt.", "nov.",
+            "dec.",
+        ],
+        weekdays: [
+            "mandag", "tirsdag", "onsdag", "torsdag", "fredag", "lørdag", "søndag",
+        ],
+        weekdays_short: ["man.", "tirs.", "ons.", "tors.", "fre.", "lør.", "søn."],
+        meridiem: ["AM", "PM"],
+        ordinal_suffixes: [".", ".", ".", "."],
+    };
+
+    /// Polish, with month names in the genitive as used after a day.
+    #[cfg(feature = "locales")]
+    pub const PL: Locale = Locale {
+        code: "pl",
+        months: [
+            "stycznia",
+            "lutego",
+            "marca",
+            "kwietnia",
+            "maja",
+            "czerwca",
+            "lipca",
+            "sierpnia",
+            "września",
+            "października",
+            "listopada",
+            "grudnia",
+        ],
+        months_short: [
+            "sty", "lut", "mar", "kwi", "maj", "cze", "lip", "sie", "wrz", "paź", "lis", "gru",
+        ],
+        weekdays: [
+            "poniedziałek",
+            "wtorek",
+            "środa",
+            "czwartek",
+            "piątek",
+            "sobota",
+            "niedziela",
+        ],
+        weekdays_short: ["pon.", "wt.", "śr.", "czw.", "pt.", "sob.", "niedz."],
+        meridiem: ["AM", "PM"],
+        ordinal_suffixes: ["", "", "", ""],
+    };
+
+    /// Turkish.
+    #[cfg(feature = "locales")]
+    pub const TR: Locale = Locale {
+        code: "tr",
+        months: [
+            "Ocak", "Şubat", "Mart", "Nisan", "Mayıs", "Haziran", "Temmuz", "Ağustos", "Eylül",
+            "Ekim", "Kasım", "Aralık",
+        ],
+        months_short: [
+            "Oca", "Şub", "Mar", "Nis", "May", "Haz", "Tem", "Ağu", "Eyl", "Eki", "Kas", "Ara",
+        ],
+        weekdays: [
+            "Pazartesi",
+            "Salı",
+            "Çarşamba",
+            "Perşembe",
+            "Cuma",
+            "Cumartesi",
+            "Pazar",
+        ],
+        weekdays_short: ["Pzt", "Sal", "Çar", "Per", "Cum", "Cmt", "Paz"],
+        meridiem: ["ÖÖ", "ÖS"],
+        ordinal_suffixes: ["", "", "", ""],
     };
-    format_datetime(dt, format)
+
+    /// Russian, with month names in the genitive as used after a day.
+    #[cfg(feature = "locales")]
+    pub const RU: Locale = Locale {
+        code: "ru",
+        months: [
+            "января",
+            "февраля",
+            "марта",
+            "апреля",
+            "мая",
+            "июня",
+            "июля",
+            "августа",
+            "сентября",
+            "октября",
+            "ноября",
+            "декабря",
+        ],
+        months_short: [
+            "янв.",
+            "февр.",
+            "мар.",
+            "апр.",
+            "мая",
+            "июн.",
+            "июл.",
+            "авг.",
+            "сент.",
+            "окт.",
+            "нояб.",
+            "дек.",
+        ],
+        weekdays: [
+            "понедельник",
+            "вторник",
+            "среда",
+            "четверг",
+            "пятница",
+            "суббота",
+            "воскресенье",
+        ],
+        weekdays_short: ["пн", "вт", "ср", "чт", "пт", "сб", "вс"],
+        meridiem: ["AM", "PM"],
+        ordinal_suffixes: ["", "", "", ""],
+    };
+
+    /// Japanese.
+    #[cfg(feature = "locales")]
+    pub const JA: Locale = Locale {
+        code: "ja",
+        months: [
+            "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+        ],
+        months_short: [
+            "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+        ],
+        weekdays: [
+            "月曜日",
+            "火曜日",
+            "水曜日",
+            "木曜日",
+            "金曜日",
+            "土曜日",
+            "日曜日",
+        ],
+        weekdays_short: ["月", "火", "水", "木", "金", "土", "日"],
+        meridiem: ["午前", "午後"],
+        ordinal_suffixes: ["", "", "", ""],
+    };
+
+    /// Chinese (Simplified).
+    #[cfg(feature = "locales")]
+    pub const ZH: Locale = Locale {
+        code: "zh",
+        months: [
+            "一月",
+            "二月",
+            "三月",
+            "四月",
+            "五月",
+            "六月",
+            "七月",
+            "八月",
+            "九月",
+            "十月",
+            "十一月",
+            "十二月",
+        ],
+        months_short: [
+            "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+        ],
+        weekdays: [
+            "星期一",
+            "星期二",
+            "星期三",
+            "星期四",
+            "星期五",
+            "星期六",
+            "星期日",
+        ],
+        weekdays_short: ["周一", "周二", "周三", "周四", "周五", "周六", "周日"],
+        meridiem: ["上午", "下午"],
+        ordinal_suffixes: ["", "", "", ""],
+    };
+
+    /// Looks up a registered or built-in locale by language tag, ignoring case.
+    ///
+    /// A tag with a region such as `de-AT` falls back to its language (`de`)
+    /// when there is no exact match. Registered locales take precedence over
+    /// built-in ones.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::Locale;
+    ///
+    /// assert_eq!(Locale::get("en-GB"), Some(Locale::EN));
+    /// assert_eq!(Locale::get("tlh"), None);
+    /// ```
+    pub fn get(code: &str) -> Option<Locale> {
+        let language = code.split(['-', '_']).next().unwrap_or(code);
+        let registered = REGISTERED.read().unwrap_or_else(|e| e.into_inner());
+        [code, language].into_iter().find_map(|tag| {
+            registered
+                .iter()
+                .rev()
+                .chain(BUILT_IN)
+                .find(|locale| locale.code.eq_ignore_ascii_case(tag))
+                .copied()
+        })
+    }
+
+    /// Makes `locale` available to [`get`](Locale::get) for the rest of the
+    /// process, replacing any earlier registration with the same code.
+    pub fn register(locale: Locale) {
+        let mut registered = REGISTERED.write().unwrap_or_else(|e| e.into_inner());
+        registered.retain(|existing| !existing.code.eq_ignore_ascii_case(locale.code));
+        registered.push(locale);
+    }
+
+    pub(crate) fn ordinal_suffix(&self, day: u32) -> &'static str {
+        match day {
+            1 | 21 | 31 => self.ordinal_suffixes[0],
+            2 | 22 => self.ordinal_suffixes[1],
+            3 | 23 => self.ordinal_suffixes[2],
+            _ => self.ordinal_suffixes[3],
+        }
+    }
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Locale::EN
+    }
 }
 
-#[cfg(not(feature = "chrono"))]
-pub(crate) fn to_locale_string_from_ts(ts_ms: i64, preset: &str) -> String {
-    use crate::format::format_datetime_from_ts;
-    let format = match preset {
+// The pattern for a preset given by name ("DATE_SHORT"); anything else is
+// already a pattern.
+pub(crate) fn preset_pattern(preset: &str) -> &str {
+    match preset {
         "DATE_SHORT" => DATE_SHORT,
         "DATE_MED" => DATE_MED,
+        "DATE_MED_WITH_WEEKDAY" => DATE_MED_WITH_WEEKDAY,
         "DATE_FULL" => DATE_FULL,
+        "DATE_HUGE" => DATE_HUGE,
         "TIME_SIMPLE" => TIME_SIMPLE,
         "TIME_WITH_SECONDS" => TIME_WITH_SECONDS,
+        "TIME_WITH_SHORT_OFFSET" => TIME_WITH_SHORT_OFFSET,
+        "TIME_WITH_LONG_OFFSET" => TIME_WITH_LONG_OFFSET,
+        "TIME_24_SIMPLE" => TIME_24_SIMPLE,
+        "TIME_24_WITH_SECONDS" => TIME_24_WITH_SECONDS,
+        "TIME_24_WITH_SHORT_OFFSET" => TIME_24_WITH_SHORT_OFFSET,
+        "TIME_24_WITH_LONG_OFFSET" => TIME_24_WITH_LONG_OFFSET,
         "DATETIME_SHORT" => DATETIME_SHORT,
         "DATETIME_MED" => DATETIME_MED,
+        "DATETIME_MED_WITH_WEEKDAY" => DATETIME_MED_WITH_WEEKDAY,
         "DATETIME_FULL" => DATETIME_FULL,
+        "DATETIME_HUGE" => DATETIME_HUGE,
+        "DATETIME_SHORT_WITH_SECONDS" => DATETIME_SHORT_WITH_SECONDS,
+        "DATETIME_MED_WITH_SECONDS" => DATETIME_MED_WITH_SECONDS,
+        "DATETIME_FULL_WITH_SECONDS" => DATETIME_FULL_WITH_SECONDS,
+        "DATETIME_HUGE_WITH_SECONDS" => DATETIME_HUGE_WITH_SECONDS,
         _ => preset,
+    }
+}
+
+// The pattern `DateTime::to_locale` renders for `options`, in the en-US
+// order that `Intl.DateTimeFormat` uses.
+pub(crate) fn options_pattern(options: &LocaleOptions) -> String {
+    let date = match options.date_style {
+        Some(LocaleStyle::Short) => "M/d/yy",
+        Some(LocaleStyle::Medium) => "MMM d, yyyy",
+        Some(LocaleStyle::Long) => "MMMM d, yyyy",
+        Some(LocaleStyle::Full) => "EEEE, MMMM d, yyyy",
+        None if options.time_style.is_none() => "M/d/yy",
+        None => "",
+    };
+    let clock = match options.hour_cycle {
+        HourCycle::H12 => ["h:mm a", "h:mm:ss a"],
+        HourCycle::H23 => ["HH:mm", "HH:mm:ss"],
     };
-    format_datetime_from_ts(ts_ms, format)
+    let time = match options.time_style {
+        Some(LocaleStyle::Short) => clock[0].to_string(),
+        Some(LocaleStyle::Medium) => clock[1].to_string(),
+        Some(LocaleStyle::Long) => format!("{} ZZZZ", clock[1]),
+        Some(LocaleStyle::Full) => format!("{} z", clock[1]),
+        None => return date.to_string(),
+    };
+    match options.date_style {
+        None => time,
+        Some(LocaleStyle::Long | LocaleStyle::Full) => format!("{} 'at' {}", date, time),
+        Some(_) => format!("{}, {}", date, time),
+    }
 }
 
-#[cfg(all(test, feature = "chrono"))]
+#[cfg(test)]
 mod tests {
-    use super::*;
-    use chrono::TimeZone;
-
     #[test]
     fn test_presets() {
-        let dt = chrono::Utc
-            .with_ymd_and_hms(2025, 10, 29, 14, 30, 0)
-            .unwrap();
-        assert_eq!(to_locale_string(&dt, "DATE_SHORT"), "10/29/2025");
-        assert_eq!(to_locale_string(&dt, "DATE_MED"), "Oct 29, 2025");
-        assert_eq!(to_locale_string(&dt, "DATE_FULL"), "October 29, 2025");
-        assert_eq!(to_locale_string(&dt, "TIME_SIMPLE"), "2:30 pm");
+        let dt = crate::DateTime::from_iso("2025-10-29T14:30:00Z").unwrap();
+        let to_locale_string = |preset| dt.to_locale_string(preset);
+        assert_eq!(to_locale_string("DATE_SHORT"), "10/29/2025");
+        assert_eq!(to_locale_string("DATE_MED"), "Oct 29, 2025");
+        assert_eq!(to_locale_string("DATE_FULL"), "October 29, 2025");
+        assert_eq!(to_locale_string("TIME_SIMPLE"), "2:30 pm");
+        assert_eq!(to_locale_string("TIME_24_WITH_SECONDS"), "14:30:00");
+        assert_eq!(
+            to_locale_string("DATETIME_HUGE"),
+            "Wednesday, October 29, 2025 at 2:30 pm UTC"
+        );
     }
 }
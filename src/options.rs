@@ -0,0 +1,187 @@
+// Option structs taken by the more configurable DateTime methods. They are
+// plain data with public fields and a `Default`, so callers can use struct
+// update syntax: `SetOptions { overflow: Overflow::Reject, ..Default::default() }`.
+
+use crate::locale::Locale;
+
+/// What to do when a day of the month does not exist in the target month, for
+/// example setting the month to February on January 31st.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Overflow {
+    /// Move the day back to the last day of the month (Luxon's behaviour).
+    #[default]
+    Clamp,
+    /// Fail with an error.
+    Reject,
+}
+
+/// How to read a wall-clock time that a DST change makes ambiguous (it
+/// happens twice, when clocks go back) or nonexistent (it is skipped, when
+/// clocks go forward).
+///
+/// In New York, 01:30 on 2 November 2025 happens first in EDT and again in
+/// EST, and 02:30 on 9 March 2025 never happens at all.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Disambiguation {
+    /// The earlier instant: the first 01:30, and 01:30 EST for the skipped
+    /// 02:30.
+    Earlier,
+    /// The later instant: the second 01:30, and 03:30 EDT for the skipped
+    /// 02:30.
+    Later,
+    /// The earlier instant for an ambiguous time, and a skipped one moved
+    /// forward by the length of the gap (02:30 becomes 03:30), as in Luxon.
+    #[default]
+    Shift,
+    /// Fail with [`Error::AmbiguousLocalTime`](crate::Error::AmbiguousLocalTime)
+    /// or [`Error::NonexistentLocalTime`](crate::Error::NonexistentLocalTime).
+    Reject,
+}
+
+/// Options for [`DateTime::set_with`](crate::DateTime::set_with).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct SetOptions {
+    /// Handling of days past the end of the resulting month.
+    pub overflow: Overflow,
+    /// Handling of wall-clock times repeated or skipped by a DST change.
+    pub disambiguation: Disambiguation,
+}
+
+/// Options for [`DateTime::set_zone_with`](crate::DateTime::set_zone_with).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct SetZoneOptions {
+    /// Keep the wall-clock time and move the instant instead, like Luxon's
+    /// `keepLocalTime`: 09:00 in New York becomes 09:00 in Tokyo. When `false`
+    /// (the default) the instant is kept, as in
+    /// [`set_zone`](crate::DateTime::set_zone).
+    pub keep_local_time: bool,
+}
+
+/// Options for [`DateTime::from_format_with`](crate::DateTime::from_format_with).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ParseOptions {
+    /// Check that a weekday read with `E`, `EEE` or `EEEE` falls on the parsed
+    /// date. When `false` (the default) the weekday is read and ignored.
+    pub validate_weekday: bool,
+    /// Accept messy input: surrounding whitespace is ignored, a run of
+    /// whitespace in the format matches any run of whitespace in the input, and
+    /// numeric fields take one or two digits whatever the token width, so
+    /// `"Oct  5 2025  7:09:03"` matches `"MMM dd yyyy HH:mm:ss"`.
+    pub lenient: bool,
+    /// Where `yy` switches centuries: two-digit years at or above the cutoff
+    /// are read as 19xx and those below as 20xx, so with `Some(70)` "85" is
+    /// 1985 and "25" is 2025. `None` (the default) reads every `yy` as 20xx.
+    pub two_digit_year_cutoff: Option<u32>,
+}
+
+/// Options for [`DateTime::to_relative_with`](crate::DateTime::to_relative_with).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct RelativeOptions {
+    /// Round the count to the nearest whole unit. When `false` (the default)
+    /// it is truncated toward zero as in Luxon, so 1 day and 20 hours ago is
+    /// "1 day ago" rather than "2 days ago".
+    pub round: bool,
+    /// The largest unit to use, such as `"days"` for "46 days ago" instead of
+    /// "1 month ago". Singular and plural names are accepted; `None` (the
+    /// default) or an unknown name allows everything up to years.
+    pub max_unit: Option<&'static str>,
+}
+
+/// How much detail [`DateTime::to_locale`](crate::DateTime::to_locale) gives
+/// the date or the time, after `Intl.DateTimeFormat`'s `dateStyle` and
+/// `timeStyle`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LocaleStyle {
+    /// `10/30/25` or `2:30 pm`.
+    Short,
+    /// `Oct 30, 2025` or `2:30:05 pm`.
+    Medium,
+    /// `October 30, 2025` or `2:30:05 pm EDT`.
+    Long,
+    /// `Thursday, October 30, 2025` or `2:30:05 pm America/New_York`.
+    Full,
+}
+
+/// Whether times use a 12-hour clock with a meridiem or a 24-hour clock.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum HourCycle {
+    /// `2:30 pm`, midnight as 12.
+    #[default]
+    H12,
+    /// `14:30`, midnight as 00.
+    H23,
+}
+
+/// Options for [`DateTime::to_locale`](crate::DateTime::to_locale).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct LocaleOptions {
+    /// Detail for the date; `None` leaves it out. With neither a date nor a
+    /// time style (the default) the date is printed in the short style.
+    pub date_style: Option<LocaleStyle>,
+    /// Detail for the time; `None` (the default) leaves it out.
+    pub time_style: Option<LocaleStyle>,
+    /// The clock for the time.
+    pub hour_cycle: HourCycle,
+    /// Month, weekday and meridiem names. `None` (the default) is English.
+    pub locale: Option<Locale>,
+}
+
+/// Date and time layout for [`DateTime::to_iso_with`](crate::DateTime::to_iso_with).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum IsoFormat {
+    /// `2025-10-30T14:30:00.000+01:00`.
+    #[default]
+    Extended,
+    /// `20251030T143000.000+0100`, without separators.
+    Basic,
+}
+
+/// How [`DateTime::to_iso_with`](crate::DateTime::to_iso_with) writes the
+/// UTC offset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum IsoOffset {
+    /// `Z` for UTC, otherwise `+01:00`.
+    #[default]
+    Z,
+    /// Always numeric, `+00:00` for UTC.
+    Numeric,
+    /// No offset at all, for local date-times.
+    Omit,
+}
+
+/// Options for [`DateTime::to_iso_with`](crate::DateTime::to_iso_with).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct IsoOptions {
+    /// Leave out `.SSS` when the milliseconds are zero. By default they are
+    /// always written.
+    pub suppress_milliseconds: bool,
+    /// Leave out the seconds, and the milliseconds, when both are zero.
+    pub suppress_seconds: bool,
+    /// How to write the offset.
+    pub offset: IsoOffset,
+    /// Extended (with separators, the default) or basic format.
+    pub format: IsoFormat,
+}
+
+/// Options for [`Duration::to_human_with`](crate::Duration::to_human_with).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct HumanOptions {
+    /// Show at most this many of the largest non-zero units; the rest are
+    /// dropped, not rounded. `None` (the default) shows them all.
+    pub max_units: Option<usize>,
+    /// Abbreviate units and drop the commas: `2h 5m` instead of
+    /// `2 hours, 5 minutes`.
+    pub compact: bool,
+}
+
+/// Which ends of an interval count as inside it, for
+/// [`Interval::contains_with`](crate::Interval::contains_with).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Bounds {
+    /// Both ends included, `[start, end]`, as [`Interval::contains`](crate::Interval::contains).
+    #[default]
+    Closed,
+    /// The start included and the end excluded, `[start, end)`, as in Luxon,
+    /// so back-to-back intervals never both contain their shared instant.
+    HalfOpen,
+}
@@ -0,0 +1,257 @@
+// Pluggable timezone rules. A provider installed with
+// `Settings::set_zone_provider` is asked about zone names before the built-in
+// lookup, and the zones it resolves carry a reference back to it so their
+// offsets keep working after the provider is replaced.
+
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use std::borrow::Cow;
+use std::panic::RefUnwindSafe;
+
+/// A source of timezone rules: zone names, UTC offsets and their transitions.
+///
+/// Install one with [`Settings::set_zone_provider`](crate::Settings::set_zone_provider)
+/// and [`DateTime::set_zone`](crate::DateTime::set_zone) looks names up in it
+/// first, falling back to the built-in zones and fixed offsets. A zone is
+/// identified by an id chosen by the provider, so a `DateTime` can stay `Copy`
+/// while pointing into the provider's data.
+///
+/// Only [`zone_id`](Self::zone_id), [`zone_name`](Self::zone_name) and
+/// [`offset_at`](Self::offset_at) are required. The transition queries
+/// default to searching `offset_at` over the following or preceding two
+/// years; providers with a transition table should override them.
+///
+/// Built-in providers are [`StaticProvider`], [`TzfileProvider`](crate::TzfileProvider)
+/// and, with the `tz` feature, [`ChronoTzProvider`].
+///
+/// Zones hold a reference to their provider, so the bounds on this trait
+/// keep `DateTime` `Send`, `Sync` and unwind-safe.
+///
+/// # Examples
+///
+/// ```rust
+/// use tempotime::{DateTime, Settings, TimeZoneProvider};
+///
+/// // Company offices on fixed offsets.
+/// struct Offices;
+///
+/// const OFFICES: [(&str, i32); 2] = [("Office/Berlin", 3600), ("Office/Pune", 19800)];
+///
+/// impl TimeZoneProvider for Offices {
+///     fn zone_id(&self, name: &str) -> Option<usize> {
+///         OFFICES.iter().position(|(office, _)| *office == name)
+///     }
+///     fn zone_name(&self, id: usize) -> &str {
+///         OFFICES[id].0
+///     }
+///     fn offset_at(&self, id: usize, _instant_ms: i64) -> i32 {
+///         OFFICES[id].1
+///     }
+/// }
+///
+/// static PROVIDER: Offices = Offices;
+/// Settings::set_zone_provider(&PROVIDER);
+///
+/// let dt = DateTime::from_iso("2025-10-30T12:00:00Z").unwrap().set_zone("Office/Pune");
+/// assert_eq!(dt.zone_name(), Some("Office/Pune"));
+/// assert_eq!(dt.to_format("HH:mm"), "17:30");
+///
+/// Settings::reset_zone_provider();
+/// ```
+pub trait TimeZoneProvider: Send + Sync + RefUnwindSafe {
+    /// The id of the zone called `name`, or `None` if this provider does not
+    /// know it.
+    fn zone_id(&self, name: &str) -> Option<usize>;
+
+    /// The canonical name of the zone with this id.
+    fn zone_name(&self, id: usize) -> &str;
+
+    /// Seconds east of UTC in the zone at `instant_ms`, in milliseconds since
+    /// the Unix epoch.
+    fn offset_at(&self, id: usize, instant_ms: i64) -> i32;
+
+    /// Whether daylight saving time is in effect in the zone at `instant_ms`.
+    /// Defaults to `false`.
+    fn is_dst(&self, id: usize, instant_ms: i64) -> bool {
+        let _ = (id, instant_ms);
+        false
+    }
+
+    /// The abbreviation in use in the zone at `instant_ms`, such as `EST`, for
+    /// the `ZZZZ` format token. Defaults to `None`, which formats as
+    /// `UTC±H`.
+    fn abbreviation(&self, id: usize, instant_ms: i64) -> Option<Cow<'_, str>> {
+        let _ = (id, instant_ms);
+        None
+    }
+
+    /// The first instant after `instant_ms` at which the zone's offset
+    /// changes, if any.
+    fn next_transition(&self, id: usize, instant_ms: i64) -> Option<i64> {
+        scan_transition(|at| self.offset_at(id, at), instant_ms, true)
+    }
+
+    /// The last instant at or before `instant_ms` at which the zone's offset
+    /// changed, if any.
+    fn previous_transition(&self, id: usize, instant_ms: i64) -> Option<i64> {
+        scan_transition(|at| self.offset_at(id, at), instant_ms, false)
+    }
+}
+
+// How far `scan_transition` looks for an offset change. Zones observing DST
+// change at least twice a year; two years covers those with a long gap.
+const TRANSITION_HORIZON_DAYS: u32 = 731;
+
+// The first instant after `instant_ms` (or the last at or before it when
+// searching backwards) at which `offset_at` changes. It steps a day at a time
+// for up to `TRANSITION_HORIZON_DAYS` and then bisects to the millisecond.
+// Two changes inside a single day cancel out and are missed.
+pub(crate) fn scan_transition(
+    offset_at: impl Fn(i64) -> i32,
+    instant_ms: i64,
+    forward: bool,
+) -> Option<i64> {
+    const DAY_MS: i64 = 86_400_000;
+    let step = if forward { DAY_MS } else { -DAY_MS };
+    let mut near = instant_ms;
+    for _ in 0..TRANSITION_HORIZON_DAYS {
+        let far = near.checked_add(step)?;
+        if offset_at(far) != offset_at(near) {
+            let (mut lo, mut hi) = if forward { (near, far) } else { (far, near) };
+            // Invariant: the offset at `lo` differs from the one at `hi`.
+            let before = offset_at(lo);
+            while hi - lo > 1 {
+                let mid = lo + (hi - lo) / 2;
+                if offset_at(mid) == before {
+                    lo = mid;
+                } else {
+                    hi = mid;
+                }
+            }
+            return Some(hi);
+        }
+        near = far;
+    }
+    None
+}
+
+// A zone resolved by a provider: the provider itself and the id it gave.
+// Equality and hashing go by the provider's address, so the same name looked
+// up in two providers gives two different zones.
+#[derive(Clone, Copy)]
+pub(crate) struct ProvidedZone {
+    pub(crate) provider: &'static dyn TimeZoneProvider,
+    pub(crate) id: usize,
+}
+
+impl ProvidedZone {
+    pub(crate) fn name(&self) -> &'static str {
+        self.provider.zone_name(self.id)
+    }
+}
+
+impl PartialEq for ProvidedZone {
+    fn eq(&self, other: &Self) -> bool {
+        core::ptr::addr_eq(self.provider, other.provider) && self.id == other.id
+    }
+}
+
+impl Eq for ProvidedZone {}
+
+impl Hash for ProvidedZone {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (self.provider as *const dyn TimeZoneProvider)
+            .cast::<()>()
+            .hash(state);
+        self.id.hash(state);
+    }
+}
+
+impl fmt::Debug for ProvidedZone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ProvidedZone").field(&self.name()).finish()
+    }
+}
+
+/// The small built-in table of common zones used without the `tz` feature:
+/// fixed offsets, no DST.
+///
+/// Names are matched without regard to case.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StaticProvider;
+
+impl TimeZoneProvider for StaticProvider {
+    fn zone_id(&self, name: &str) -> Option<usize> {
+        crate::zone::STATIC_ZONES
+            .iter()
+            .position(|(zone, ..)| name.eq_ignore_ascii_case(zone))
+    }
+
+    fn zone_name(&self, id: usize) -> &str {
+        crate::zone::STATIC_ZONES[id].0
+    }
+
+    fn offset_at(&self, id: usize, _instant_ms: i64) -> i32 {
+        crate::zone::STATIC_ZONES[id].1
+    }
+
+    fn abbreviation(&self, id: usize, _instant_ms: i64) -> Option<Cow<'_, str>> {
+        Some(Cow::Borrowed(crate::zone::STATIC_ZONES[id].2))
+    }
+
+    fn next_transition(&self, _id: usize, _instant_ms: i64) -> Option<i64> {
+        None
+    }
+
+    fn previous_transition(&self, _id: usize, _instant_ms: i64) -> Option<i64> {
+        None
+    }
+}
+
+/// The IANA database compiled into `chrono-tz`, as used by default with the
+/// `tz` feature. Ids index `chrono_tz::TZ_VARIANTS`.
+#[cfg(feature = "tz")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ChronoTzProvider;
+
+#[cfg(feature = "tz")]
+impl ChronoTzProvider {
+    fn offset(id: usize, instant_ms: i64) -> <chrono_tz::Tz as chrono::TimeZone>::Offset {
+        use chrono::TimeZone;
+        let utc = chrono::DateTime::from_timestamp_millis(instant_ms)
+            .unwrap_or_default()
+            .naive_utc();
+        chrono_tz::TZ_VARIANTS[id].offset_from_utc_datetime(&utc)
+    }
+}
+
+#[cfg(feature = "tz")]
+impl TimeZoneProvider for ChronoTzProvider {
+    fn zone_id(&self, name: &str) -> Option<usize> {
+        let tz = name.parse::<chrono_tz::Tz>().ok()?;
+        chrono_tz::TZ_VARIANTS
+            .iter()
+            .position(|&variant| variant == tz)
+    }
+
+    fn zone_name(&self, id: usize) -> &str {
+        chrono_tz::TZ_VARIANTS[id].name()
+    }
+
+    fn offset_at(&self, id: usize, instant_ms: i64) -> i32 {
+        use chrono::Offset;
+        Self::offset(id, instant_ms).fix().local_minus_utc()
+    }
+
+    fn is_dst(&self, id: usize, instant_ms: i64) -> bool {
+        use chrono_tz::OffsetComponents;
+        !Self::offset(id, instant_ms).dst_offset().is_zero()
+    }
+
+    fn abbreviation(&self, id: usize, instant_ms: i64) -> Option<Cow<'_, str>> {
+        use chrono_tz::OffsetName;
+        Some(Cow::Owned(
+            Self::offset(id, instant_ms).abbreviation().to_string(),
+        ))
+    }
+}
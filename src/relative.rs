@@ -0,0 +1,123 @@
+// English descriptions of one DateTime relative to another, for
+// `DateTime::to_relative`: "3 days ago", "in 2 hours", "just now".
+//
+// As in Luxon, the largest unit with a count of at least one is used. Years
+// and months are counted on the calendar in the base's zone, so January 31st
+// to February 28th is one month; shorter units are elapsed time.
+//
+// `to_relative_calendar` instead compares calendar years, months and days
+// ("yesterday", "next month"), ignoring the time of day.
+
+use crate::calendar::days_from_civil;
+use crate::options::RelativeOptions;
+use crate::DateTime;
+
+// Largest first, as (plural, singular).
+const UNITS: [(&str, &str); 7] = [
+    ("years", "year"),
+    ("months", "month"),
+    ("weeks", "week"),
+    ("days", "day"),
+    ("hours", "hour"),
+    ("minutes", "minute"),
+    ("seconds", "second"),
+];
+
+pub(crate) fn relative(dt: &DateTime, base: &DateTime, options: RelativeOptions) -> String {
+    let first = options
+        .max_unit
+        .and_then(|max| {
+            UNITS
+                .iter()
+                .position(|(plural, singular)| max == *plural || max == *singular)
+        })
+        .unwrap_or(0);
+    for &(plural, singular) in &UNITS[first..] {
+        let count = count(dt, base, plural);
+        if count.abs() < 1.0 {
+            continue;
+        }
+        let whole = if options.round {
+            count.round()
+        } else {
+            count.trunc()
+        } as i64;
+        return numeric(whole, singular, plural);
+    }
+    "just now".to_string()
+}
+
+pub(crate) fn calendar(dt: &DateTime, base: &DateTime) -> String {
+    let local = dt.with_zone_of(base);
+    let years = local.year() as i64 - base.year() as i64;
+    let months = years * 12 + local.month() as i64 - base.month() as i64;
+    let days = days_from_civil(local.year(), local.month(), local.day())
+        - days_from_civil(base.year(), base.month(), base.day());
+    match (years, months, days) {
+        (0, 0, 0) => "today".to_string(),
+        (0, 0, 1) => "tomorrow".to_string(),
+        (0, 0, -1) => "yesterday".to_string(),
+        (0, 0, n) => numeric(n, "day", "days"),
+        (0, 1, _) => "next month".to_string(),
+        (0, -1, _) => "last month".to_string(),
+        (0, n, _) => numeric(n, "month", "months"),
+        (1, ..) => "next year".to_string(),
+        (-1, ..) => "last year".to_string(),
+        (n, ..) => numeric(n, "year", "years"),
+    }
+}
+
+fn numeric(n: i64, singular: &str, plural: &str) -> String {
+    let unit = if n.abs() == 1 { singular } else { plural };
+    if n < 0 {
+        format!("{} {} ago", -n, unit)
+    } else {
+        format!("in {} {}", n, unit)
+    }
+}
+
+// `dt - base` in `unit`, with a fractional part.
+fn count(dt: &DateTime, base: &DateTime, unit: &str) -> f64 {
+    match unit {
+        "years" => months(dt, base) / 12.0,
+        "months" => months(dt, base),
+        _ => dt.diff(base, unit),
+    }
+}
+
+// Calendar months from `base` to `dt`: whole months by the calendar, plus the
+// elapsed fraction of the next one.
+fn months(dt: &DateTime, base: &DateTime) -> f64 {
+    let local = dt.with_zone_of(base);
+    let mut whole = (local.year() as i64 - base.year() as i64) * 12 + local.month() as i64
+        - base.month() as i64;
+    let Some(mut from) = shift_months(base, whole) else {
+        return whole as f64;
+    };
+    // The day or time of day may not have been reached yet.
+    if whole > 0 && from > *dt {
+        whole -= 1;
+    } else if whole < 0 && from < *dt {
+        whole += 1;
+    }
+    from = shift_months(base, whole).unwrap_or(from);
+    let step = if dt >= &from { 1 } else { -1 };
+    match shift_months(base, whole + step) {
+        Some(next) if next != from => {
+            whole as f64
+                + step as f64 * dt.diff(&from, "milliseconds") / next.diff(&from, "milliseconds")
+        }
+        _ => whole as f64,
+    }
+}
+
+// The same day and time `n` months from `dt`, clamped to the end of shorter
+// months, in `dt`'s zone.
+fn shift_months(dt: &DateTime, n: i64) -> Option<DateTime> {
+    let total = dt.year() as i64 * 12 + dt.month() as i64 - 1 + n;
+    dt.set(&[
+        ("year", total.div_euclid(12)),
+        ("month", total.rem_euclid(12) + 1),
+    ])
+    .ok()
+}
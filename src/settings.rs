@@ -0,0 +1,155 @@
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::RwLock;
+
+use crate::calendar::{WeekRule, Weekend};
+use crate::provider::TimeZoneProvider;
+
+static WEEKEND: AtomicU8 = AtomicU8::new(Weekend::SATURDAY_SUNDAY.to_bits());
+static WEEK_RULE: RwLock<WeekRule> = RwLock::new(WeekRule::ISO);
+static CLOCK: RwLock<Option<fn() -> i64>> = RwLock::new(None);
+static ZONE_PROVIDER: RwLock<Option<&'static dyn TimeZoneProvider>> = RwLock::new(None);
+// Zone name, standard abbreviation, daylight abbreviation.
+static ZONE_ABBREVIATIONS: RwLock<Vec<(String, String, String)>> = RwLock::new(Vec::new());
+
+/// Process-wide defaults, modelled on Luxon's `Settings`.
+///
+/// Settings are global and apply to every `DateTime` in the process, so they are
+/// meant to be configured once at startup rather than toggled per call.
+///
+/// # Examples
+///
+/// ```rust
+/// use tempotime::{DateTime, Settings, Weekend};
+///
+/// Settings::set_weekend(Weekend::FRIDAY_SATURDAY);
+///
+/// // 2025-10-31 is a Friday
+/// let friday = DateTime::from_iso("2025-10-31T12:00:00Z").unwrap();
+/// assert!(friday.is_weekend());
+///
+/// Settings::set_weekend(Weekend::default());
+/// ```
+pub struct Settings;
+
+impl Settings {
+    /// The weekend definition used by [`DateTime::is_weekend`](crate::DateTime::is_weekend).
+    pub fn weekend() -> Weekend {
+        Weekend::from_bits(WEEKEND.load(Ordering::Relaxed))
+    }
+
+    /// Replaces the weekend definition for the whole process.
+    pub fn set_weekend(weekend: Weekend) {
+        WEEKEND.store(weekend.to_bits(), Ordering::Relaxed);
+    }
+
+    /// The week numbering used by
+    /// [`DateTime::local_week_number`](crate::DateTime::local_week_number) and
+    /// the `n`/`ii` format tokens. Defaults to [`WeekRule::ISO`].
+    pub fn week_rule() -> WeekRule {
+        *WEEK_RULE.read().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Replaces the process-wide week numbering, e.g. with [`WeekRule::US`].
+    pub fn set_week_rule(rule: WeekRule) {
+        *WEEK_RULE.write().unwrap_or_else(|e| e.into_inner()) = rule;
+    }
+
+    /// Replaces the clock behind [`DateTime::now`](crate::DateTime::now).
+    ///
+    /// The function returns milliseconds since the Unix epoch. This is mainly
+    /// useful for tests that need a fixed "now".
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::{DateTime, Settings};
+    ///
+    /// fn fixed() -> i64 { 1_761_834_600_000 } // 2025-10-30T14:30:00Z
+    ///
+    /// Settings::set_now(fixed);
+    /// assert_eq!(DateTime::now().to_format("yyyy-MM-dd HH:mm"), "2025-10-30 14:30");
+    /// Settings::reset_now();
+    /// ```
+    pub fn set_now(clock: fn() -> i64) {
+        *CLOCK.write().unwrap_or_else(|e| e.into_inner()) = Some(clock);
+    }
+
+    /// Restores the system clock.
+    pub fn reset_now() {
+        *CLOCK.write().unwrap_or_else(|e| e.into_inner()) = None;
+    }
+
+    pub(crate) fn clock_millis() -> Option<i64> {
+        let clock = *CLOCK.read().unwrap_or_else(|e| e.into_inner());
+        clock.map(|f| f())
+    }
+
+    /// Installs a [`TimeZoneProvider`] that zone names are looked up in before
+    /// the built-in zones.
+    ///
+    /// DateTimes already in one of the provider's zones keep using it after it
+    /// is replaced, which is why it must be `'static`. See
+    /// [`TzfileProvider`](crate::TzfileProvider) for loading tzdata at run time.
+    pub fn set_zone_provider(provider: &'static dyn TimeZoneProvider) {
+        *ZONE_PROVIDER.write().unwrap_or_else(|e| e.into_inner()) = Some(provider);
+    }
+
+    /// Removes the installed provider, leaving only the built-in zones.
+    pub fn reset_zone_provider() {
+        *ZONE_PROVIDER.write().unwrap_or_else(|e| e.into_inner()) = None;
+    }
+
+    pub(crate) fn zone_provider() -> Option<&'static dyn TimeZoneProvider> {
+        *ZONE_PROVIDER.read().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Replaces the abbreviations the `ZZZZ` format token prints for `zone`,
+    /// in standard and in daylight saving time.
+    ///
+    /// Abbreviations are ambiguous (`CST` is used in Chicago, Beijing and
+    /// Havana) and the tz database only has numeric ones for many zones, so
+    /// an application can pick the spellings its users expect. `zone` is
+    /// matched against [`DateTime::zone_name`](crate::DateTime::zone_name).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use tempotime::{DateTime, Settings};
+    ///
+    /// Settings::set_zone_abbreviations("Asia/Shanghai", "CST (China)", "CDT (China)");
+    ///
+    /// let dt = DateTime::from_iso("2025-10-30T06:30:00Z").unwrap().set_zone("Asia/Shanghai");
+    /// assert_eq!(dt.to_format("h:mm a ZZZZ"), "2:30 pm CST (China)");
+    ///
+    /// Settings::clear_zone_abbreviations();
+    /// assert_eq!(dt.to_format("h:mm a ZZZZ"), "2:30 pm CST");
+    /// ```
+    pub fn set_zone_abbreviations(zone: &str, standard: &str, daylight: &str) {
+        let mut overrides = ZONE_ABBREVIATIONS
+            .write()
+            .unwrap_or_else(|e| e.into_inner());
+        overrides.retain(|(name, ..)| name != zone);
+        overrides.push((zone.to_string(), standard.to_string(), daylight.to_string()));
+    }
+
+    /// Removes every abbreviation set with
+    /// [`set_zone_abbreviations`](Self::set_zone_abbreviations).
+    pub fn clear_zone_abbreviations() {
+        ZONE_ABBREVIATIONS
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .clear();
+    }
+
+    // Writes the overriding abbreviation for `zone`, if one is set; `is_dst`
+    // is only asked when it is.
+    pub(crate) fn write_zone_abbreviation<W: core::fmt::Write>(
+        w: &mut W,
+        zone: &str,
+        is_dst: impl FnOnce() -> bool,
+    ) -> Option<core::fmt::Result> {
+        let overrides = ZONE_ABBREVIATIONS.read().unwrap_or_else(|e| e.into_inner());
+        let (_, standard, daylight) = overrides.iter().find(|(name, ..)| name == zone)?;
+        Some(w.write_str(if is_dst() { daylight } else { standard }))
+    }
+}
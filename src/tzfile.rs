@@ -0,0 +1,618 @@
+// A `TimeZoneProvider` reading compiled tz database files (TZif, RFC 8536),
+// such as those under /usr/share/zoneinfo. Each file holds a table of
+// transitions and, from version 2 on, a POSIX TZ string giving the rule for
+// instants after the table ends.
+
+use std::borrow::Cow;
+use std::path::Path;
+
+use crate::calendar::{days_from_civil, days_in_month, is_leap_year};
+use crate::error::Error;
+use crate::provider::{scan_transition, TimeZoneProvider};
+
+/// Zones read from compiled tz database files (TZif), so servers can ship
+/// and update their own tzdata snapshot without recompiling.
+///
+/// [`system`](Self::system) reads the operating system's zoneinfo directory,
+/// [`load`](Self::load) any directory laid out the same way, and
+/// [`insert`](Self::insert) a single file from memory. Install the result
+/// with [`Settings::set_zone_provider`](crate::Settings::set_zone_provider);
+/// it must live for the rest of the program, which `Box::leak` arranges for
+/// a provider loaded at run time.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use tempotime::{DateTime, Settings, TzfileProvider};
+///
+/// let provider = TzfileProvider::system().expect("zoneinfo directory");
+/// Settings::set_zone_provider(Box::leak(Box::new(provider)));
+///
+/// let dt = DateTime::from_iso("2025-07-01T12:00:00Z").unwrap().set_zone("America/New_York");
+/// assert_eq!(dt.offset(), -240);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct TzfileProvider {
+    // Sorted by name for binary search.
+    zones: Vec<(String, Tzif)>,
+}
+
+impl TzfileProvider {
+    /// A provider with no zones.
+    pub fn new() -> Self {
+        TzfileProvider::default()
+    }
+
+    /// Reads every TZif file under `/usr/share/zoneinfo`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an I/O error if the directory cannot be read.
+    pub fn system() -> std::io::Result<Self> {
+        Self::load("/usr/share/zoneinfo")
+    }
+
+    /// Reads every TZif file under `dir`, naming each zone by its path
+    /// relative to `dir`, such as `America/New_York`. Files that are not
+    /// TZif, like `zone.tab`, are skipped, as are the `right` (leap-second)
+    /// and `posix` copies of the tree.
+    ///
+    /// # Errors
+    ///
+    /// Returns an I/O error if a directory cannot be read.
+    pub fn load(dir: impl AsRef<Path>) -> std::io::Result<Self> {
+        let mut provider = TzfileProvider::new();
+        provider.load_dir(dir.as_ref(), "")?;
+        Ok(provider)
+    }
+
+    fn load_dir(&mut self, dir: &Path, prefix: &str) -> std::io::Result<()> {
+        for entry in std::fs::read_dir(dir)? {
+            let entry = entry?;
+            let Ok(file_name) = entry.file_name().into_string() else {
+                continue;
+            };
+            let name = format!("{}{}", prefix, file_name);
+            let path = entry.path();
+            if path.is_dir() {
+                if name != "right" && name != "posix" {
+                    self.load_dir(&path, &format!("{}/", name))?;
+                }
+            } else if let Ok(data) = std::fs::read(&path) {
+                // Not every file in a zoneinfo directory is a zone.
+                let _ = self.insert(&name, &data);
+            }
+        }
+        Ok(())
+    }
+
+    /// Adds the zone `name` from the contents of a TZif file, replacing any
+    /// zone of that name.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Invalid`] with `what` set to `"tzfile"` if `data` is
+    /// not a well-formed TZif file.
+    pub fn insert(&mut self, name: &str, data: &[u8]) -> Result<(), Error> {
+        let tzif = Tzif::parse(data)?;
+        match self
+            .zones
+            .binary_search_by(|(zone, _)| zone.as_str().cmp(name))
+        {
+            Ok(ix) => self.zones[ix].1 = tzif,
+            Err(ix) => self.zones.insert(ix, (name.to_string(), tzif)),
+        }
+        Ok(())
+    }
+
+    /// The names of the zones held, in order.
+    pub fn zone_names(&self) -> impl Iterator<Item = &str> {
+        self.zones.iter().map(|(name, _)| name.as_str())
+    }
+
+    /// The number of zones held.
+    pub fn len(&self) -> usize {
+        self.zones.len()
+    }
+
+    /// Whether no zones are held.
+    pub fn is_empty(&self) -> bool {
+        self.zones.is_empty()
+    }
+}
+
+impl TimeZoneProvider for TzfileProvider {
+    fn zone_id(&self, name: &str) -> Option<usize> {
+        self.zones
+            .binary_search_by(|(zone, _)| zone.as_str().cmp(name))
+            .ok()
+    }
+
+    fn zone_name(&self, id: usize) -> &str {
+        &self.zones[id].0
+    }
+
+    fn offset_at(&self, id: usize, instant_ms: i64) -> i32 {
+        self.zones[id]
+            .1
+            .local_type(instant_ms.div_euclid(1000))
+            .offset
+    }
+
+    fn is_dst(&self, id: usize, instant_ms: i64) -> bool {
+        self.zones[id]
+            .1
+            .local_type(instant_ms.div_euclid(1000))
+            .is_dst
+    }
+
+    fn abbreviation(&self, id: usize, instant_ms: i64) -> Option<Cow<'_, str>> {
+        Some(Cow::Borrowed(
+            self.zones[id].1.abbreviation(instant_ms.div_euclid(1000)),
+        ))
+    }
+
+    // The table gives exact transitions; past its end the footer rule is
+    // searched like any other provider's offsets.
+    fn next_transition(&self, id: usize, instant_ms: i64) -> Option<i64> {
+        let tzif = &self.zones[id].1;
+        let after = tzif
+            .transitions
+            .partition_point(|&(at, _)| at.saturating_mul(1000) <= instant_ms);
+        if let Some(ix) = (after..tzif.transitions.len()).find(|&ix| tzif.changes_offset(ix)) {
+            return Some(tzif.transitions[ix].0.saturating_mul(1000));
+        }
+        tzif.rule?;
+        let table_end = tzif
+            .transitions
+            .last()
+            .map_or(instant_ms, |&(at, _)| at.saturating_mul(1000));
+        scan_transition(|at| self.offset_at(id, at), instant_ms.max(table_end), true)
+    }
+
+    fn previous_transition(&self, id: usize, instant_ms: i64) -> Option<i64> {
+        let tzif = &self.zones[id].1;
+        let after = tzif
+            .transitions
+            .partition_point(|&(at, _)| at.saturating_mul(1000) <= instant_ms);
+        if tzif.rule.is_some() && after == tzif.transitions.len() {
+            let table_end = tzif
+                .transitions
+                .last()
+                .map_or(i64::MIN, |&(at, _)| at.saturating_mul(1000));
+            let found = scan_transition(|at| self.offset_at(id, at), instant_ms, false);
+            if let Some(at) = found.filter(|&at| at > table_end) {
+                return Some(at);
+            }
+        }
+        (0..after)
+            .rev()
+            .find(|&ix| tzif.changes_offset(ix))
+            .map(|ix| tzif.transitions[ix].0.saturating_mul(1000))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct LocalType {
+    offset: i32,
+    is_dst: bool,
+    // Index into `Tzif::abbreviations`.
+    abbreviation: usize,
+}
+
+// The contents of one TZif file that matter for offsets and abbreviations.
+#[derive(Debug, Clone)]
+struct Tzif {
+    // Transition instants in seconds, each with the index of the local time
+    // type that starts there.
+    transitions: Vec<(i64, usize)>,
+    types: Vec<LocalType>,
+    // The distinct abbreviations of the types and the footer rule.
+    abbreviations: Vec<String>,
+    // The footer rule for instants after the last transition.
+    rule: Option<PosixRule>,
+}
+
+impl Tzif {
+    fn parse(data: &[u8]) -> Result<Tzif, Error> {
+        let mut input = Input { data, pos: 0 };
+        let header = input.header()?;
+        let tzif = if header.version >= b'2' {
+            input.skip(header.data_len(4))?;
+            let header = input.header()?;
+            let mut tzif = input.body(&header, 8)?;
+            // A rule this reader does not understand is dropped rather than
+            // failing the whole file; the last transition's type then applies.
+            tzif.rule = PosixRule::parse(input.footer()?, &mut tzif.abbreviations);
+            tzif
+        } else {
+            input.body(&header, 4)?
+        };
+        if tzif.types.is_empty() {
+            return Err(input.invalid());
+        }
+        Ok(tzif)
+    }
+
+    // Whether the transition at `ix` changes the UTC offset, rather than
+    // only the abbreviation or DST flag.
+    fn changes_offset(&self, ix: usize) -> bool {
+        let before = match ix {
+            0 => self.types[0],
+            _ => self.types[self.transitions[ix - 1].1],
+        };
+        self.types[self.transitions[ix].1].offset != before.offset
+    }
+
+    fn abbreviation(&self, secs: i64) -> &str {
+        &self.abbreviations[self.local_type(secs).abbreviation]
+    }
+
+    fn local_type(&self, secs: i64) -> LocalType {
+        let after = self.transitions.partition_point(|&(at, _)| at <= secs);
+        match (after, &self.rule) {
+            (n, Some(rule)) if n == self.transitions.len() => rule.local_type(secs),
+            (0, _) => self.types[0],
+            (n, _) => self.types[self.transitions[n - 1].1],
+        }
+    }
+}
+
+struct Header {
+    version: u8,
+    isutcnt: usize,
+    isstdcnt: usize,
+    leapcnt: usize,
+    timecnt: usize,
+    typecnt: usize,
+    charcnt: usize,
+}
+
+impl Header {
+    // The length of the data block that follows, with `time_size`-byte times.
+    // Saturates, since the counts come straight from the file.
+    fn data_len(&self, time_size: usize) -> usize {
+        [
+            (self.timecnt, time_size + 1),
+            (self.typecnt, 6),
+            (self.charcnt, 1),
+            (self.leapcnt, time_size + 4),
+            (self.isstdcnt, 1),
+            (self.isutcnt, 1),
+        ]
+        .iter()
+        .fold(0usize, |len, &(count, size)| {
+            len.saturating_add(count.saturating_mul(size))
+        })
+    }
+}
+
+// A cursor over the file, reporting errors at the byte it stopped on.
+struct Input<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl Input<'_> {
+    fn invalid(&self) -> Error {
+        Error::Invalid {
+            what: "tzfile",
+            position: self.pos,
+        }
+    }
+
+    fn take(&mut self, len: usize) -> Result<&[u8], Error> {
+        let end = self
+            .pos
+            .checked_add(len)
+            .filter(|&end| end <= self.data.len())
+            .ok_or_else(|| self.invalid())?;
+        let bytes = &self.data[self.pos..end];
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    fn skip(&mut self, len: usize) -> Result<(), Error> {
+        self.take(len).map(|_| ())
+    }
+
+    fn int(&mut self, size: usize) -> Result<i64, Error> {
+        let bytes = self.take(size)?;
+        let unsigned = bytes.iter().fold(0u64, |acc, &b| acc << 8 | b as u64);
+        // Sign-extend from `size` bytes.
+        let shift = 64 - 8 * size as u32;
+        Ok(((unsigned << shift) as i64) >> shift)
+    }
+
+    fn header(&mut self) -> Result<Header, Error> {
+        let start = self.pos;
+        if self.take(4)? != b"TZif" {
+            self.pos = start;
+            return Err(self.invalid());
+        }
+        let version = self.take(1)?[0];
+        self.skip(15)?;
+        let mut counts = [0usize; 6];
+        for count in &mut counts {
+            *count = self.int(4)? as u32 as usize;
+        }
+        let [isutcnt, isstdcnt, leapcnt, timecnt, typecnt, charcnt] = counts;
+        Ok(Header {
+            version,
+            isutcnt,
+            isstdcnt,
+            leapcnt,
+            timecnt,
+            typecnt,
+            charcnt,
+        })
+    }
+
+    fn body(&mut self, header: &Header, time_size: usize) -> Result<Tzif, Error> {
+        // Check the counts against the input before allocating for them.
+        if header.data_len(time_size) > self.data.len() - self.pos {
+            return Err(self.invalid());
+        }
+        let times = (0..header.timecnt)
+            .map(|_| self.int(time_size))
+            .collect::<Result<Vec<_>, _>>()?;
+        let indices = self.take(header.timecnt)?.to_vec();
+        let mut types = Vec::with_capacity(header.typecnt);
+        let mut designations = Vec::with_capacity(header.typecnt);
+        for _ in 0..header.typecnt {
+            let offset = self.int(4)? as i32;
+            let flags = self.take(2)?;
+            types.push(LocalType {
+                offset,
+                is_dst: flags[0] != 0,
+                abbreviation: 0,
+            });
+            designations.push(flags[1] as usize);
+        }
+        if indices.iter().any(|&ix| ix as usize >= types.len()) {
+            return Err(self.invalid());
+        }
+        let invalid = self.invalid();
+        let chars = self.take(header.charcnt)?;
+        let mut abbreviations = Vec::new();
+        for (local_type, &start) in types.iter_mut().zip(&designations) {
+            let text = chars.get(start..).ok_or_else(|| invalid.clone())?;
+            let text = text.split(|&b| b == 0).next().unwrap_or_default();
+            local_type.abbreviation = intern(&mut abbreviations, &String::from_utf8_lossy(text));
+        }
+        self.skip(header.leapcnt * (time_size + 4) + header.isstdcnt + header.isutcnt)?;
+        let transitions = times
+            .into_iter()
+            .zip(indices.into_iter().map(usize::from))
+            .collect();
+        Ok(Tzif {
+            transitions,
+            types,
+            abbreviations,
+            rule: None,
+        })
+    }
+
+    // The POSIX TZ string between newlines at the end of a version 2+ file.
+    fn footer(&self) -> Result<&str, Error> {
+        let rest = &self.data[self.pos..];
+        let text = rest
+            .strip_prefix(b"\n")
+            .and_then(|text| text.split(|&b| b == b'\n').next());
+        text.and_then(|text| std::str::from_utf8(text).ok())
+            .ok_or_else(|| self.invalid())
+    }
+}
+
+// A POSIX TZ rule such as `EST5EDT,M3.2.0,M11.1.0`.
+#[derive(Debug, Clone, Copy)]
+struct PosixRule {
+    standard: LocalType,
+    dst: Option<Dst>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Dst {
+    daylight: LocalType,
+    start: (RuleDate, i32),
+    end: (RuleDate, i32),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum RuleDate {
+    // `Jn`: day 1 to 365, never counting February 29th.
+    Julian(u32),
+    // `n`: day 0 to 365, counting February 29th.
+    Ordinal(u32),
+    // `Mm.w.d`: weekday `d` (0 is Sunday) of week `w` of month `m`, where
+    // week 5 is the last.
+    Month(u32, u32, u32),
+}
+
+impl PosixRule {
+    // Reads the rule, adding its abbreviations to `abbreviations`.
+    fn parse(text: &str, abbreviations: &mut Vec<String>) -> Option<PosixRule> {
+        let mut rest = text;
+        let name = parse_name(&mut rest)?;
+        // POSIX offsets are west of UTC.
+        let std_offset = -parse_time(&mut rest)?;
+        let standard = LocalType {
+            offset: std_offset,
+            is_dst: false,
+            abbreviation: intern(abbreviations, name),
+        };
+        if rest.is_empty() {
+            return Some(PosixRule {
+                standard,
+                dst: None,
+            });
+        }
+        let name = parse_name(&mut rest)?;
+        let offset = if rest.starts_with(',') {
+            std_offset + 3600
+        } else {
+            -parse_time(&mut rest)?
+        };
+        let start = parse_transition(&mut rest)?;
+        let end = parse_transition(&mut rest)?;
+        if !rest.is_empty() {
+            return None;
+        }
+        let daylight = LocalType {
+            offset,
+            is_dst: true,
+            abbreviation: intern(abbreviations, name),
+        };
+        Some(PosixRule {
+            standard,
+            dst: Some(Dst {
+                daylight,
+                start,
+                end,
+            }),
+        })
+    }
+
+    fn local_type(&self, secs: i64) -> LocalType {
+        let (standard, std_offset) = (self.standard, self.standard.offset);
+        let Some(dst) = self.dst else {
+            return standard;
+        };
+        let year =
+            crate::calendar::civil_from_days((secs + std_offset as i64).div_euclid(86_400)).0;
+        // Clocks go forward at the start time in standard time, and back at
+        // the end time in daylight time.
+        let start = dst.start.0.day(year) * 86_400 + dst.start.1 as i64 - std_offset as i64;
+        let end = dst.end.0.day(year) * 86_400 + dst.end.1 as i64 - dst.daylight.offset as i64;
+        let in_dst = if start < end {
+            (start..end).contains(&secs)
+        } else {
+            !(end..start).contains(&secs)
+        };
+        if in_dst {
+            dst.daylight
+        } else {
+            standard
+        }
+    }
+}
+
+impl RuleDate {
+    // Days since the epoch of this date in `year`.
+    fn day(self, year: i32) -> i64 {
+        let jan1 = days_from_civil(year, 1, 1);
+        match self {
+            RuleDate::Julian(n) => jan1 + n as i64 - 1 + (is_leap_year(year) && n >= 60) as i64,
+            RuleDate::Ordinal(n) => jan1 + n as i64,
+            RuleDate::Month(month, week, weekday) => {
+                let first = days_from_civil(year, month, 1);
+                // 1970-01-01 was a Thursday, day 4 counting from Sunday.
+                let first_weekday = (first + 4).rem_euclid(7) as u32;
+                let mut day = 1 + (weekday + 7 - first_weekday) % 7 + (week - 1) * 7;
+                while day > days_in_month(year, month) {
+                    day -= 7;
+                }
+                first + day as i64 - 1
+            }
+        }
+    }
+}
+
+// The index of `name` in `names`, adding it if it is new.
+fn intern(names: &mut Vec<String>, name: &str) -> usize {
+    names
+        .iter()
+        .position(|known| known == name)
+        .unwrap_or_else(|| {
+            names.push(name.to_string());
+            names.len() - 1
+        })
+}
+
+// Reads a zone abbreviation: three or more letters, or anything in `<...>`
+// (returned without the brackets), as in `<+03>`.
+fn parse_name<'a>(rest: &mut &'a str) -> Option<&'a str> {
+    let (name, len) = if let Some(quoted) = rest.strip_prefix('<') {
+        let end = quoted.find('>')?;
+        (&quoted[..end], end + 2)
+    } else {
+        let len = rest
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or(rest.len());
+        (&rest[..len], len)
+    };
+    if name.len() < 3 {
+        return None;
+    }
+    *rest = &rest[len..];
+    Some(name)
+}
+
+// `[+-]hh[:mm[:ss]]` in seconds, hours up to 167 as TZif version 3 allows.
+fn parse_time(rest: &mut &str) -> Option<i32> {
+    let sign = match rest.as_bytes().first()? {
+        b'-' => -1,
+        b'+' => 1,
+        _ => 0,
+    };
+    if sign != 0 {
+        *rest = &rest[1..];
+    }
+    let mut total = 0;
+    for (i, scale) in [3600, 60, 1].into_iter().enumerate() {
+        if i > 0 {
+            match rest.strip_prefix(':') {
+                Some(after) => *rest = after,
+                None => break,
+            }
+        }
+        let len = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let value: i32 = rest[..len]
+            .parse()
+            .ok()
+            .filter(|&v| v <= if i == 0 { 167 } else { 59 })?;
+        total += value * scale;
+        *rest = &rest[len..];
+    }
+    Some(if sign < 0 { -total } else { total })
+}
+
+// `,date[/time]`, the time defaulting to 02:00.
+fn parse_transition(rest: &mut &str) -> Option<(RuleDate, i32)> {
+    *rest = rest.strip_prefix(',')?;
+    let number = |rest: &mut &str| -> Option<u32> {
+        let len = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let value = rest[..len].parse().ok()?;
+        *rest = &rest[len..];
+        Some(value)
+    };
+    let date = if let Some(after) = rest.strip_prefix('M') {
+        *rest = after;
+        let month = number(rest)?;
+        *rest = rest.strip_prefix('.')?;
+        let week = number(rest)?;
+        *rest = rest.strip_prefix('.')?;
+        let weekday = number(rest)?;
+        if !(1..=12).contains(&month) || !(1..=5).contains(&week) || weekday > 6 {
+            return None;
+        }
+        RuleDate::Month(month, week, weekday)
+    } else if let Some(after) = rest.strip_prefix('J') {
+        *rest = after;
+        RuleDate::Julian(number(rest).filter(|n| (1..=365).contains(n))?)
+    } else {
+        RuleDate::Ordinal(number(rest).filter(|&n| n <= 365)?)
+    };
+    let time = match rest.strip_prefix('/') {
+        Some(after) => {
+            *rest = after;
+            parse_time(rest)?
+        }
+        None => 7200,
+    };
+    Some((date, time))
+}
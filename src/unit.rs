@@ -0,0 +1,104 @@
+// Units of time as a type, the checked counterpart of the unit names
+// ("days", "hour") that the string-based methods accept.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::Error;
+
+/// A unit of time.
+///
+/// Units order from largest to smallest, so `Unit::Years < Unit::Days`.
+///
+/// # Examples
+///
+/// ```rust
+/// use tempotime::Unit;
+///
+/// assert_eq!("hours".parse::<Unit>().unwrap(), Unit::Hours);
+/// assert_eq!("minute".parse::<Unit>().unwrap(), Unit::Minutes);
+/// assert!("dayz".parse::<Unit>().is_err());
+/// assert_eq!(Unit::Weeks.to_string(), "weeks");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Unit {
+    Years,
+    Months,
+    Weeks,
+    Days,
+    Hours,
+    Minutes,
+    Seconds,
+    Milliseconds,
+}
+
+impl Unit {
+    /// Every unit, largest first.
+    pub const ALL: [Unit; 8] = [
+        Unit::Years,
+        Unit::Months,
+        Unit::Weeks,
+        Unit::Days,
+        Unit::Hours,
+        Unit::Minutes,
+        Unit::Seconds,
+        Unit::Milliseconds,
+    ];
+
+    /// The plural name, as used by `from_object` and `to_object`.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Unit::Years => "years",
+            Unit::Months => "months",
+            Unit::Weeks => "weeks",
+            Unit::Days => "days",
+            Unit::Hours => "hours",
+            Unit::Minutes => "minutes",
+            Unit::Seconds => "seconds",
+            Unit::Milliseconds => "milliseconds",
+        }
+    }
+
+    // The short form for compact output: `y`, `mo`, `w`, `d`, `h`, `m`,
+    // `s` and `ms`.
+    pub(crate) const fn abbreviation(self) -> &'static str {
+        match self {
+            Unit::Years => "y",
+            Unit::Months => "mo",
+            Unit::Weeks => "w",
+            Unit::Days => "d",
+            Unit::Hours => "h",
+            Unit::Minutes => "m",
+            Unit::Seconds => "s",
+            Unit::Milliseconds => "ms",
+        }
+    }
+
+    // Fixed length in milliseconds, with 30-day months and 365-day years.
+    pub(crate) const fn length_ms(self) -> i64 {
+        crate::duration::UNIT_MS[self.index()]
+    }
+
+    // Position in `Unit::ALL`.
+    pub(crate) const fn index(self) -> usize {
+        self as usize
+    }
+}
+
+impl FromStr for Unit {
+    type Err = Error;
+
+    /// Reads a singular or plural unit name such as `"day"` or `"days"`.
+    fn from_str(s: &str) -> Result<Self, Error> {
+        Unit::ALL
+            .into_iter()
+            .find(|unit| s == unit.name() || s == &unit.name()[..unit.name().len() - 1])
+            .ok_or_else(|| Error::UnknownUnit(s.to_string()))
+    }
+}
+
+impl fmt::Display for Unit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
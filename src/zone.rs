@@ -0,0 +1,254 @@
+#[cfg(feature = "tz")]
+use chrono::{Offset, TimeZone};
+#[cfg(feature = "tz")]
+use chrono_tz::Tz;
+
+use crate::error::Error;
+use crate::options::Disambiguation;
+#[cfg(feature = "tz")]
+use crate::provider::scan_transition;
+use crate::provider::ProvidedZone;
+use crate::settings::Settings;
+
+// The zone attached to a DateTime. The stored instant is always UTC; a zone
+// only changes how that instant is read back as wall-clock fields.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum Zone {
+    // An entry from the built-in table used when the `tz` feature is off.
+    #[cfg(not(feature = "tz"))]
+    Static {
+        name: &'static str,
+        offset_secs: i32,
+        abbreviation: &'static str,
+    },
+    #[cfg(feature = "tz")]
+    Iana(Tz),
+    // A constant offset given as `+05:30` or `UTC-07:00`, in every build. The
+    // name is kept spelled out as `UTC±HH:MM` so it can be borrowed.
+    Fixed {
+        offset_secs: i32,
+        name: [u8; 9],
+    },
+    // A zone from the provider installed in `Settings`.
+    Provided(ProvidedZone),
+}
+
+impl Zone {
+    // Looks `name` up in the installed provider, then the built-in zones,
+    // then as a fixed offset.
+    pub(crate) fn parse(name: &str) -> Option<Zone> {
+        if let Some(provider) = Settings::zone_provider() {
+            if let Some(id) = provider.zone_id(name) {
+                return Some(Zone::Provided(ProvidedZone { provider, id }));
+            }
+        }
+
+        #[cfg(feature = "tz")]
+        let named = name.parse::<Tz>().ok().map(Zone::Iana);
+
+        #[cfg(not(feature = "tz"))]
+        let named = static_zone(name).map(|(name, offset_secs, abbreviation)| Zone::Static {
+            name,
+            offset_secs,
+            abbreviation,
+        });
+
+        named.or_else(|| Zone::parse_offset(name))
+    }
+
+    // A fixed-offset zone from `+05:30`, `-07:00`, `+0530`, `+5`, or any of
+    // those after `UTC` or `GMT`. The whole string must be an offset.
+    fn parse_offset(name: &str) -> Option<Zone> {
+        let unsigned = name.get(..3).is_some_and(|prefix| {
+            prefix.eq_ignore_ascii_case("UTC") || prefix.eq_ignore_ascii_case("GMT")
+        });
+        let sign = name.as_bytes().get(if unsigned { 3 } else { 0 });
+        if !matches!(sign, Some(b'+' | b'-')) {
+            return None;
+        }
+        match crate::datetime::parse_offset(name, 0) {
+            Ok((offset_secs, len)) if len == name.len() => Some(Zone::with_offset(offset_secs)),
+            _ => None,
+        }
+    }
+
+    // The fixed-offset zone `offset_secs` east of UTC, to the minute.
+    pub(crate) fn with_offset(offset_secs: i32) -> Zone {
+        let minutes = offset_secs.unsigned_abs() / 60;
+        let digit = |n: u32| b'0' + n as u8;
+        let (hours, minutes) = (minutes / 60, minutes % 60);
+        let sign = if offset_secs < 0 { b'-' } else { b'+' };
+        Zone::Fixed {
+            offset_secs,
+            name: [
+                b'U',
+                b'T',
+                b'C',
+                sign,
+                digit(hours / 10),
+                digit(hours % 10),
+                b':',
+                digit(minutes / 10),
+                digit(minutes % 10),
+            ],
+        }
+    }
+
+    // The IANA name with `tz`, or the canonical table spelling without it.
+    pub(crate) fn name(&self) -> &str {
+        match *self {
+            #[cfg(not(feature = "tz"))]
+            Zone::Static { name, .. } => name,
+            #[cfg(feature = "tz")]
+            Zone::Iana(tz) => tz.name(),
+            Zone::Fixed { ref name, .. } => core::str::from_utf8(name).unwrap_or("UTC"),
+            Zone::Provided(zone) => zone.name(),
+        }
+    }
+
+    // Writes the abbreviation in use at the instant, such as `EST` or `JST`,
+    // when the zone data has one. The tz database gives numeric ones like
+    // `+04` for zones without a common abbreviation. An override from
+    // `Settings::set_zone_abbreviations` wins over the data.
+    pub(crate) fn write_abbreviation<W: core::fmt::Write>(
+        &self,
+        w: &mut W,
+        instant_ms: i64,
+    ) -> Option<core::fmt::Result> {
+        if let Some(result) =
+            Settings::write_zone_abbreviation(w, self.name(), || self.is_dst_at(instant_ms))
+        {
+            return Some(result);
+        }
+        match *self {
+            #[cfg(not(feature = "tz"))]
+            Zone::Static { abbreviation, .. } => Some(w.write_str(abbreviation)),
+            Zone::Fixed { .. } => None,
+            Zone::Provided(zone) => zone
+                .provider
+                .abbreviation(zone.id, instant_ms)
+                .map(|abbreviation| w.write_str(&abbreviation)),
+            #[cfg(feature = "tz")]
+            Zone::Iana(tz) => {
+                use chrono_tz::OffsetName;
+                let utc = chrono::DateTime::from_timestamp_millis(instant_ms)
+                    .unwrap_or_default()
+                    .naive_utc();
+                Some(w.write_str(tz.offset_from_utc_datetime(&utc).abbreviation()))
+            }
+        }
+    }
+
+    // Seconds east of UTC in effect at the given instant.
+    #[allow(unused_variables)]
+    pub(crate) fn offset_secs_at(&self, instant_ms: i64) -> i32 {
+        match *self {
+            #[cfg(not(feature = "tz"))]
+            Zone::Static { offset_secs, .. } => offset_secs,
+            Zone::Fixed { offset_secs, .. } => offset_secs,
+            Zone::Provided(zone) => zone.provider.offset_at(zone.id, instant_ms),
+            #[cfg(feature = "tz")]
+            Zone::Iana(tz) => {
+                let utc = chrono::DateTime::from_timestamp_millis(instant_ms)
+                    .unwrap_or_default()
+                    .naive_utc();
+                tz.offset_from_utc_datetime(&utc).fix().local_minus_utc()
+            }
+        }
+    }
+
+    // The instant at which wall-clock time in this zone reads `local_ms`
+    // (milliseconds since the epoch as if the zone were UTC). The offsets a
+    // day either side are the candidates: one fits for an ordinary time, both
+    // fit for a time repeated when clocks go back, and neither fits for one
+    // skipped when they go forward.
+    pub(crate) fn local_to_instant_ms(
+        &self,
+        local_ms: i64,
+        disambiguation: Disambiguation,
+    ) -> Result<i64, Error> {
+        const DAY_MS: i64 = 86_400_000;
+        let with_offset_at = |at: i64| local_ms - self.offset_secs_at(at) as i64 * 1000;
+        let before = with_offset_at(local_ms.saturating_sub(DAY_MS));
+        let after = with_offset_at(local_ms.saturating_add(DAY_MS));
+        let fits = |instant: i64| with_offset_at(instant) == instant;
+
+        let (earlier, later) = (before.min(after), before.max(after));
+        match (fits(before), fits(after)) {
+            (true, false) => Ok(before),
+            (false, true) => Ok(after),
+            _ if before == after => Ok(before),
+            (true, true) => match disambiguation {
+                Disambiguation::Earlier | Disambiguation::Shift => Ok(earlier),
+                Disambiguation::Later => Ok(later),
+                Disambiguation::Reject => Err(Error::AmbiguousLocalTime),
+            },
+            (false, false) => match disambiguation {
+                Disambiguation::Earlier => Ok(earlier),
+                Disambiguation::Later | Disambiguation::Shift => Ok(later),
+                Disambiguation::Reject => Err(Error::NonexistentLocalTime),
+            },
+        }
+    }
+
+    // Whether daylight saving time is in effect at the given instant.
+    #[allow(unused_variables)]
+    pub(crate) fn is_dst_at(&self, instant_ms: i64) -> bool {
+        match *self {
+            #[cfg(feature = "tz")]
+            Zone::Iana(tz) => {
+                use chrono_tz::OffsetComponents;
+                let utc = chrono::DateTime::from_timestamp_millis(instant_ms)
+                    .unwrap_or_default()
+                    .naive_utc();
+                !tz.offset_from_utc_datetime(&utc).dst_offset().is_zero()
+            }
+            Zone::Provided(zone) => zone.provider.is_dst(zone.id, instant_ms),
+            _ => false,
+        }
+    }
+
+    // The first instant after `instant_ms` (or the last at or before it when
+    // searching backwards) at which the UTC offset changes. Providers answer
+    // from their own data; IANA zones are searched.
+    pub(crate) fn transition_ms(&self, instant_ms: i64, forward: bool) -> Option<i64> {
+        match *self {
+            Zone::Provided(zone) if forward => zone.provider.next_transition(zone.id, instant_ms),
+            Zone::Provided(zone) => zone.provider.previous_transition(zone.id, instant_ms),
+            #[cfg(feature = "tz")]
+            Zone::Iana(_) => scan_transition(|at| self.offset_secs_at(at), instant_ms, forward),
+            _ => None,
+        }
+    }
+}
+
+// List of common zones with their fixed offsets (seconds east of UTC) and
+// standard-time abbreviations as the tz database spells them, also served by
+// `StaticProvider` in every build. Keep ordered by popularity to make linear
+// scan cheap in practice.
+pub(crate) const STATIC_ZONES: &[(&str, i32, &str)] = &[
+    ("UTC", 0, "UTC"),
+    ("Europe/London", 0, "GMT"),
+    ("America/New_York", -5 * 3600, "EST"),
+    ("America/Los_Angeles", -8 * 3600, "PST"),
+    ("Europe/Paris", 3600, "CET"),
+    ("Asia/Tokyo", 9 * 3600, "JST"),
+    ("Asia/Shanghai", 8 * 3600, "CST"),
+    ("Australia/Sydney", 10 * 3600, "AEST"),
+    ("Asia/Kolkata", 5 * 3600 + 30 * 60, "IST"),
+    ("America/Sao_Paulo", -3 * 3600, "-03"),
+];
+
+// For zero-deps (no `tz`) builds provide a small built-in mapping of common
+// timezone names to fixed offsets (seconds east of UTC). This is intentionally
+// small and does not attempt to model DST — it's a convenience for simple
+// localized displays. Lookup is a case-insensitive linear scan to avoid heap
+// allocations and any extra runtime dependencies. The canonical spelling is
+// returned alongside the offset and abbreviation.
+#[cfg(not(feature = "tz"))]
+fn static_zone(zone: &str) -> Option<(&'static str, i32, &'static str)> {
+    STATIC_ZONES
+        .iter()
+        .find(|(name, ..)| zone.eq_ignore_ascii_case(name))
+        .copied()
+}
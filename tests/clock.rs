@@ -0,0 +1,50 @@
+use tempotime::{DateTime, Settings};
+
+// 2025-10-30T14:30:00Z, a Thursday
+fn fixed_now() -> i64 {
+    1_761_834_600_000
+}
+
+// The clock is process-wide, so all checks share a single test.
+#[test]
+fn relative_predicates_use_injected_clock() {
+    Settings::set_now(fixed_now);
+
+    let now = DateTime::now();
+    assert_eq!(
+        now.to_iso(),
+        DateTime::from_iso("2025-10-30T14:30:00Z").unwrap().to_iso()
+    );
+
+    let earlier_today = DateTime::from_iso("2025-10-30T01:00:00Z").unwrap();
+    assert!(earlier_today.is_today());
+    assert!(earlier_today.is_past());
+    assert!(!earlier_today.is_future());
+
+    let tomorrow = DateTime::from_iso("2025-10-31T00:00:00Z").unwrap();
+    assert!(!tomorrow.is_today());
+    assert!(tomorrow.is_future());
+    assert!(tomorrow.is_this_week());
+    assert!(tomorrow.is_this_month());
+
+    let monday = DateTime::from_iso("2025-10-27T00:00:00Z").unwrap();
+    assert!(monday.is_this_week());
+    let previous_sunday = DateTime::from_iso("2025-10-26T23:59:59Z").unwrap();
+    assert!(!previous_sunday.is_this_week());
+    assert!(previous_sunday.is_this_month());
+
+    let next_month = DateTime::from_iso("2025-11-01T00:00:00Z").unwrap();
+    assert!(!next_month.is_this_month());
+
+    #[cfg(feature = "tz")]
+    {
+        // 16:00 UTC is still the 30th in UTC but already the 31st in Tokyo,
+        // where "now" (23:30) is still the 30th.
+        let evening = DateTime::from_iso("2025-10-30T16:00:00Z").unwrap();
+        assert!(evening.is_today());
+        assert!(!evening.set_zone("Asia/Tokyo").is_today());
+    }
+
+    Settings::reset_now();
+    assert!(DateTime::now().to_iso() != now.to_iso());
+}
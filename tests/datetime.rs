@@ -11,7 +11,7 @@ fn test_datetime_now() {
 #[test]
 fn test_datetime_local() {
     let dt = DateTime::local();
-    assert!(dt.to_iso().len() > 0);
+    assert!(!dt.to_iso().is_empty());
 }
 
 #[test]
@@ -120,6 +120,1542 @@ fn test_to_format_basic() {
     assert_eq!(dt.to_format("dd"), "29");
 }
 
+#[test]
+fn test_chaining_borrows() {
+    let dates = [
+        DateTime::from_iso("2025-10-29T12:00:00Z").unwrap(),
+        DateTime::from_iso("2025-11-03T08:15:00Z").unwrap(),
+    ];
+    let starts: Vec<String> = dates
+        .iter()
+        .map(|d| {
+            d.start_of("month")
+                .plus(&Duration::from_object(&[("days", 1)]))
+                .to_format("MM-dd")
+        })
+        .collect();
+    assert_eq!(starts, ["10-02", "11-02"]);
+    assert_eq!(dates[0].to_format("dd HH:mm"), "29 12:00");
+}
+
+#[test]
+fn test_const_construction() {
+    use tempotime::datetime;
+
+    const CUTOFF: DateTime = DateTime::from_timestamp_ms(1_761_834_600_000);
+    const LEAP: DateTime = datetime!(2024-02-29 08:05:09);
+    assert_eq!(CUTOFF, datetime!(2025-10-30 14:30));
+    assert_eq!(LEAP.to_format("yyyy-MM-dd HH:mm:ss"), "2024-02-29 08:05:09");
+    assert_eq!(datetime!(1969 - 12 - 31).to_millis(), -86_400_000);
+}
+
+#[test]
+fn test_age_and_anniversaries() {
+    let born = DateTime::from_iso("2000-02-29T00:00:00Z").unwrap();
+    let at = |s: &str| DateTime::from_iso(s).unwrap();
+
+    assert_eq!(born.age_in_years(&at("2000-02-29T00:00:00Z")), 0);
+    assert_eq!(born.age_in_years(&at("2001-02-27T12:00:00Z")), 0);
+    assert_eq!(born.age_in_years(&at("2001-02-28T00:00:00Z")), 1);
+    assert_eq!(born.age_in_years(&at("2004-02-28T23:59:59Z")), 3);
+    assert_eq!(born.age_in_years(&at("2004-02-29T00:00:00Z")), 4);
+    assert_eq!(born.age_in_years(&at("1998-06-01T00:00:00Z")), -1);
+
+    let next = |now: &str| {
+        born.next_anniversary(&at(now))
+            .unwrap()
+            .to_format("yyyy-MM-dd")
+    };
+    assert_eq!(next("2025-02-27T00:00:00Z"), "2025-02-28");
+    assert_eq!(next("2025-02-28T00:00:00Z"), "2026-02-28");
+    assert_eq!(next("2027-06-01T00:00:00Z"), "2028-02-29");
+    assert_eq!(next("1990-01-01T00:00:00Z"), "2000-02-29");
+    assert!(born.next_anniversary(&at("9999-06-01T00:00:00Z")).is_none());
+}
+
+#[test]
+fn test_iso_basic_format() {
+    let dt = DateTime::from_iso("20251030T143000Z").unwrap();
+    assert_eq!(dt, DateTime::from_iso("2025-10-30T14:30:00Z").unwrap());
+    assert_eq!(dt.to_iso_basic(), "20251030T143000Z");
+
+    let zoned = DateTime::from_iso("20251030T163000.125+0200")
+        .unwrap()
+        .set_zone("Asia/Tokyo");
+    assert_eq!(zoned.to_iso_basic(), "20251030T143000.125Z");
+    assert_eq!(DateTime::from_iso(&zoned.to_iso_basic()).unwrap(), zoned);
+
+    assert!(DateTime::from_iso("20251030T14:30:00Z").is_err());
+    assert!(DateTime::from_iso("20251330T143000Z").is_err());
+}
+
+#[test]
+fn test_structured_errors() {
+    use tempotime::Error;
+
+    assert_eq!(
+        DateTime::from_iso("2025-10-30X14:30:00Z").unwrap_err(),
+        Error::Expected {
+            expected: 'T',
+            position: 10
+        }
+    );
+    assert_eq!(
+        DateTime::from_iso("2025-10-30T14:3").unwrap_err(),
+        Error::UnexpectedEnd { position: 15 }
+    );
+    assert_eq!(
+        DateTime::from_iso("2025-04-31").unwrap_err(),
+        Error::InvalidComponent {
+            unit: "day",
+            value: 31
+        }
+    );
+    assert_eq!(
+        DateTime::from_object(&[("year", 10_000)]).unwrap_err(),
+        Error::InvalidYear(10_000)
+    );
+    assert_eq!(
+        DateTime::from_millis(i64::MAX).unwrap_err(),
+        Error::OutOfRange
+    );
+    assert_eq!(
+        DateTime::from_format("Oct 30", "MMM dd, yyyy").unwrap_err(),
+        Error::Expected {
+            expected: ',',
+            position: 6
+        }
+    );
+    assert_eq!(
+        DateTime::from_format("2025 at", "yyyy' at").unwrap_err(),
+        Error::UnterminatedLiteral
+    );
+
+    let dt = DateTime::from_iso("2025-10-30T12:00:00Z").unwrap();
+    assert_eq!(
+        dt.try_set_zone("Nowhere/Special").unwrap_err(),
+        Error::UnknownZone("Nowhere/Special".into())
+    );
+    assert_eq!(dt.try_set_zone("Asia/Tokyo").unwrap().hour(), 21);
+
+    let err: Box<dyn std::error::Error> = Box::new(Error::InvalidYear(10_000));
+    assert!(err.to_string().contains("10000"));
+}
+
+#[test]
+fn test_from_format_offsets() {
+    let expected = DateTime::from_iso("2025-10-30T14:30:00Z").unwrap();
+    let parse = |s: &str, fmt: &str| DateTime::from_format(s, fmt).unwrap();
+
+    assert_eq!(
+        parse("2025-10-30 16:30 +02:00", "yyyy-MM-dd HH:mm ZZ"),
+        expected
+    );
+    assert_eq!(
+        parse("2025-10-30 09:30 -0500", "yyyy-MM-dd HH:mm ZZZ"),
+        expected
+    );
+    assert_eq!(
+        parse("2025-10-30 20:00 +5:30", "yyyy-MM-dd HH:mm Z"),
+        expected
+    );
+    assert_eq!(
+        parse("2025-10-30 09:30 GMT-5", "yyyy-MM-dd HH:mm Z"),
+        expected
+    );
+    assert_eq!(
+        parse("2025-10-30 14:30 UTC", "yyyy-MM-dd HH:mm Z"),
+        expected
+    );
+    assert_eq!(parse("2025-10-30T14:30Z", "yyyy-MM-dd'T'HH:mmZ"), expected);
+
+    assert!(DateTime::from_format("2025-10-30 14:30 +25:00", "yyyy-MM-dd HH:mm ZZ").is_err());
+    assert!(DateTime::from_format("2025-10-30 14:30 EST", "yyyy-MM-dd HH:mm ZZ").is_err());
+}
+
+#[test]
+fn test_from_format_offset_zone() {
+    let dt = DateTime::from_format("2025-10-30 16:30 +02:00", "yyyy-MM-dd HH:mm ZZ").unwrap();
+    assert_eq!((dt.hour(), dt.minute()), (16, 30));
+    assert_eq!(dt.zone_name(), Some("UTC+02:00"));
+
+    let india = DateTime::from_format("2025-10-30 20:00 +05:30", "yyyy-MM-dd HH:mm ZZ").unwrap();
+    assert_eq!((india.hour(), india.minute()), (20, 0));
+    assert_eq!(india.zone_name(), Some("UTC+05:30"));
+    assert_eq!(india.to_format("ZZ"), "+05:30");
+    assert_eq!(india, DateTime::from_iso("2025-10-30T14:30:00Z").unwrap());
+}
+
+#[test]
+fn test_from_format_weekdays() {
+    use tempotime::{Error, ParseOptions, Weekday};
+
+    let expected = DateTime::from_iso("2025-10-30T00:00:00Z").unwrap();
+    assert_eq!(
+        DateTime::from_format("Thursday, October 30, 2025", "EEEE, MMMM dd, yyyy").unwrap(),
+        expected
+    );
+    assert_eq!(
+        DateTime::from_format("thu 30 Oct 2025", "EEE dd MMM yyyy").unwrap(),
+        expected
+    );
+    assert_eq!(
+        DateTime::from_format("4 2025-10-30", "E yyyy-MM-dd").unwrap(),
+        expected
+    );
+
+    // Not validated by default
+    assert_eq!(
+        DateTime::from_format("Mon 30 Oct 2025", "EEE dd MMM yyyy").unwrap(),
+        expected
+    );
+
+    let strict = ParseOptions {
+        validate_weekday: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        DateTime::from_format_with("Mon 30 Oct 2025", "EEE dd MMM yyyy", strict).unwrap_err(),
+        Error::WeekdayMismatch {
+            parsed: Weekday::Monday,
+            actual: Weekday::Thursday
+        }
+    );
+    assert_eq!(
+        DateTime::from_format("Thurs 30 Oct 2025", "EEEE dd MMM yyyy").unwrap_err(),
+        Error::Invalid {
+            what: "weekday",
+            position: 0
+        }
+    );
+}
+
+#[test]
+fn test_from_format_widths_and_lenient() {
+    use tempotime::ParseOptions;
+
+    // Single-letter tokens take one or two digits
+    let dt = DateTime::from_format("2025-3-14 9:05", "yyyy-M-d H:mm").unwrap();
+    assert_eq!(dt.to_format("yyyy-MM-dd HH:mm"), "2025-03-14 09:05");
+    let dt = DateTime::from_format("10/30/2025", "M/d/yyyy").unwrap();
+    assert_eq!(dt.to_format("yyyy-MM-dd"), "2025-10-30");
+
+    // Doubled tokens and single spaces stay strict by default
+    let messy = "Oct  5 2025  7:09:03";
+    assert!(DateTime::from_format(messy, "MMM dd yyyy HH:mm:ss").is_err());
+    assert!(DateTime::from_format("2025-10-5", "yyyy-MM-dd").is_err());
+
+    let lenient = ParseOptions {
+        lenient: true,
+        ..Default::default()
+    };
+    let dt = DateTime::from_format_with(messy, "MMM dd yyyy HH:mm:ss", lenient).unwrap();
+    assert_eq!(dt.to_format("yyyy-MM-dd HH:mm:ss"), "2025-10-05 07:09:03");
+    let dt =
+        DateTime::from_format_with("  2025-1-2\t3:4:5 ", "yyyy-MM-dd HH:mm:ss", lenient).unwrap();
+    assert_eq!(dt.to_format("yyyy-MM-dd HH:mm:ss"), "2025-01-02 03:04:05");
+    assert!(DateTime::from_format_with("2025-10-052025", "yyyy-MM-dd yyyy", lenient).is_err());
+}
+
+#[test]
+fn test_from_formats() {
+    use tempotime::Error;
+
+    let formats = [
+        "yyyy-MM-dd HH:mm",
+        "yyyy-MM-dd",
+        "MM/dd/yyyy",
+        "MMM d, yyyy",
+    ];
+    let parse = |s: &str| {
+        DateTime::from_formats(s, &formats)
+            .unwrap()
+            .to_format("yyyy-MM-dd HH:mm")
+    };
+    assert_eq!(parse("2025-10-30 14:30"), "2025-10-30 14:30");
+    assert_eq!(parse("2025-10-30"), "2025-10-30 00:00");
+    assert_eq!(parse("10/30/2025"), "2025-10-30 00:00");
+    assert_eq!(parse("Oct 5, 2025"), "2025-10-05 00:00");
+
+    // A format matching only a prefix of the input does not count
+    assert!(DateTime::from_format("2025-10-30 14:30", "yyyy-MM-dd").is_err());
+
+    let err = DateTime::from_formats("30.10.2025", &["yyyy-MM-dd", "MM/dd/yyyy"]).unwrap_err();
+    match &err {
+        Error::NoMatchingFormat(attempts) => {
+            assert_eq!(attempts.len(), 2);
+            assert_eq!(attempts[0].0, "yyyy-MM-dd");
+            assert_eq!(
+                attempts[1].1,
+                Error::Expected {
+                    expected: '/',
+                    position: 2
+                }
+            );
+        }
+        other => panic!("unexpected error: {:?}", other),
+    }
+    assert!(err.to_string().contains("'MM/dd/yyyy'"));
+    assert_eq!(
+        DateTime::from_formats("2025", &[]),
+        Err(Error::NoMatchingFormat(Vec::new()))
+    );
+}
+
+#[test]
+fn test_two_digit_year_cutoff() {
+    use tempotime::ParseOptions;
+
+    let year = |s: &str, options| {
+        DateTime::from_format_with(s, "yy-MM-dd", options)
+            .unwrap()
+            .year()
+    };
+    assert_eq!(year("85-06-01", ParseOptions::default()), 2085);
+
+    let historical = ParseOptions {
+        two_digit_year_cutoff: Some(70),
+        ..Default::default()
+    };
+    assert_eq!(year("85-06-01", historical), 1985);
+    assert_eq!(year("70-01-01", historical), 1970);
+    assert_eq!(year("69-12-31", historical), 2069);
+    assert_eq!(year("00-01-01", historical), 2000);
+}
+
+#[test]
+fn test_from_human() {
+    use tempotime::Error;
+
+    // Thursday
+    let now = DateTime::from_iso("2025-10-30T09:15:42Z").unwrap();
+    let parse = |s: &str| {
+        DateTime::from_human(s, &now)
+            .unwrap()
+            .to_format("EEE yyyy-MM-dd HH:mm:ss")
+    };
+
+    assert_eq!(parse("now"), "Thu 2025-10-30 09:15:42");
+    assert_eq!(parse("Today"), "Thu 2025-10-30 00:00:00");
+    assert_eq!(parse("yesterday at midnight"), "Wed 2025-10-29 00:00:00");
+    assert_eq!(parse("at 5:45 PM"), "Thu 2025-10-30 17:45:00");
+    assert_eq!(parse("tomorrow at 12am"), "Fri 2025-10-31 00:00:00");
+    assert_eq!(parse("thursday"), "Thu 2025-11-06 00:00:00");
+    assert_eq!(parse("next mon at 09:30"), "Mon 2025-11-03 09:30:00");
+    assert_eq!(parse("last Wednesday"), "Wed 2025-10-29 00:00:00");
+    assert_eq!(parse("in an hour"), "Thu 2025-10-30 10:15:42");
+    assert_eq!(parse("in 1 week"), "Thu 2025-11-06 09:15:42");
+    assert_eq!(parse("90 minutes ago"), "Thu 2025-10-30 07:45:42");
+
+    assert_eq!(
+        DateTime::from_human("", &now),
+        Err(Error::UnexpectedEnd { position: 0 })
+    );
+    assert_eq!(
+        DateTime::from_human("tomorrow at 25:00", &now),
+        Err(Error::InvalidComponent {
+            unit: "hour",
+            value: 25
+        })
+    );
+    assert_eq!(
+        DateTime::from_human("next fortnight", &now),
+        Err(Error::Invalid {
+            what: "expression",
+            position: 5
+        })
+    );
+    assert_eq!(
+        DateTime::from_human("in 2 parsecs", &now),
+        Err(Error::UnknownUnit("parsecs".into()))
+    );
+    assert_eq!(
+        DateTime::from_human("today please", &now),
+        Err(Error::Invalid {
+            what: "expression",
+            position: 6
+        })
+    );
+    assert_eq!(
+        DateTime::from_human("tomorrow at 13pm", &now),
+        Err(Error::Invalid {
+            what: "time",
+            position: 12
+        })
+    );
+    assert_eq!(
+        DateTime::from_human("in 9223372036854775807 years", &now),
+        Err(Error::OutOfRange)
+    );
+    assert_eq!(
+        DateTime::from_human("in 400000 years", &now),
+        Err(Error::OutOfRange)
+    );
+    assert_eq!(
+        DateTime::from_human("9223372036854775807 milliseconds ago", &now),
+        Err(Error::OutOfRange)
+    );
+}
+
+#[test]
+fn test_from_human_uses_zone_dates() {
+    // 05:00 on Friday in Tokyo
+    let now = DateTime::from_iso("2025-10-30T20:00:00Z")
+        .unwrap()
+        .set_zone("Asia/Tokyo");
+    let today = DateTime::from_human("today", &now).unwrap();
+    assert_eq!(today, DateTime::from_iso("2025-10-30T15:00:00Z").unwrap());
+    assert_eq!((today.day(), today.hour()), (31, 0));
+    assert_eq!(DateTime::from_human("next friday", &now).unwrap().day(), 7);
+}
+
+#[test]
+fn test_parse_auto_detects() {
+    use tempotime::Error;
+
+    let expected = DateTime::from_iso("2025-10-30T14:30:00Z").unwrap();
+    for s in [
+        "1761834600",
+        " 1761834600000 ",
+        "2025-10-30T16:30:00+02:00",
+        "20251030T143000Z",
+        "2025-10-30 14:30:00",
+        "Thu, 30 Oct 2025 10:30:00 EDT",
+        "30 Oct 2025 14:30 +0000",
+        "Thursday, 30-Oct-25 14:30:00 GMT",
+    ] {
+        assert_eq!(DateTime::parse(s).unwrap(), expected, "{}", s);
+    }
+
+    assert_eq!(
+        DateTime::parse("1761834600.25").unwrap().to_millis(),
+        1_761_834_600_250
+    );
+    assert_eq!(
+        DateTime::parse("-86400").unwrap().to_format("yyyy-MM-dd"),
+        "1969-12-31"
+    );
+    assert_eq!(
+        DateTime::parse("99999999999999999999"),
+        Err(Error::OutOfRange)
+    );
+
+    match DateTime::parse("next tuesday") {
+        Err(Error::NoMatchingFormat(attempts)) => {
+            let names: Vec<&str> = attempts.iter().map(|(name, _)| name.as_str()).collect();
+            assert_eq!(names, ["ISO 8601", "SQL", "RFC 2822", "HTTP date"]);
+        }
+        other => panic!("unexpected: {:?}", other),
+    }
+    assert!(DateTime::parse("1761834600.").is_err());
+}
+
+#[test]
+fn test_from_rfc2822() {
+    let dt = DateTime::from_rfc2822("Thu, 30 Oct 2025 16:30:00 +0200").unwrap();
+    assert_eq!(dt, DateTime::from_iso("2025-10-30T14:30:00Z").unwrap());
+    assert_eq!(
+        DateTime::from_rfc2822("1 Jan 99 00:00 -0100").unwrap(),
+        DateTime::from_iso("1999-01-01T01:00:00Z").unwrap()
+    );
+    assert!(DateTime::from_rfc2822("Thu, 31 Nov 2025 16:30:00 +0200").is_err());
+
+    // A non-ASCII zone is an error, not a panic
+    assert_eq!(
+        DateTime::from_rfc2822("30 Oct 2025 14:30:00 é123"),
+        Err(tempotime::Error::Invalid {
+            what: "zone",
+            position: 21
+        })
+    );
+    assert!(DateTime::parse("30 Oct 2025 14:30:00 é123").is_err());
+    assert!(DateTime::from_rfc2822("30 Oct 2025 14:30:00 +é12").is_err());
+}
+
+#[test]
+fn test_iso_strict_and_lenient() {
+    let bogus = "2025-13-45T99:99:99Z";
+    assert!(DateTime::from_iso(bogus).is_err());
+    assert!(DateTime::from_iso("2025-02-29T00:00:00Z").is_err());
+    assert!(DateTime::from_iso("2025-10-30T24:00:00Z").is_err());
+
+    let lenient = |s: &str| {
+        DateTime::from_iso_lenient(s)
+            .unwrap()
+            .to_format("yyyy-MM-dd HH:mm:ss.SSS")
+    };
+    assert_eq!(lenient(bogus), "2026-02-18 04:40:39.000");
+    assert_eq!(lenient("2025-02-29T00:00:00Z"), "2025-03-01 00:00:00.000");
+    assert_eq!(lenient("2025-00-00T00:00:00Z"), "2024-11-30 00:00:00.000");
+    assert_eq!(
+        lenient("2025-12-31T23:59:60.5+01:00"),
+        "2025-12-31 23:00:00.500"
+    );
+    assert_eq!(lenient("2025-10-30T14:30:00Z"), "2025-10-30 14:30:00.000");
+
+    assert!(DateTime::from_iso_lenient("9999-13-01").is_err());
+    assert!(DateTime::from_iso_lenient("2025-10-30T14:30").is_err());
+}
+
+#[test]
+fn test_tempo_format_macro() {
+    const ISO: &str = tempotime::tempo_format!("yyyy-MM-dd'T'HH:mm:ss");
+    assert_eq!(ISO, "yyyy-MM-dd'T'HH:mm:ss");
+
+    let dt = DateTime::from_iso("2025-10-30T14:05:00Z").unwrap();
+    assert_eq!(dt.to_format("yyyy-MM-dd HH:mm:ss"), "2025-10-30 14:05:00");
+    assert_eq!(
+        dt.to_format(tempotime::tempo_format!("EEE, MMM do yyyy G")),
+        "Thu, Oct 30th 2025 AD"
+    );
+    assert_eq!(tempotime::tempo_format!("h 'o''clock' a"), "h 'o''clock' a");
+    assert_eq!(
+        tempotime::tempo_format!("kkkk-WW iiii-nn DDD"),
+        "kkkk-WW iiii-nn DDD"
+    );
+}
+
+#[test]
+fn test_compiled_format() {
+    use tempotime::{Error, Format, ParseOptions};
+
+    let patterns = [
+        "yyyy-MM-dd HH:mm:ss.SSS",
+        "EEEE, MMMM do yyyy 'at' h:mm a",
+        "EEE dd MMM yy G",
+        "DDD D kkkk-WW iiii-nn",
+        "yyyy-MM-dd'T'HH:mm:ss",
+    ];
+    let dt = DateTime::from_iso("2025-10-30T14:05:09.042Z").unwrap();
+    for pattern in patterns {
+        let format = Format::compile(pattern).unwrap();
+        assert_eq!(format.pattern(), pattern);
+        assert_eq!(format.format(&dt), dt.to_format(pattern), "{}", pattern);
+    }
+
+    let format = Format::compile("MMM dd, yyyy 'at' h:mm a").unwrap();
+    for input in [
+        "Oct 30, 2025 at 2:05 pm",
+        "Oct 30, 2025 at 12:00 am",
+        "Oct 30 2025",
+    ] {
+        assert_eq!(
+            format.parse(input),
+            DateTime::from_format(input, format.pattern()),
+            "{}",
+            input
+        );
+    }
+    let lenient = ParseOptions {
+        lenient: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        format
+            .parse_with(" Oct  5, 2025 at 7:09 pm ", lenient)
+            .unwrap()
+            .to_format("yyyy-MM-dd HH:mm"),
+        "2025-10-05 19:09"
+    );
+
+    assert_eq!(Format::compile("yyyy 'at"), Err(Error::UnterminatedLiteral));
+}
+
+#[test]
+fn test_parse_many() {
+    use tempotime::{Error, Format};
+
+    let format = Format::compile("dd/MMM/yyyy:HH:mm:ss Z").unwrap();
+    let log: String = (0..1000)
+        .map(|i| format!("30/Oct/2025:14:{:02}:{:02} +0000\n", i / 60 % 60, i % 60))
+        .collect();
+    let parsed: Result<Vec<_>, _> = format.parse_many(log.lines()).collect();
+    let parsed = parsed.unwrap();
+    assert_eq!(parsed.len(), 1000);
+    assert_eq!(
+        parsed[0],
+        DateTime::from_iso("2025-10-30T14:00:00Z").unwrap()
+    );
+    assert_eq!(
+        parsed[999],
+        DateTime::from_iso("2025-10-30T14:16:39Z").unwrap()
+    );
+
+    let lines = [
+        "30/Oct/2025:14:00:00 +0200",
+        "30/Oct/2025 14:00:00 +0200",
+        "",
+    ];
+    let results: Vec<_> = format.parse_many(lines.iter().copied()).collect();
+    assert_eq!(
+        results[0].as_ref().unwrap().to_millis(),
+        DateTime::from_iso("2025-10-30T12:00:00Z")
+            .unwrap()
+            .to_millis()
+    );
+    assert_eq!(
+        results[1],
+        Err(Error::Expected {
+            expected: ':',
+            position: 11
+        })
+    );
+    assert_eq!(results[2], Err(Error::UnexpectedEnd { position: 0 }));
+
+    // Non-ASCII text where a field is expected is an error, not a panic
+    let lines = ["30/Oct/202é:14:00:00 +0000", "30/Oçt/2025:14:00:00 +0000"];
+    let results: Vec<_> = format.parse_many(lines.iter().copied()).collect();
+    assert_eq!(
+        results[0],
+        Err(Error::Invalid {
+            what: "year",
+            position: 7
+        })
+    );
+    assert_eq!(
+        results[1],
+        Err(Error::Invalid {
+            what: "month name",
+            position: 3
+        })
+    );
+    let full = Format::compile("MMMM d, yyyy").unwrap();
+    assert!(full.parse("Januaré 5, 2025").is_err());
+    assert!(DateTime::from_format("202é", "yy").is_err());
+}
+
+#[test]
+fn test_format_infer() {
+    use tempotime::Format;
+
+    let infer = |samples: &[&str]| Format::infer(samples).map(|f| f.pattern().to_string());
+    let cases: [(&[&str], &str); 10] = [
+        (
+            &["2025-10-30T14:30:00.123+02:00", "2025-10-31T08:00:00.000Z"],
+            "yyyy-MM-dd'T'HH:mm:ss.SSSZ",
+        ),
+        (&["2025-10-30T14:30:00"], "yyyy-MM-dd'T'HH:mm:ss"),
+        (
+            &["2025-10-30 14:30:00", "2025-10-30 14:31:00"],
+            "yyyy-MM-dd HH:mm:ss",
+        ),
+        (&["2025-10-30"], "yyyy-MM-dd"),
+        (&["10/30/2025", "01/02/2025"], "MM/dd/yyyy"),
+        (&["01/02/2025", "30/10/2025"], "dd/MM/yyyy"),
+        (&["10/30/2025 2:30 pm"], "MM/dd/yyyy h:mm a"),
+        (&["3/7/2025", "12/25/2025"], "M/d/yyyy"),
+        (
+            &[
+                "Thu, 30 Oct 2025 14:30:00 GMT",
+                "Fri, 31 Oct 2025 09:00:00 -0500",
+            ],
+            "EEE, d MMM yyyy HH:mm:ss Z",
+        ),
+        (&["1761834600000", "1761834601000"], "x"),
+    ];
+    for (samples, expected) in cases {
+        assert_eq!(infer(samples).as_deref(), Some(expected), "{:?}", samples);
+    }
+
+    assert_eq!(infer(&[]), None);
+    assert_eq!(infer(&["2025-10-30", "10/30/2025"]), None);
+    assert_eq!(infer(&["2025-02-30"]), None);
+    assert_eq!(infer(&["202é-10-30"]), None);
+    assert_eq!(infer(&["30 Oçt 2025 14:30:00 +0000"]), None);
+
+    let format = Format::infer(&["1761834600", "-86400"]).unwrap();
+    assert_eq!(format.pattern(), "X");
+    assert_eq!(
+        format.parse("1761834600"),
+        DateTime::from_iso("2025-10-30T14:30:00Z")
+    );
+    assert_eq!(
+        format.parse("-86400"),
+        DateTime::from_iso("1969-12-31T00:00:00Z")
+    );
+}
+
+#[test]
+fn test_from_format_epoch_tokens() {
+    use tempotime::Error;
+
+    let expected = DateTime::from_iso("2025-10-30T14:30:00.250Z").unwrap();
+    assert_eq!(DateTime::from_format("1761834600250", "x"), Ok(expected));
+    assert_eq!(
+        DateTime::from_format("ts=1761834600", "'ts='X"),
+        DateTime::from_iso("2025-10-30T14:30:00Z")
+    );
+    assert_eq!(
+        DateTime::from_format("ts=", "'ts='X"),
+        Err(Error::Invalid {
+            what: "timestamp",
+            position: 3
+        })
+    );
+    assert_eq!(
+        DateTime::from_format("99999999999999999", "X"),
+        Err(Error::OutOfRange)
+    );
+}
+
+#[test]
+fn test_offset_and_zone_tokens() {
+    let dt = DateTime::from_iso("2025-10-30T14:30:00Z").unwrap();
+    assert_eq!(dt.to_format("Z|ZZ|ZZZ|ZZZZ|z"), "+0|+00:00|+0000|UTC|UTC");
+    assert_eq!(
+        dt.to_format("yyyy-MM-dd HH:mm:ssZZ"),
+        "2025-10-30 14:30:00+00:00"
+    );
+    assert_eq!(
+        tempotime::Format::compile("HH:mm ZZZ").unwrap().format(&dt),
+        "14:30 +0000"
+    );
+}
+
+// Without tz, zones are fixed offsets; the wall clock and offset both follow them.
+#[cfg(not(feature = "tz"))]
+#[test]
+fn test_offset_and_zone_tokens_in_static_zones() {
+    let dt = DateTime::from_iso("2025-10-30T14:30:00Z").unwrap();
+    let tokyo = dt.set_zone("Asia/Tokyo");
+    assert_eq!(
+        tokyo.to_format("HH:mm Z|ZZ|ZZZ|ZZZZ|z"),
+        "23:30 +9|+09:00|+0900|JST|Asia/Tokyo"
+    );
+    let kolkata = dt.set_zone("asia/kolkata");
+    assert_eq!(
+        kolkata.to_format("HH:mm Z|ZZ|ZZZ|z"),
+        "20:00 +5:30|+05:30|+0530|Asia/Kolkata"
+    );
+    let new_york = dt.set_zone("America/New_York");
+    assert_eq!(
+        new_york.to_format("yyyy-MM-dd HH:mm:ssZZ"),
+        "2025-10-30 09:30:00-05:00"
+    );
+    assert_eq!(new_york.to_format("h:mm a ZZZZ"), "9:30 am EST");
+}
+
+#[cfg(feature = "tz")]
+#[test]
+fn test_format_in_iana_zones() {
+    let dt = DateTime::from_iso("2025-10-30T14:30:00Z").unwrap();
+    let tokyo = dt.set_zone("Asia/Tokyo");
+    assert_eq!(
+        tokyo.to_format("EEE HH:mm ZZ ZZZZ z"),
+        "Thu 23:30 +09:00 JST Asia/Tokyo"
+    );
+    assert_eq!(
+        tokyo
+            .set(&[("hour", 1)])
+            .unwrap()
+            .to_format("yyyy-MM-dd EEE"),
+        "2025-10-30 Thu"
+    );
+    let new_york = dt.set_zone("America/New_York");
+    assert_eq!(new_york.to_format("h:mm a ZZ ZZZZ"), "10:30 am -04:00 EDT");
+    let mut out = String::new();
+    new_york
+        .plus(&tempotime::Duration::from_object(&[("days", 3)]))
+        .format_into(&mut out, "MMM d HH:mm ZZZZ")
+        .unwrap();
+    // Days keep the time of day across the change back to standard time.
+    assert_eq!(out, "Nov 2 10:30 EST");
+}
+
+#[test]
+fn test_quarter_and_day_of_year_tokens() {
+    let dt = DateTime::from_iso("2025-10-30T14:30:00Z").unwrap();
+    assert_eq!(dt.to_format("Qq yyyy"), "Q4 2025");
+    assert_eq!(dt.to_format("qq"), "04");
+    assert_eq!(dt.to_format("o ooo DDD do"), "303 303 303 30th");
+
+    let jan = DateTime::from_iso("2024-01-05T00:00:00Z").unwrap();
+    assert_eq!(jan.to_format("q qq o ooo"), "1 01 5 005");
+    let quarters: Vec<String> = (1..=12)
+        .map(|m| jan.set(&[("month", m)]).unwrap().to_format("q"))
+        .collect();
+    assert_eq!(quarters.concat(), "111222333444");
+    let leap_end = DateTime::from_iso("2024-12-31T00:00:00Z").unwrap();
+    assert_eq!(leap_end.to_format("o"), "366");
+}
+
+#[test]
+fn test_iso_week_labels() {
+    let dt = DateTime::from_iso("2025-10-30T14:30:00Z").unwrap();
+    assert_eq!(dt.to_format("kkkk-'W'WW"), "2025-W44");
+    assert_eq!(dt.to_format("'Week' W 'of' kkkk"), "Week 44 of 2025");
+
+    // ISO week-years differ from calendar years around New Year.
+    let early = DateTime::from_iso("2027-01-01T00:00:00Z").unwrap();
+    assert_eq!(early.to_format("yyyy: kkkk-'W'WW"), "2027: 2026-W53");
+    let late = DateTime::from_iso("2024-12-30T00:00:00Z").unwrap();
+    assert_eq!(
+        late.to_format("yyyy: kkkk-'W'WW, kk-'W'W"),
+        "2024: 2025-W01, 25-W1"
+    );
+    assert_eq!(
+        tempotime::Format::compile("kkkk-'W'WW")
+            .unwrap()
+            .format(&dt),
+        "2025-W44"
+    );
+}
+
+#[test]
+fn test_uppercase_meridiem() {
+    let afternoon = DateTime::from_iso("2025-10-30T14:30:00Z").unwrap();
+    let midnight = DateTime::from_iso("2025-10-30T00:05:00Z").unwrap();
+    assert_eq!(afternoon.to_format("h:mm A"), "2:30 PM");
+    assert_eq!(midnight.to_format("hh:mm A|a"), "12:05 AM|am");
+
+    for input in ["2:30 PM", "2:30 pm", "2:30 Pm"] {
+        assert_eq!(
+            DateTime::from_format(input, "h:mm A")
+                .unwrap()
+                .to_format("HH:mm"),
+            "14:30",
+            "{}",
+            input
+        );
+    }
+    assert_eq!(
+        DateTime::from_format("12:05 AM", "hh:mm A")
+            .unwrap()
+            .to_format("HH:mm"),
+        "00:05"
+    );
+    assert!(DateTime::from_format("12:05 XM", "hh:mm A").is_err());
+}
+
+#[test]
+fn test_epoch_output_tokens() {
+    let dt = DateTime::from_iso("2025-10-30T14:30:00.250Z").unwrap();
+    assert_eq!(dt.to_format("X"), "1761834600");
+    assert_eq!(dt.to_format("{x}"), "{1761834600250}");
+    assert_eq!(dt.to_format("'ts='X.SSS"), "ts=1761834600.250");
+
+    let before_epoch = DateTime::from_millis(-1500).unwrap();
+    assert_eq!(before_epoch.to_format("X x"), "-2 -1500");
+
+    // The instant does not depend on the zone.
+    assert_eq!(dt.set_zone("Asia/Tokyo").to_format("X"), "1761834600");
+    for pattern in ["X", "x"] {
+        let seconds = DateTime::from_iso("2025-10-30T14:30:00Z").unwrap();
+        assert_eq!(
+            DateTime::from_format(&seconds.to_format(pattern), pattern),
+            Ok(seconds)
+        );
+    }
+}
+
+#[test]
+fn test_escaped_literals_round_trip() {
+    let dt = DateTime::from_iso("2025-10-30T14:00:00Z").unwrap();
+    let cases = [
+        ("h 'o''clock' a", "2 o'clock pm"),
+        ("EEE''yy", "Thu'25"),
+        ("'''quoted''' yyyy", "'quoted' 2025"),
+        ("'yyyy MM dd'", "yyyy MM dd"),
+        ("''", "'"),
+        ("yyyy''''", "2025''"),
+    ];
+    for (pattern, expected) in cases {
+        assert_eq!(dt.to_format(pattern), expected, "{}", pattern);
+    }
+
+    for pattern in [
+        "h 'o''clock' a, MMM d yyyy",
+        "''yy-MM-dd 'at' HH:mm",
+        "yyyy-MM-dd'T'HH:mm:ss",
+    ] {
+        let text = dt.to_format(pattern);
+        assert_eq!(
+            DateTime::from_format(&text, pattern),
+            Ok(dt),
+            "{} / {}",
+            pattern,
+            text
+        );
+    }
+
+    // Output keeps an unclosed literal; parsing rejects it.
+    assert_eq!(dt.to_format("yyyy 'at HH"), "2025 at HH");
+    assert_eq!(
+        DateTime::from_format("2025 at HH", "yyyy 'at HH"),
+        Err(tempotime::Error::UnterminatedLiteral)
+    );
+}
+
+#[test]
+fn test_to_relative() {
+    use tempotime::RelativeOptions;
+
+    let now = DateTime::from_iso("2025-10-30T12:00:00Z").unwrap();
+    let relative = |s: &str| DateTime::from_iso(s).unwrap().to_relative(&now);
+    let cases = [
+        ("2025-10-30T12:00:00Z", "just now"),
+        ("2025-10-30T11:59:59.500Z", "just now"),
+        ("2025-10-30T11:59:59Z", "1 second ago"),
+        ("2025-10-30T12:00:45Z", "in 45 seconds"),
+        ("2025-10-30T11:58:30Z", "1 minute ago"),
+        ("2025-10-30T09:00:00Z", "3 hours ago"),
+        ("2025-10-31T12:00:00Z", "in 1 day"),
+        ("2025-10-29T13:00:00Z", "23 hours ago"),
+        ("2025-11-13T12:00:00Z", "in 2 weeks"),
+        ("2025-11-30T12:00:00Z", "in 1 month"),
+        ("2025-11-30T11:59:59Z", "in 4 weeks"),
+        ("2024-10-30T12:00:00Z", "1 year ago"),
+        ("2024-10-30T12:00:01Z", "11 months ago"),
+        ("2030-04-30T12:00:00Z", "in 4 years"),
+    ];
+    for (iso, expected) in cases {
+        assert_eq!(relative(iso), expected, "{}", iso);
+    }
+
+    // Calendar months clamp at the end of shorter months.
+    let jan31 = DateTime::from_iso("2025-01-31T00:00:00Z").unwrap();
+    let feb28 = DateTime::from_iso("2025-02-28T00:00:00Z").unwrap();
+    assert_eq!(feb28.to_relative(&jan31), "in 1 month");
+    assert_eq!(jan31.to_relative(&feb28), "4 weeks ago");
+
+    let then = DateTime::from_iso("2025-10-28T16:00:00Z").unwrap();
+    assert_eq!(then.to_relative(&now), "1 day ago");
+    let round = RelativeOptions {
+        round: true,
+        ..Default::default()
+    };
+    assert_eq!(then.to_relative_with(&now, round), "2 days ago");
+    let hours = RelativeOptions {
+        max_unit: Some("hour"),
+        ..Default::default()
+    };
+    assert_eq!(then.to_relative_with(&now, hours), "44 hours ago");
+    let unknown = RelativeOptions {
+        max_unit: Some("fortnights"),
+        ..Default::default()
+    };
+    assert_eq!(then.to_relative_with(&now, unknown), "1 day ago");
+}
+
+#[test]
+fn test_to_relative_calendar() {
+    let now = DateTime::from_iso("2025-10-30T12:00:00Z").unwrap();
+    let calendar = |s: &str| DateTime::from_iso(s).unwrap().to_relative_calendar(&now);
+    let cases = [
+        ("2025-10-30T00:00:00Z", "today"),
+        ("2025-10-30T23:59:59Z", "today"),
+        ("2025-10-31T00:00:00Z", "tomorrow"),
+        ("2025-10-29T23:59:59Z", "yesterday"),
+        ("2025-10-01T00:00:00Z", "29 days ago"),
+        ("2025-10-31T23:00:00Z", "tomorrow"),
+        ("2025-11-01T00:00:00Z", "next month"),
+        ("2025-09-30T23:59:59Z", "last month"),
+        ("2025-12-25T00:00:00Z", "in 2 months"),
+        ("2025-01-01T00:00:00Z", "9 months ago"),
+        ("2026-01-01T00:00:00Z", "next year"),
+        ("2024-12-31T00:00:00Z", "last year"),
+        ("2028-10-30T00:00:00Z", "in 3 years"),
+    ];
+    for (iso, expected) in cases {
+        assert_eq!(calendar(iso), expected, "{}", iso);
+    }
+
+    // Calendar dates are read in the base's zone.
+    let tokyo_morning = DateTime::from_iso("2025-10-30T20:00:00Z")
+        .unwrap()
+        .set_zone("Asia/Tokyo");
+    let afternoon = DateTime::from_iso("2025-10-30T14:00:00Z").unwrap();
+    assert_eq!(afternoon.to_relative_calendar(&tokyo_morning), "yesterday");
+    assert_eq!(afternoon.to_relative_calendar(&now), "today");
+}
+
+#[test]
+fn test_locales() {
+    use tempotime::{DateTime, Locale};
+
+    let dt = DateTime::from_iso("2025-10-30T14:30:00Z").unwrap();
+    let fmt = "EEEE EEE, MMMM MMM do, h a A";
+    assert_eq!(
+        dt.to_format_with_locale(fmt, &Locale::EN),
+        dt.to_format(fmt)
+    );
+    assert_eq!(Locale::get("EN-us"), Some(Locale::EN));
+    assert_eq!(Locale::get("xx"), None);
+
+    let pirate = Locale {
+        code: "en-pirate",
+        months_short: [
+            "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+        ],
+        meridiem: ["ahoy", "avast"],
+        ..Locale::EN
+    };
+    Locale::register(pirate);
+    assert_eq!(Locale::get("en-PIRATE"), Some(pirate));
+    assert_eq!(
+        dt.to_format_with_locale("h A", &Locale::get("en-pirate").unwrap()),
+        "2 AVAST"
+    );
+    Locale::register(Locale {
+        meridiem: ["yo", "ho"],
+        ..pirate
+    });
+    assert_eq!(Locale::get("en-pirate").unwrap().meridiem, ["yo", "ho"]);
+}
+
+#[cfg(feature = "locales")]
+#[test]
+fn test_built_in_locales() {
+    use tempotime::{DateTime, Locale};
+
+    let dt = DateTime::from_iso("2025-03-01T09:05:00Z").unwrap();
+    assert_eq!(
+        dt.to_format_with_locale("EEEE, do MMMM yyyy", &Locale::DE),
+        "Samstag, 1. März 2025"
+    );
+    assert_eq!(
+        dt.to_format_with_locale("EEE d MMM", &Locale::FR),
+        "sam. 1 mars"
+    );
+    assert_eq!(
+        dt.to_format_with_locale("yyyy年MMMd日 EEEE a", &Locale::JA),
+        "2025年3月1日 土曜日 午前"
+    );
+    assert_eq!(
+        dt.to_format_with_locale("h:mm a", &Locale::ES),
+        "9:05 a. m."
+    );
+    assert_eq!(Locale::get("pt-BR"), Some(Locale::PT));
+    assert_eq!(Locale::get("ru").unwrap().months[2], "марта");
+}
+
+#[test]
+fn test_locale_presets() {
+    use tempotime::DateTime;
+
+    let dt = DateTime::from_iso("2025-10-30T14:30:05Z").unwrap();
+    assert_eq!(
+        dt.to_locale_string(DateTime::DATE_MED_WITH_WEEKDAY),
+        "Thu, Oct 30, 2025"
+    );
+    assert_eq!(
+        dt.to_locale_string(DateTime::TIME_24_WITH_SECONDS),
+        "14:30:05"
+    );
+    assert_eq!(
+        dt.to_locale_string(DateTime::TIME_24_WITH_SHORT_OFFSET),
+        "14:30:05 UTC"
+    );
+    assert_eq!(
+        dt.to_locale_string(DateTime::DATETIME_SHORT_WITH_SECONDS),
+        "10/30/2025, 2:30:05 pm"
+    );
+    assert_eq!(
+        dt.to_locale_string("DATETIME_HUGE_WITH_SECONDS"),
+        "Thursday, October 30, 2025 at 2:30:05 pm UTC"
+    );
+
+    let tokyo = dt.set_zone("Asia/Tokyo");
+    assert_eq!(
+        tokyo.to_locale_string(DateTime::DATETIME_MED),
+        "Oct 30, 2025, 11:30 pm"
+    );
+    assert_eq!(
+        tokyo.to_locale_string(DateTime::TIME_24_WITH_LONG_OFFSET),
+        "23:30:05 Asia/Tokyo"
+    );
+}
+
+#[test]
+fn test_to_locale_options() {
+    use tempotime::{DateTime, HourCycle, Locale, LocaleOptions, LocaleStyle};
+
+    let dt = DateTime::from_iso("2025-10-30T09:05:07Z").unwrap();
+    let with = |date_style, time_style, hour_cycle| {
+        dt.to_locale(LocaleOptions {
+            date_style,
+            time_style,
+            hour_cycle,
+            locale: None,
+        })
+    };
+    assert_eq!(with(None, None, HourCycle::H12), "10/30/25");
+    assert_eq!(
+        with(
+            Some(LocaleStyle::Short),
+            Some(LocaleStyle::Short),
+            HourCycle::H12
+        ),
+        "10/30/25, 9:05 am"
+    );
+    assert_eq!(
+        with(Some(LocaleStyle::Long), None, HourCycle::H12),
+        "October 30, 2025"
+    );
+    assert_eq!(
+        with(None, Some(LocaleStyle::Medium), HourCycle::H23),
+        "09:05:07"
+    );
+    assert_eq!(
+        with(
+            Some(LocaleStyle::Full),
+            Some(LocaleStyle::Long),
+            HourCycle::H23
+        ),
+        "Thursday, October 30, 2025 at 09:05:07 UTC"
+    );
+
+    let custom = Locale {
+        code: "en-x-caps",
+        months: [
+            "JANUARY",
+            "FEBRUARY",
+            "MARCH",
+            "APRIL",
+            "MAY",
+            "JUNE",
+            "JULY",
+            "AUGUST",
+            "SEPTEMBER",
+            "OCTOBER",
+            "NOVEMBER",
+            "DECEMBER",
+        ],
+        ..Locale::EN
+    };
+    let options = LocaleOptions {
+        date_style: Some(LocaleStyle::Long),
+        locale: Some(custom),
+        ..Default::default()
+    };
+    assert_eq!(dt.to_locale(options), "OCTOBER 30, 2025");
+}
+
+#[test]
+fn test_to_iso_with() {
+    use tempotime::{DateTime, IsoFormat, IsoOffset, IsoOptions};
+
+    let dt = DateTime::from_iso("2025-10-30T14:30:05.120Z").unwrap();
+    assert_eq!(
+        dt.to_iso_with(IsoOptions {
+            suppress_milliseconds: true,
+            ..Default::default()
+        }),
+        "2025-10-30T14:30:05.120Z"
+    );
+    assert_eq!(
+        dt.to_iso_with(IsoOptions {
+            suppress_seconds: true,
+            ..Default::default()
+        }),
+        "2025-10-30T14:30:05.120Z"
+    );
+    assert_eq!(
+        dt.to_iso_with(IsoOptions {
+            offset: IsoOffset::Omit,
+            ..Default::default()
+        }),
+        "2025-10-30T14:30:05.120"
+    );
+
+    let tokyo = dt.start_of("minute").set_zone("Asia/Tokyo");
+    let options = IsoOptions {
+        suppress_milliseconds: true,
+        ..Default::default()
+    };
+    assert_eq!(tokyo.to_iso_with(options), "2025-10-30T23:30:00+09:00");
+    assert_eq!(
+        tokyo.to_iso_with(IsoOptions {
+            suppress_seconds: true,
+            ..options
+        }),
+        "2025-10-30T23:30+09:00"
+    );
+    assert_eq!(
+        tokyo.to_iso_with(IsoOptions {
+            format: IsoFormat::Basic,
+            ..options
+        }),
+        "20251030T233000+0900"
+    );
+    assert_eq!(
+        DateTime::from_iso(&tokyo.to_iso_with(options)).unwrap(),
+        tokyo
+    );
+}
+
+#[test]
+fn test_iso_date_and_time() {
+    use tempotime::DateTime;
+
+    let dt = DateTime::from_iso("2025-12-31T20:05:09.007Z").unwrap();
+    assert_eq!(dt.to_iso_date(), "2025-12-31");
+    assert_eq!(dt.to_iso_time(), "20:05:09.007");
+    let tokyo = dt.set_zone("Asia/Tokyo");
+    assert_eq!(tokyo.to_iso_date(), "2026-01-01");
+    assert_eq!(tokyo.to_iso_time(), "05:05:09.007");
+    assert_eq!(tokyo.to_iso_week_date(), "2026-W01-4");
+    assert_eq!(
+        DateTime::from_iso(&dt.to_iso_date()).unwrap(),
+        dt.start_of("day")
+    );
+}
+
+#[test]
+fn test_format_to_slice() {
+    use tempotime::{DateTime, Error, IsoFormat, IsoOptions};
+
+    let dt = DateTime::from_iso("2025-10-30T14:30:00.500Z").unwrap();
+    let mut buf = [0u8; 64];
+    let len = dt
+        .format_to_slice(&mut buf, "EEEE, MMMM do yyyy h:mm a")
+        .unwrap();
+    assert_eq!(
+        std::str::from_utf8(&buf[..len]).unwrap(),
+        dt.to_format("EEEE, MMMM do yyyy h:mm a")
+    );
+
+    let mut exact = [0u8; 10];
+    assert_eq!(dt.format_to_slice(&mut exact, "yyyy-MM-dd"), Ok(10));
+    assert_eq!(
+        dt.format_to_slice(&mut exact, "yyyy-MM-dd HH"),
+        Err(Error::BufferTooSmall { needed: 13 })
+    );
+    assert_eq!(&exact, b"2025-10-30");
+
+    let basic = IsoOptions {
+        format: IsoFormat::Basic,
+        ..Default::default()
+    };
+    let len = dt.iso_to_slice(&mut buf, basic).unwrap();
+    assert_eq!(&buf[..len], dt.to_iso_with(basic).as_bytes());
+    let earliest = DateTime::from_iso("-9999-01-01T00:00:00Z")
+        .unwrap()
+        .set_zone("Asia/Kolkata");
+    let mut iso = [0u8; 30];
+    assert!(earliest
+        .iso_to_slice(&mut iso, IsoOptions::default())
+        .is_ok());
+    assert_eq!(
+        dt.iso_to_slice(&mut exact, IsoOptions::default()),
+        Err(Error::BufferTooSmall { needed: 24 })
+    );
+}
+
+#[test]
+fn test_render_template() {
+    use tempotime::{DateTime, Error};
+
+    let dt = DateTime::from_iso("2025-03-01T09:05:07.040Z").unwrap();
+    assert_eq!(
+        dt.render("{weekday_short}, {month_short} {day_ordinal} {year} (Q{quarter}) {hour12}:{minute}:{second}.{millisecond} {meridiem}").unwrap(),
+        "Sat, Mar 1st 2025 (Q1) 9:05:07.040 am",
+    );
+    assert_eq!(
+        dt.render("{month}/{ day }/{year} {zone}{offset}").unwrap(),
+        "3/1/2025 UTC+00:00"
+    );
+    assert_eq!(dt.render("{{year}} is {year}").unwrap(), "{year} is 2025");
+    assert_eq!(dt.render("no placeholders").unwrap(), "no placeholders");
+    assert_eq!(
+        dt.set_zone("Asia/Tokyo")
+            .render("{hour}:{minute} {zone}")
+            .unwrap(),
+        "18:05 Asia/Tokyo"
+    );
+
+    assert_eq!(
+        dt.render("on {date}"),
+        Err(Error::Invalid {
+            what: "placeholder",
+            position: 3
+        })
+    );
+    assert_eq!(
+        dt.render("a } b"),
+        Err(Error::Invalid {
+            what: "placeholder",
+            position: 2
+        })
+    );
+    assert_eq!(
+        dt.render("at {hour"),
+        Err(Error::UnexpectedEnd { position: 8 })
+    );
+}
+
+#[test]
+fn test_duration_operators() {
+    let start = DateTime::from_iso("2024-01-15T10:00:00Z").unwrap();
+    let month = Duration::from_object(&[("months", 1)]);
+    assert_eq!(start + month.clone(), start.plus(&month));
+    assert_eq!(start - &month, start.minus(&month));
+    assert_eq!((start + &month).month(), 2);
+    assert_eq!((start - &month).year(), 2023);
+    assert_eq!(start - month.clone() + month, start);
+
+    let end = start + Duration::from_object(&[("hours", 2), ("minutes", 30)]);
+    let elapsed = end - start;
+    assert_eq!(elapsed.as_unit("minutes"), 150);
+    assert_eq!((start - end).as_unit("milliseconds"), -9_000_000);
+    assert_eq!(start + elapsed, end);
+}
+
+#[test]
+fn test_typed_units() {
+    use tempotime::Unit;
+    let dt = DateTime::from_iso("2025-01-01T14:30:15.250Z").unwrap();
+    assert_eq!(dt.start_of_unit(Unit::Years), dt.start_of("year"));
+    assert_eq!(dt.start_of_unit(Unit::Hours), dt.start_of("hours"));
+    assert_eq!(dt.end_of_unit(Unit::Months), dt.end_of("month"));
+    assert_eq!(dt.start_of_unit(Unit::Milliseconds), dt);
+    assert_eq!(dt.start_of("dayz"), dt);
+
+    // Wednesday Jan 1st 2025 belongs to the week from Monday Dec 30th.
+    assert_eq!(
+        dt.start_of_unit(Unit::Weeks)
+            .to_format("yyyy-MM-dd HH:mm:ss.SSS"),
+        "2024-12-30 00:00:00.000"
+    );
+    assert_eq!(
+        dt.end_of("week").to_format("yyyy-MM-dd HH:mm:ss.SSS"),
+        "2025-01-05 23:59:59.999"
+    );
+
+    let later = DateTime::from_iso("2025-01-02T02:30:15.250Z").unwrap();
+    assert_eq!(later.diff_in(&dt, Unit::Hours), 12.0);
+    assert_eq!(later.diff_in(&dt, Unit::Days), later.diff(&dt, "days"));
+    assert_eq!(later.diff(&dt, "fortnights"), 0.0);
+}
+
+#[test]
+fn test_zone_name_and_offset() {
+    let utc = DateTime::from_iso("2025-07-01T12:00:00Z").unwrap();
+    assert_eq!(utc.zone_name(), None);
+    assert_eq!(utc.offset(), 0);
+
+    let tokyo = utc.set_zone("Asia/Tokyo");
+    assert_eq!(tokyo.zone_name(), Some("Asia/Tokyo"));
+    assert_eq!(tokyo.offset(), 540);
+
+    let kolkata = utc.set_zone("Asia/Kolkata");
+    assert_eq!(kolkata.offset(), 330);
+    assert_eq!(
+        kolkata.set_zone("Mars/Olympus_Mons").zone_name(),
+        Some("Asia/Kolkata")
+    );
+    assert!(utc.set_zone("America/New_York").offset() < 0);
+}
+
+#[cfg(feature = "tz")]
+#[test]
+fn test_dst_queries() {
+    let utc = DateTime::from_iso("2025-06-15T00:00:00Z").unwrap();
+    assert!(!utc.is_dst());
+    assert!(utc.next_dst_transition().is_none());
+
+    let sydney = utc.set_zone("Australia/Sydney");
+    assert!(!sydney.is_dst());
+    let (at, delta) = sydney.next_dst_transition().unwrap();
+    assert_eq!(at.to_format("yyyy-MM-dd HH:mm"), "2025-10-05 03:00");
+    assert_eq!(delta, Duration::hours(1));
+    assert!(at.is_dst());
+
+    // A transition instant counts as previous, not next.
+    let (back, _) = at.previous_dst_transition().unwrap();
+    assert_eq!(back, at);
+    let (after, delta) = at.next_dst_transition().unwrap();
+    assert_eq!(after.to_format("yyyy-MM-dd HH:mm"), "2026-04-05 02:00");
+    assert_eq!(delta, Duration::hours(-1));
+    assert_eq!(
+        after
+            .minus(&Duration::milliseconds(1))
+            .to_format("HH:mm:ss.SSS"),
+        "02:59:59.999"
+    );
+
+    assert!(utc
+        .set_zone("Asia/Kolkata")
+        .previous_dst_transition()
+        .is_none());
+}
+
+#[test]
+fn test_set_zone_keep_local_time() {
+    use tempotime::{Error, SetZoneOptions};
+
+    let keep = SetZoneOptions {
+        keep_local_time: true,
+    };
+    let utc = DateTime::from_iso("2025-10-30T09:00:00Z").unwrap();
+
+    let tokyo = utc.set_zone_with("Asia/Tokyo", keep).unwrap();
+    assert_eq!(tokyo.zone_name(), Some("Asia/Tokyo"));
+    assert_eq!(tokyo.to_format("yyyy-MM-dd HH:mm"), "2025-10-30 09:00");
+    assert_eq!(tokyo.to_millis(), utc.to_millis() - 9 * 3_600_000);
+
+    let kolkata = tokyo.set_zone_with("Asia/Kolkata", keep).unwrap();
+    assert_eq!(kolkata.to_format("yyyy-MM-dd HH:mm"), "2025-10-30 09:00");
+    assert_eq!(kolkata.to_millis(), tokyo.to_millis() + 210 * 60_000);
+
+    let converted = utc
+        .set_zone_with("Asia/Tokyo", SetZoneOptions::default())
+        .unwrap();
+    assert_eq!(converted, utc);
+    assert_eq!(converted.hour(), 18);
+
+    assert_eq!(
+        utc.set_zone_with("Mars/Olympus_Mons", keep),
+        Err(Error::UnknownZone("Mars/Olympus_Mons".to_string()))
+    );
+}
+
+#[test]
+fn test_from_object_in() {
+    use tempotime::{Disambiguation, Error};
+
+    let fields = [
+        ("year", 2025),
+        ("month", 10),
+        ("day", 30),
+        ("hour", 9),
+        ("minute", 15),
+    ];
+    let tokyo = DateTime::from_object_in(&fields, "Asia/Tokyo", Disambiguation::Reject).unwrap();
+    assert_eq!(tokyo.zone_name(), Some("Asia/Tokyo"));
+    assert_eq!(tokyo.to_format("yyyy-MM-dd HH:mm"), "2025-10-30 09:15");
+    assert_eq!(tokyo, DateTime::from_iso("2025-10-30T00:15:00Z").unwrap());
+
+    assert_eq!(
+        DateTime::from_object_in(&fields, "Mars/Olympus_Mons", Disambiguation::Shift),
+        Err(Error::UnknownZone("Mars/Olympus_Mons".to_string()))
+    );
+    assert!(DateTime::from_object_in(&[("month", 13)], "UTC", Disambiguation::Shift).is_err());
+}
+
+#[cfg(feature = "tz")]
+#[test]
+fn test_disambiguation_policies() {
+    use tempotime::{Disambiguation, Error, SetOptions};
+
+    let at = |day: i64, hour: i64, policy| {
+        let fields = [
+            ("year", 2025),
+            ("month", 3),
+            ("day", day),
+            ("hour", hour),
+            ("minute", 30),
+        ];
+        DateTime::from_object_in(&fields, "America/New_York", policy)
+            .map(|dt| dt.to_format("HH:mm ZZ"))
+    };
+    // 02:30 is skipped on 9 March
+    assert_eq!(
+        at(9, 2, Disambiguation::Earlier),
+        Ok("01:30 -05:00".to_string())
+    );
+    assert_eq!(
+        at(9, 2, Disambiguation::Later),
+        Ok("03:30 -04:00".to_string())
+    );
+    assert_eq!(
+        at(9, 2, Disambiguation::Shift),
+        Ok("03:30 -04:00".to_string())
+    );
+    assert_eq!(
+        at(9, 2, Disambiguation::Reject),
+        Err(Error::NonexistentLocalTime)
+    );
+    assert_eq!(
+        at(9, 3, Disambiguation::Reject),
+        Ok("03:30 -04:00".to_string())
+    );
+    assert_eq!(
+        at(8, 2, Disambiguation::Reject),
+        Ok("02:30 -05:00".to_string())
+    );
+
+    // 01:30 happens twice on 2 November
+    let dt = DateTime::from_iso("2025-11-02T12:00:00Z")
+        .unwrap()
+        .set_zone("America/New_York");
+    let set = |policy| {
+        let options = SetOptions {
+            disambiguation: policy,
+            ..Default::default()
+        };
+        dt.set_with(&[("hour", 1), ("minute", 30)], options)
+            .map(|dt| dt.to_format("HH:mm ZZ"))
+    };
+    assert_eq!(set(Disambiguation::Earlier), Ok("01:30 -04:00".to_string()));
+    assert_eq!(set(Disambiguation::Shift), Ok("01:30 -04:00".to_string()));
+    assert_eq!(set(Disambiguation::Later), Ok("01:30 -05:00".to_string()));
+    assert_eq!(set(Disambiguation::Reject), Err(Error::AmbiguousLocalTime));
+    assert_eq!(
+        dt.set(&[("hour", 1), ("minute", 30)]).unwrap().offset(),
+        -240
+    );
+}
+
+#[test]
+fn test_fixed_offset_zones() {
+    let utc = DateTime::from_iso("2025-10-30T12:00:00Z").unwrap();
+
+    let india = utc.set_zone("UTC+05:30");
+    assert_eq!(india.zone_name(), Some("UTC+05:30"));
+    assert_eq!(india.offset(), 330);
+    assert_eq!(
+        india.to_format("yyyy-MM-dd HH:mm ZZ"),
+        "2025-10-30 17:30 +05:30"
+    );
+    assert!(india.to_iso().ends_with("+05:30"));
+    assert_eq!(DateTime::from_iso(&india.to_iso()).unwrap(), utc);
+
+    let denver = utc.set_zone("-07:00");
+    assert_eq!(denver.zone_name(), Some("UTC-07:00"));
+    assert_eq!(denver.hour(), 5);
+    assert!(denver.to_iso().starts_with("2025-10-30T05:00:00"));
+    assert!(denver.to_iso().ends_with("-07:00"));
+
+    for spelling in ["+0530", "GMT+5:30", "utc+05:30"] {
+        assert_eq!(utc.set_zone(spelling).offset(), 330, "{}", spelling);
+    }
+    assert_eq!(utc.set_zone("+9").zone_name(), Some("UTC+09:00"));
+    for invalid in ["+25:00", "+05:30x", "05:30", "UTC+"] {
+        assert!(utc.try_set_zone(invalid).is_err(), "{}", invalid);
+    }
+}
+
+#[test]
+fn test_to_iso_is_the_same_in_every_build() {
+    let dt = DateTime::from_iso("2025-10-30T14:30:00.123Z").unwrap();
+    assert_eq!(dt.to_iso(), "2025-10-30T14:30:00.123Z");
+    assert_eq!(dt.set_zone("UTC").to_iso(), "2025-10-30T14:30:00.123Z");
+    assert_eq!(
+        dt.set_zone("+05:30").to_iso(),
+        "2025-10-30T20:00:00.123+05:30"
+    );
+    assert_eq!(
+        DateTime::from_iso(&dt.set_zone("-07:00").to_iso()).unwrap(),
+        dt
+    );
+    assert_eq!(
+        DateTime::from_iso("-0044-03-15T00:00:00Z")
+            .unwrap()
+            .to_iso(),
+        "-0044-03-15T00:00:00Z"
+    );
+}
+
+#[test]
+fn test_copy_semantics() {
+    let dt = DateTime::from_iso("2025-10-29T12:00:00Z").unwrap();
+    let later = dt.plus(&Duration::from_object(&[("hours", 1)]));
+    let start = dt.start_of("day");
+    assert!(start < dt && dt < later);
+    assert_eq!(dt.until(&later).start(), &dt);
+}
+
 #[test]
 fn test_to_format_full() {
     let dt = DateTime::from_iso("2025-10-29T14:05:09Z").unwrap();
@@ -159,7 +1695,7 @@ fn test_diff() {
 fn test_immutability() {
     let dt = DateTime::from_iso("2025-10-29T12:00:00Z").unwrap();
     let dur = Duration::from_object(&[("days", 1)]);
-    let _modified = dt.clone().plus(&dur);
+    let _modified = dt.plus(&dur);
     assert_eq!(dt.to_format("dd"), "29");
 }
 
@@ -183,3 +1719,579 @@ fn test_chainable_operations() {
 
     assert_eq!(result, "2025-03-16");
 }
+
+#[test]
+fn test_equality_ignores_zone() {
+    use std::collections::HashSet;
+
+    let utc = DateTime::from_iso("2025-10-30T12:00:00Z").unwrap();
+    let tokyo = utc.set_zone("Asia/Tokyo");
+    let later = DateTime::from_iso("2025-10-30T12:00:01Z").unwrap();
+
+    assert!(tokyo.same_instant_as(&utc));
+    assert_eq!(tokyo, utc);
+    assert!(!later.same_instant_as(&utc));
+
+    let set: HashSet<DateTime> = [utc, tokyo, later].into_iter().collect();
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn test_from_object_components() {
+    let dt = DateTime::from_object(&[
+        ("year", 2024),
+        ("month", 2),
+        ("day", 29),
+        ("hour", 23),
+        ("minute", 59),
+        ("second", 58),
+        ("millisecond", 7),
+    ])
+    .unwrap();
+    assert_eq!(
+        dt.to_format("yyyy-MM-dd HH:mm:ss.SSS"),
+        "2024-02-29 23:59:58.007"
+    );
+
+    let defaults = DateTime::from_object(&[("year", 2025)]).unwrap();
+    assert_eq!(
+        defaults.to_format("yyyy-MM-dd HH:mm:ss"),
+        "2025-01-01 00:00:00"
+    );
+}
+
+#[test]
+fn test_from_object_validation() {
+    assert!(DateTime::from_object(&[("year", 2025), ("month", 13)]).is_err());
+    assert!(DateTime::from_object(&[("year", 2025), ("month", 2), ("day", 29)]).is_err());
+    assert!(DateTime::from_object(&[("hour", 24)]).is_err());
+    assert!(DateTime::from_object(&[("minute", -1)]).is_err());
+    let err = DateTime::from_object(&[("dayz", 1)]).unwrap_err();
+    assert_eq!(err, tempotime::Error::UnknownUnit("dayz".to_string()));
+    assert!(err.to_string().contains("dayz"));
+}
+
+#[test]
+fn test_from_ymd_hms() {
+    let dt = DateTime::from_ymd_hms(2025, 10, 30, 14, 30, 5).unwrap();
+    assert_eq!(
+        dt.to_iso(),
+        DateTime::from_iso("2025-10-30T14:30:05Z").unwrap().to_iso()
+    );
+    assert!(DateTime::from_ymd_hms(2025, 4, 31, 0, 0, 0).is_err());
+}
+
+#[test]
+fn test_component_accessors() {
+    let dt = DateTime::from_format("2024-02-29 07:08:09.123", "yyyy-MM-dd HH:mm:ss.SSS").unwrap();
+    assert_eq!(
+        (
+            dt.year(),
+            dt.month(),
+            dt.day(),
+            dt.hour(),
+            dt.minute(),
+            dt.second(),
+            dt.millisecond()
+        ),
+        (2024, 2, 29, 7, 8, 9, 123)
+    );
+}
+
+#[test]
+fn test_component_accessors_follow_zone() {
+    // 20:00 UTC is 05:00 the next day in Tokyo (static map or IANA alike)
+    let dt = DateTime::from_iso("2025-10-30T20:00:00Z")
+        .unwrap()
+        .set_zone("Asia/Tokyo");
+    assert_eq!((dt.day(), dt.hour()), (31, 5));
+}
+
+#[test]
+fn test_weekday_day_of_year_quarter() {
+    use tempotime::Weekday;
+
+    let dt = DateTime::from_iso("2024-12-31T23:00:00Z").unwrap();
+    assert_eq!(dt.weekday(), Weekday::Tuesday);
+    assert_eq!(dt.day_of_year(), 366);
+    assert_eq!(dt.quarter(), 4);
+
+    let dt = DateTime::from_iso("2025-01-01T00:00:00Z").unwrap();
+    assert_eq!(dt.weekday(), Weekday::Wednesday);
+    assert_eq!(dt.day_of_year(), 1);
+    assert_eq!(dt.quarter(), 1);
+
+    // Read in the attached zone: still Dec 31 in New York
+    let ny = dt.set_zone("America/New_York");
+    assert_eq!(
+        (ny.weekday(), ny.day_of_year(), ny.quarter()),
+        (Weekday::Tuesday, 366, 4)
+    );
+}
+
+#[test]
+fn test_iso_week_number() {
+    // Monday 2024-12-30 opens ISO week 1 of 2025
+    let dt = DateTime::from_iso("2024-12-30T00:00:00Z").unwrap();
+    assert_eq!((dt.week_year(), dt.week_number()), (2025, 1));
+    assert_eq!(dt.year(), 2024);
+
+    // Sunday 2023-01-01 closes week 52 of 2022
+    let dt = DateTime::from_iso("2023-01-01T12:00:00Z").unwrap();
+    assert_eq!((dt.week_year(), dt.week_number()), (2022, 52));
+
+    let dt = DateTime::from_iso("2025-10-30T14:30:00Z").unwrap();
+    assert_eq!(dt.week_number(), 44);
+    assert_eq!(dt.to_format("kkkk-WW"), "2025-44");
+    assert_eq!(dt.to_format("kk W"), "25 44");
+
+    let dt = DateTime::from_iso("2025-01-06T00:00:00Z").unwrap();
+    assert_eq!(dt.to_format("W WW"), "2 02");
+}
+
+#[test]
+fn test_calendar_helpers() {
+    let dt = DateTime::from_iso("2023-02-15T12:00:00Z").unwrap();
+    assert_eq!(dt.days_in_month(), 28);
+    assert_eq!(dt.days_in_year(), 365);
+    assert!(!dt.is_in_leap_year());
+
+    // Century rules
+    assert!(DateTime::from_iso("2000-06-01T00:00:00Z")
+        .unwrap()
+        .is_in_leap_year());
+    assert!(!DateTime::from_iso("1900-06-01T00:00:00Z")
+        .unwrap()
+        .is_in_leap_year());
+
+    assert_eq!(
+        DateTime::from_iso("2025-04-01T00:00:00Z")
+            .unwrap()
+            .days_in_month(),
+        30
+    );
+    assert_eq!(
+        DateTime::from_iso("2025-12-01T00:00:00Z")
+            .unwrap()
+            .days_in_month(),
+        31
+    );
+}
+
+#[test]
+fn test_epoch_conversions() {
+    let dt = DateTime::from_millis(1_761_834_600_123).unwrap();
+    assert_eq!(
+        dt.to_format("yyyy-MM-dd HH:mm:ss.SSS"),
+        "2025-10-30 14:30:00.123"
+    );
+    assert_eq!(dt.to_millis(), 1_761_834_600_123);
+    assert_eq!(dt.to_seconds(), 1_761_834_600);
+    assert!((dt.to_seconds_f64() - 1_761_834_600.123).abs() < 1e-6);
+
+    assert_eq!(
+        DateTime::from_seconds(0)
+            .unwrap()
+            .to_format("yyyy-MM-dd HH:mm:ss"),
+        "1970-01-01 00:00:00"
+    );
+    assert_eq!(DateTime::from_seconds_f64(-0.5).unwrap().to_millis(), -500);
+    // Pre-epoch seconds round down
+    assert_eq!(DateTime::from_millis(-1).unwrap().to_seconds(), -1);
+
+    // The zone does not change the instant
+    let zoned = dt.set_zone("Asia/Tokyo");
+    assert_eq!(zoned.to_millis(), dt.to_millis());
+}
+
+#[test]
+fn test_epoch_conversions_reject_out_of_range() {
+    assert!(DateTime::from_millis(i64::MAX).is_err());
+    assert!(DateTime::from_seconds(i64::MIN).is_err());
+    assert!(DateTime::from_seconds_f64(f64::NAN).is_err());
+    assert!(DateTime::from_seconds_f64(f64::INFINITY).is_err());
+    assert!(DateTime::from_seconds(253_402_300_799).is_ok()); // 9999-12-31T23:59:59Z
+    assert!(DateTime::from_seconds(253_402_300_800).is_err());
+}
+
+#[test]
+fn test_set_components() {
+    use tempotime::{Overflow, SetOptions};
+
+    let dt = DateTime::from_iso("2024-03-31T10:15:30Z").unwrap();
+
+    let dt2 = dt
+        .set(&[("year", 2025), ("hour", 0), ("second", 0)])
+        .unwrap();
+    assert_eq!(dt2.to_format("yyyy-MM-dd HH:mm:ss"), "2025-03-31 00:15:00");
+
+    // Day overflow clamps by default, errors when asked to
+    let apr = dt.set(&[("month", 4)]).unwrap();
+    assert_eq!(apr.to_format("yyyy-MM-dd"), "2024-04-30");
+    let strict = SetOptions {
+        overflow: Overflow::Reject,
+        ..Default::default()
+    };
+    assert!(dt.set_with(&[("month", 4)], strict).is_err());
+    assert!(dt.set_with(&[("month", 5)], strict).is_ok());
+
+    assert!(dt.set(&[("month", 13)]).is_err());
+    assert!(dt.set(&[("hour", 24)]).is_err());
+    assert!(dt.set(&[("minute", -1)]).is_err());
+    assert!(dt.set(&[("fortnight", 1)]).is_err());
+}
+
+#[test]
+fn test_set_uses_wall_clock_in_zone() {
+    // 2025-10-30T20:00Z is 05:00 on Oct 31 in Tokyo
+    let tokyo = DateTime::from_iso("2025-10-30T20:00:00Z")
+        .unwrap()
+        .set_zone("Asia/Tokyo");
+    let nine = tokyo.set(&[("hour", 9)]).unwrap();
+    assert_eq!((nine.day(), nine.hour(), nine.minute()), (31, 9, 0));
+    assert_eq!(
+        nine.to_millis(),
+        DateTime::from_iso("2025-10-31T00:00:00Z")
+            .unwrap()
+            .to_millis()
+    );
+}
+
+#[test]
+fn test_with_setters() {
+    let dt = DateTime::from_iso("2024-02-29T08:00:00Z").unwrap();
+
+    let dt2 = dt
+        .with_month(3)
+        .and_then(|d| d.with_day(15))
+        .and_then(|d| d.with_hour(23))
+        .and_then(|d| d.with_minute(59))
+        .and_then(|d| d.with_second(58))
+        .and_then(|d| d.with_millisecond(7))
+        .unwrap();
+    assert_eq!(
+        dt2.to_format("yyyy-MM-dd HH:mm:ss.SSS"),
+        "2024-03-15 23:59:58.007"
+    );
+
+    // Feb 29 does not exist in 2025
+    assert!(dt.with_year(2025).is_err());
+    assert!(dt.with_year(2028).is_ok());
+    assert!(dt.with_day(30).is_err());
+    assert!(dt.with_month(0).is_err());
+    assert!(dt.with_hour(24).is_err());
+}
+
+#[test]
+fn test_min_max_clamp() {
+    use tempotime::Interval;
+
+    let a = DateTime::from_iso("2025-01-01T00:00:00Z").unwrap();
+    let b = DateTime::from_iso("2025-06-01T00:00:00Z").unwrap();
+    assert_eq!(DateTime::min(b, a), a);
+    assert_eq!(DateTime::max(b, a), b);
+
+    let window = Interval::from_date_times(a, b);
+    let early = DateTime::from_iso("2024-12-31T00:00:00Z").unwrap();
+    let inside = DateTime::from_iso("2025-03-01T00:00:00Z").unwrap();
+    let late = DateTime::from_iso("2025-06-01T00:00:01Z").unwrap();
+    assert_eq!(early.clamp(&window), a);
+    assert_eq!(inside.clamp(&window), inside);
+    assert_eq!(late.clamp(&window), b);
+
+    // Reversed endpoints still describe the same window
+    let reversed = Interval::from_date_times(b, a);
+    assert_eq!(inside.clamp(&reversed), inside);
+
+    let mut all = vec![b, inside, a];
+    all.sort();
+    assert_eq!(all, vec![a, inside, b]);
+}
+
+#[test]
+fn test_to_object_round_trips() {
+    use tempotime::Disambiguation;
+
+    let dt = DateTime::from_object(&[
+        ("year", 2024),
+        ("month", 2),
+        ("day", 29),
+        ("hour", 23),
+        ("minute", 59),
+        ("second", 58),
+        ("millisecond", 250),
+    ])
+    .unwrap();
+    let obj = dt.to_object();
+    assert_eq!(obj.len(), 7);
+    assert_eq!(obj["month"], 2);
+    assert_eq!(obj["millisecond"], 250);
+
+    let pairs: Vec<(&str, i64)> = obj.iter().map(|(k, v)| (k.as_str(), *v)).collect();
+    assert_eq!(DateTime::from_object(&pairs).unwrap(), dt);
+
+    // Zoned components are wall-clock; offset is in minutes
+    let ny = dt.set_zone("America/New_York").to_object();
+    assert_eq!((ny["day"], ny["hour"]), (29, 18));
+    assert_eq!(ny["offset"], -300);
+
+    // The zone is not in the map; it comes back through `from_object_in`
+    let ny = dt.set_zone("America/New_York");
+    let obj = ny.to_object();
+    let pairs: Vec<(&str, i64)> = obj
+        .iter()
+        .filter(|(k, _)| *k != "offset")
+        .map(|(k, v)| (k.as_str(), *v))
+        .collect();
+    let zone = ny.zone_name().unwrap();
+    let back = DateTime::from_object_in(&pairs, zone, Disambiguation::Reject).unwrap();
+    assert_eq!((back, back.zone_name()), (ny, Some(zone)));
+}
+
+#[test]
+fn test_checked_arithmetic() {
+    let dt = DateTime::from_iso("2025-10-30T12:00:00Z").unwrap();
+    let week = Duration::from_object(&[("weeks", 1)]);
+    assert_eq!(dt.checked_plus(&week), Some(dt.plus(&week)));
+    assert_eq!(dt.checked_minus(&week), Some(dt.minus(&week)));
+
+    // Last representable instant and one past it
+    let edge = DateTime::from_iso("9999-12-31T23:59:59Z").unwrap();
+    let second = Duration::from_object(&[("seconds", 1)]);
+    assert!(edge
+        .checked_plus(&Duration::from_object(&[("milliseconds", 999)]))
+        .is_some());
+    assert!(edge.checked_plus(&second).is_none());
+    assert!(edge
+        .checked_plus(&Duration::from_object(&[("years", 1)]))
+        .is_none());
+
+    let huge = Duration::from_object(&[("weeks", i64::MAX / 2)]);
+    assert!(dt.checked_plus(&huge).is_none());
+    assert!(dt.checked_minus(&huge).is_none());
+    assert!(dt
+        .checked_minus(&Duration::from_object(&[("milliseconds", i64::MIN)]))
+        .is_none());
+    assert!(dt
+        .checked_plus(&Duration::from_object(&[("months", i64::MIN)]))
+        .is_none());
+}
+
+#[test]
+fn test_plus_clamps_to_supported_range() {
+    let dt = DateTime::from_iso("2025-10-30T12:00:00Z").unwrap();
+    let max = DateTime::from_iso("9999-12-31T23:59:59.999Z").unwrap();
+    let min = DateTime::from_iso("-9999-01-01T00:00:00Z").unwrap();
+
+    let eons = Duration::from_object(&[("years", i64::MAX)]);
+    assert_eq!(dt.plus(&eons), max);
+    assert_eq!(dt.minus(&eons), min);
+    assert_eq!(dt.plus(&Duration::from_object(&[("years", 400_000)])), max);
+    assert_eq!(dt.minus(&Duration::from_object(&[("years", 400_000)])), min);
+    assert_eq!(
+        dt.plus(&Duration::from_object(&[("months", i64::MIN)])),
+        min
+    );
+
+    let forever = Duration::from_object(&[("milliseconds", i64::MAX)]);
+    let tokyo = dt.set_zone("Asia/Tokyo").plus(&forever);
+    assert_eq!(tokyo, max);
+    assert_eq!(tokyo.zone_name(), Some("Asia/Tokyo"));
+    assert_eq!(tokyo.to_iso(), "+10000-01-01T08:59:59.999+09:00");
+
+    // Whatever is printed reads back
+    let utc = dt.plus(&Duration::from_object(&[("years", 400_000)]));
+    assert_eq!(DateTime::from_iso(&utc.to_iso()).unwrap(), utc);
+    assert_eq!(DateTime::from_iso(&tokyo.to_iso()).unwrap(), tokyo);
+}
+
+#[test]
+fn test_constructors_enforce_year_range() {
+    assert!(DateTime::from_ymd_hms(9999, 12, 31, 23, 59, 59).is_ok());
+    assert!(DateTime::from_ymd_hms(10000, 1, 1, 0, 0, 0).is_err());
+    assert!(DateTime::from_ymd_hms(-10000, 1, 1, 0, 0, 0).is_err());
+    assert!(DateTime::from_object(&[("year", 12345)]).is_err());
+    assert!(DateTime::from_iso("2025-01-01T00:00:00Z")
+        .unwrap()
+        .with_year(10000)
+        .is_err());
+}
+
+#[test]
+fn test_negative_years() {
+    let ides = DateTime::from_iso("-0044-03-15T12:00:00Z").unwrap();
+    assert_eq!(ides.year(), -44);
+    assert_eq!(ides.to_format("yyyy-MM-dd"), "-0044-03-15");
+    assert_eq!(ides.to_format("yy G GG GGGGG"), "44 BC Before Christ B");
+    assert!(ides.to_iso().starts_with("-0044-03-15T12:00:00"));
+    assert_eq!(DateTime::from_iso(&ides.to_iso()).unwrap(), ides);
+
+    // Year 0 is 1 BC; year 1 is AD
+    let zero = DateTime::from_ymd_hms(0, 1, 1, 0, 0, 0).unwrap();
+    assert_eq!(zero.to_format("yyyy G"), "0000 BC");
+    assert!(zero.is_in_leap_year());
+    let one = DateTime::from_ymd_hms(1, 1, 1, 0, 0, 0).unwrap();
+    assert_eq!(one.to_format("yyyy G"), "0001 AD");
+    assert_eq!(
+        one.minus(&Duration::from_object(&[("days", 1)]))
+            .to_format("yyyy-MM-dd"),
+        "0000-12-31"
+    );
+    // Proleptic Gregorian, so not the Julian-calendar weekday
+    assert_eq!(ides.to_format("EEEE"), "Thursday");
+}
+
+#[test]
+fn test_from_iso_offsets_and_fractions() {
+    let utc = DateTime::from_iso("2025-10-30T14:30:00Z").unwrap();
+    assert_eq!(
+        DateTime::from_iso("2025-10-30T16:30:00+02:00").unwrap(),
+        utc
+    );
+    assert_eq!(DateTime::from_iso("2025-10-30T09:30:00-0500").unwrap(), utc);
+    assert_eq!(DateTime::from_iso("2025-10-30 14:30:00").unwrap(), utc);
+
+    let frac = DateTime::from_iso("2025-10-30T14:30:00.25Z").unwrap();
+    assert_eq!(frac.millisecond(), 250);
+
+    assert!(DateTime::from_iso("2025-10-30T14:30:00Z trailing").is_err());
+    assert!(DateTime::from_iso("+10000-01-01T00:00:00Z").is_err());
+}
+
+#[test]
+fn test_http_dates() {
+    let dt = DateTime::from_http_date("Sun, 06 Nov 1994 08:49:37 GMT").unwrap();
+    assert_eq!(dt.to_format("yyyy-MM-dd HH:mm:ss"), "1994-11-06 08:49:37");
+    assert_eq!(
+        DateTime::from_http_date("Sunday, 06-Nov-94 08:49:37 GMT").unwrap(),
+        dt
+    );
+    assert_eq!(
+        DateTime::from_http_date("Sun Nov  6 08:49:37 1994").unwrap(),
+        dt
+    );
+
+    // Output is GMT regardless of zone, and drops milliseconds
+    let zoned = DateTime::from_iso("2025-10-30T23:30:00.999Z")
+        .unwrap()
+        .set_zone("Asia/Tokyo");
+    assert_eq!(zoned.to_http_date(), "Thu, 30 Oct 2025 23:30:00 GMT");
+    assert_eq!(
+        DateTime::from_http_date(&zoned.to_http_date())
+            .unwrap()
+            .to_millis(),
+        zoned.to_millis() - 999
+    );
+
+    assert!(DateTime::from_http_date("Thu, 31 Feb 2025 00:00:00 GMT").is_err());
+    assert!(DateTime::from_http_date("2025-10-30T14:30:00Z").is_err());
+}
+
+#[test]
+fn test_sql_round_trip() {
+    let date_only = DateTime::from_sql("2025-10-30").unwrap();
+    assert_eq!(date_only.to_format("yyyy-MM-dd HH:mm"), "2025-10-30 00:00");
+
+    let dt = DateTime::from_sql("2025-10-30 14:30:00.123").unwrap();
+    assert_eq!(dt.to_sql(), "2025-10-30 14:30:00.123 +00:00");
+
+    // Offsets with and without minutes, with and without a separating space
+    let utc = DateTime::from_iso("2025-10-30T14:30:00Z").unwrap();
+    assert_eq!(DateTime::from_sql("2025-10-30 16:30:00+02").unwrap(), utc);
+    assert_eq!(
+        DateTime::from_sql("2025-10-30 10:00:00 -04:30").unwrap(),
+        utc
+    );
+
+    // Zoned output keeps the wall clock and the zone's offset
+    let tokyo = utc.set_zone("Asia/Tokyo");
+    assert_eq!(tokyo.to_sql(), "2025-10-30 23:30:00.000 +09:00");
+    assert_eq!(DateTime::from_sql(&tokyo.to_sql()).unwrap(), utc);
+
+    assert!(DateTime::from_sql("2025-02-30").is_err());
+    assert!(DateTime::from_sql("30/10/2025").is_err());
+}
+
+#[test]
+fn test_julian_day() {
+    let epoch = DateTime::from_millis(0).unwrap();
+    assert_eq!(epoch.to_julian_day(), 2_440_587.5);
+    assert_eq!(DateTime::from_julian_day(2_440_587.5).unwrap(), epoch);
+
+    // Julian day 0 is noon on 24 November 4714 BCE (proleptic Gregorian)
+    let jd0 = DateTime::from_julian_day(0.0).unwrap();
+    assert_eq!(jd0.to_format("yyyy-MM-dd HH:mm"), "-4713-11-24 12:00");
+    assert!(DateTime::from_julian_day(-2_000_000.0).is_err());
+    assert!(DateTime::from_julian_day(f64::NAN).is_err());
+
+    let dt = DateTime::from_iso("2025-10-30T18:00:00Z").unwrap();
+    assert_eq!(dt.to_julian_day(), 2_460_979.25);
+    assert_eq!(DateTime::from_julian_day(dt.to_julian_day()).unwrap(), dt);
+}
+
+#[test]
+fn test_ordinal_dates() {
+    let dt = DateTime::from_ordinal_date(2024, 366).unwrap();
+    assert_eq!(dt.to_format("yyyy-MM-dd"), "2024-12-31");
+    assert_eq!(dt.to_format("yyyy-DDD"), "2024-366");
+    assert_eq!(
+        DateTime::from_ordinal_date(2025, 32)
+            .unwrap()
+            .to_format("MM-dd DDD"),
+        "02-01 032"
+    );
+    assert!(DateTime::from_ordinal_date(2025, 0).is_err());
+    assert!(DateTime::from_ordinal_date(2025, 366).is_err());
+
+    // ISO ordinal form, with and without time
+    assert_eq!(
+        DateTime::from_iso("2025-300").unwrap(),
+        DateTime::from_ordinal_date(2025, 300).unwrap()
+    );
+    let timed = DateTime::from_iso("2025-300T14:30:00Z").unwrap();
+    assert_eq!(timed.to_format("yyyy-MM-dd HH:mm"), "2025-10-27 14:30");
+    assert!(DateTime::from_iso("2025-366T00:00:00Z").is_err());
+
+    // A bare calendar date is midnight UTC
+    assert_eq!(
+        DateTime::from_iso("2025-10-27")
+            .unwrap()
+            .to_format("DDD HH:mm"),
+        "300 00:00"
+    );
+}
+
+#[test]
+fn test_iso_week_dates() {
+    use tempotime::Weekday;
+
+    // 2020 has 53 ISO weeks; its last day is Sunday 2021-01-03
+    let dt = DateTime::from_iso_week_date(2020, 53, Weekday::Sunday).unwrap();
+    assert_eq!(dt.to_format("yyyy-MM-dd"), "2021-01-03");
+    assert_eq!(dt.to_iso_week_date(), "2020-W53-7");
+    assert!(DateTime::from_iso_week_date(2021, 53, Weekday::Monday).is_err());
+    assert!(DateTime::from_iso_week_date(2021, 0, Weekday::Monday).is_err());
+
+    assert_eq!(
+        DateTime::from_iso("2025-W44-4").unwrap(),
+        DateTime::from_iso("2025-10-30").unwrap()
+    );
+    let timed = DateTime::from_iso("2025-W01-1T08:00:00Z").unwrap();
+    assert_eq!(timed.to_format("yyyy-MM-dd HH:mm"), "2024-12-30 08:00");
+    assert_eq!(timed.to_iso_week_date(), "2025-W01-1");
+}
+
+#[test]
+fn test_until() {
+    let a = DateTime::from_iso("2025-10-30T00:00:00Z").unwrap();
+    let b = DateTime::from_iso("2025-10-30T06:00:00Z").unwrap();
+
+    let forward = a.until(&b);
+    let backward = b.until(&a);
+    assert_eq!((forward.start(), forward.end()), (&a, &b));
+    assert_eq!((backward.start(), backward.end()), (&a, &b));
+    assert_eq!(forward.length("hours").as_unit("hours"), 6);
+
+    let empty = a.until(&a);
+    assert!(empty.contains(&a));
+}
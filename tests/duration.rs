@@ -1,5 +1,13 @@
 use tempotime::Duration;
 
+// Compares durations unit by unit, since `==` only compares total lengths.
+macro_rules! assert_same {
+    ($left:expr, $right:expr $(,)?) => {{
+        let (left, right) = (&$left, &$right);
+        assert!(left.equals(right), "{:?} is not {:?}", left, right);
+    }};
+}
+
 #[test]
 fn test_from_object_single() {
     let dur = Duration::from_object(&[("days", 5)]);
@@ -31,8 +39,8 @@ fn test_to_object() {
 fn test_to_object_zero_values() {
     let dur = Duration::from_object(&[("days", 5)]);
     let obj = dur.to_object();
-    assert!(obj.get("weeks").is_none());
-    assert!(obj.get("hours").is_none());
+    assert!(!obj.contains_key("weeks"));
+    assert!(!obj.contains_key("hours"));
 }
 
 #[test]
@@ -89,3 +97,950 @@ fn test_large_values() {
     let days = dur.as_unit("days");
     assert!(days > 36000); // ~~~365 * 100
 }
+
+#[test]
+fn test_from_iso() {
+    let obj = |pairs: &[(&str, i64)]| Duration::from_object(pairs);
+    assert_same!(
+        Duration::from_iso("P1Y2M3W4DT5H6M7S").unwrap(),
+        obj(&[
+            ("years", 1),
+            ("months", 2),
+            ("weeks", 3),
+            ("days", 4),
+            ("hours", 5),
+            ("minutes", 6),
+            ("seconds", 7)
+        ])
+    );
+    assert_same!(Duration::from_iso("PT36H").unwrap(), obj(&[("hours", 36)]));
+    assert_same!(Duration::from_iso("P0D").unwrap(), Duration::default());
+    assert_same!(
+        Duration::from_iso("PT0.250S").unwrap(),
+        obj(&[("milliseconds", 250)])
+    );
+    assert_same!(
+        Duration::from_iso("PT1,5M").unwrap(),
+        obj(&[("minutes", 1), ("seconds", 30)])
+    );
+    assert_same!(
+        Duration::from_iso("P1.5Y").unwrap(),
+        obj(&[("years", 1), ("months", 6)])
+    );
+    assert_same!(
+        Duration::from_iso("P0.5W").unwrap(),
+        obj(&[("days", 3), ("hours", 12)])
+    );
+    assert_same!(
+        Duration::from_iso("PT0.7H").unwrap(),
+        obj(&[("minutes", 42)])
+    );
+}
+
+#[test]
+fn test_from_iso_negative() {
+    let obj = |pairs: &[(&str, i64)]| Duration::from_object(pairs);
+    assert_same!(
+        Duration::from_iso("-P1DT2H").unwrap(),
+        obj(&[("days", -1), ("hours", -2)])
+    );
+    assert_same!(
+        Duration::from_iso("P-1DT2H").unwrap(),
+        obj(&[("days", -1), ("hours", 2)])
+    );
+    assert_same!(Duration::from_iso("-P-3M").unwrap(), obj(&[("months", 3)]));
+    assert_same!(
+        Duration::from_iso("-PT1.5H").unwrap(),
+        obj(&[("hours", -1), ("minutes", -30)])
+    );
+}
+
+#[test]
+fn test_from_iso_errors() {
+    use tempotime::Error;
+
+    assert_eq!(
+        Duration::from_iso(""),
+        Err(Error::UnexpectedEnd { position: 0 })
+    );
+    assert_eq!(
+        Duration::from_iso("1D"),
+        Err(Error::Expected {
+            expected: 'P',
+            position: 0
+        })
+    );
+    assert_eq!(
+        Duration::from_iso("P"),
+        Err(Error::UnexpectedEnd { position: 1 })
+    );
+    assert_eq!(
+        Duration::from_iso("P1DT"),
+        Err(Error::UnexpectedEnd { position: 4 })
+    );
+    assert_eq!(
+        Duration::from_iso("P1H"),
+        Err(Error::Invalid {
+            what: "duration unit",
+            position: 2
+        })
+    );
+    assert_eq!(
+        Duration::from_iso("P1D2Y"),
+        Err(Error::Invalid {
+            what: "duration unit",
+            position: 4
+        })
+    );
+    assert_eq!(
+        Duration::from_iso("PT1H1H"),
+        Err(Error::Invalid {
+            what: "duration unit",
+            position: 5
+        })
+    );
+    assert_eq!(
+        Duration::from_iso("PTD"),
+        Err(Error::Invalid {
+            what: "duration component",
+            position: 2
+        })
+    );
+    assert_eq!(
+        Duration::from_iso("P1"),
+        Err(Error::UnexpectedEnd { position: 2 })
+    );
+    assert_eq!(
+        Duration::from_iso("P1.2.3D"),
+        Err(Error::Invalid {
+            what: "duration component",
+            position: 1
+        })
+    );
+    assert_eq!(
+        Duration::from_iso("P99999999999999999999D"),
+        Err(Error::Invalid {
+            what: "duration component",
+            position: 1
+        })
+    );
+    // Only the last component may have a fraction.
+    assert_eq!(
+        Duration::from_iso("PT0.5H15M"),
+        Err(Error::Invalid {
+            what: "duration component",
+            position: 2
+        })
+    );
+    assert_eq!(
+        Duration::from_iso("PT1.5M30S"),
+        Err(Error::Invalid {
+            what: "duration component",
+            position: 2
+        })
+    );
+    assert_eq!(
+        Duration::from_iso("P1.5DT2H"),
+        Err(Error::Invalid {
+            what: "duration component",
+            position: 1
+        })
+    );
+}
+
+#[test]
+fn test_to_iso() {
+    let obj = |pairs: &[(&str, i64)]| Duration::from_object(pairs);
+    assert_eq!(
+        obj(&[
+            ("years", 1),
+            ("months", 2),
+            ("weeks", 3),
+            ("days", 4),
+            ("hours", 5),
+            ("minutes", 6),
+            ("seconds", 7),
+            ("milliseconds", 8)
+        ])
+        .to_iso(),
+        "P1Y2M3W4DT5H6M7.008S"
+    );
+    assert_eq!(obj(&[("months", 1)]).to_iso(), "P1M");
+    assert_eq!(obj(&[("minutes", 1)]).to_iso(), "PT1M");
+    assert_eq!(obj(&[("milliseconds", 500)]).to_iso(), "PT0.5S");
+    assert_eq!(obj(&[("milliseconds", 1500)]).to_iso(), "PT1.5S");
+    assert_eq!(
+        obj(&[("days", -1), ("milliseconds", -20)]).to_iso(),
+        "-P1DT0.02S"
+    );
+    assert_eq!(obj(&[("days", 1), ("hours", -2)]).to_iso(), "P1DT-2H");
+    assert_eq!(
+        obj(&[("seconds", 1), ("milliseconds", -1500)]).to_iso(),
+        "-PT0.5S"
+    );
+    assert_eq!(
+        obj(&[("years", i64::MIN)]).to_iso(),
+        "-P9223372036854775808Y"
+    );
+}
+
+#[test]
+fn test_iso_round_trip() {
+    for iso in [
+        "P1Y2M10DT2H30M",
+        "PT0S",
+        "-P3W",
+        "P1DT-2H",
+        "PT0.001S",
+        "-PT1M30.5S",
+        "P4DT12H30M5S",
+    ] {
+        let dur = Duration::from_iso(iso).unwrap();
+        assert_eq!(dur.to_iso(), iso);
+        assert_same!(Duration::from_iso(&dur.to_iso()).unwrap(), dur);
+    }
+}
+
+#[test]
+fn test_plus_minus_negate_abs() {
+    let obj = |pairs: &[(&str, i64)]| Duration::from_object(pairs);
+    let a = obj(&[("months", 1), ("hours", 2)]);
+    let b = obj(&[("days", 30), ("hours", -3), ("milliseconds", 5)]);
+    assert_same!(
+        a.plus(&b),
+        obj(&[
+            ("months", 1),
+            ("days", 30),
+            ("hours", -1),
+            ("milliseconds", 5)
+        ])
+    );
+    assert_same!(
+        a.minus(&b),
+        obj(&[
+            ("months", 1),
+            ("days", -30),
+            ("hours", 5),
+            ("milliseconds", -5)
+        ])
+    );
+    assert_same!(a.plus(&b).minus(&b), a);
+    assert_same!(a.negate(), obj(&[("months", -1), ("hours", -2)]));
+    assert_same!(a.negate().negate(), a);
+    assert_eq!(a.plus(&a.negate()).as_unit("milliseconds"), 0);
+
+    assert_same!(a.negate().abs(), a);
+    assert_same!(a.abs(), a);
+    assert_same!(
+        obj(&[("days", 1), ("hours", -2)]).abs(),
+        obj(&[("days", 1), ("hours", -2)])
+    );
+    assert_same!(
+        obj(&[("days", -1), ("hours", 2)]).abs(),
+        obj(&[("days", 1), ("hours", -2)])
+    );
+}
+
+#[test]
+fn test_times_and_divided_by() {
+    let obj = |pairs: &[(&str, i64)]| Duration::from_object(pairs);
+    let dur = obj(&[("days", 1), ("hours", 3)]);
+    assert_same!(dur.times(2), obj(&[("days", 2), ("hours", 6)]));
+    assert_same!(dur.times(-1), dur.negate());
+    assert_same!(dur.times(0), Duration::default());
+    assert_same!(dur.divided_by(2), obj(&[("hours", 13), ("minutes", 30)]));
+    assert_same!(obj(&[("hours", 1)]).divided_by(3), obj(&[("minutes", 20)]));
+    assert_same!(
+        obj(&[("seconds", 1)]).divided_by(3),
+        obj(&[("milliseconds", 333)])
+    );
+    assert_same!(obj(&[("years", 1)]).divided_by(4), obj(&[("months", 3)]));
+    assert_same!(obj(&[("months", 1)]).divided_by(2), obj(&[("days", 15)]));
+    assert_same!(
+        obj(&[("minutes", -3)]).divided_by(2),
+        obj(&[("minutes", -1), ("seconds", -30)])
+    );
+    assert_same!(dur.times_f64(0.5), dur.divided_by(2));
+    assert_same!(dur.divided_by_f64(0.5), dur.times(2));
+    // Integer division is exact, whatever the size.
+    let big = Duration::milliseconds((1 << 53) + 1);
+    assert_same!(big.divided_by(1), big);
+    assert_same!(
+        obj(&[("milliseconds", i64::MAX)]).divided_by(-1),
+        obj(&[("milliseconds", -i64::MAX)])
+    );
+    assert_same!(
+        obj(&[("days", 1), ("milliseconds", 1)]).divided_by(2),
+        obj(&[("hours", 12), ("milliseconds", 1)])
+    );
+    assert_same!(
+        obj(&[("milliseconds", -3)]).divided_by(2),
+        obj(&[("milliseconds", -2)])
+    );
+}
+
+#[test]
+#[should_panic(expected = "division by zero")]
+fn test_divided_by_zero() {
+    let _ = Duration::from_object(&[("days", 1)]).divided_by(0);
+}
+
+#[test]
+fn test_normalize() {
+    let obj = |pairs: &[(&str, i64)]| Duration::from_object(pairs);
+    assert_same!(
+        obj(&[("minutes", 90)]).normalize(),
+        obj(&[("hours", 1), ("minutes", 30)])
+    );
+    assert_same!(
+        obj(&[("milliseconds", 90_061_001)]).normalize(),
+        obj(&[
+            ("days", 1),
+            ("hours", 1),
+            ("minutes", 1),
+            ("seconds", 1),
+            ("milliseconds", 1)
+        ])
+    );
+    assert_same!(obj(&[("days", 10)]).normalize(), obj(&[("days", 10)]));
+    assert_same!(
+        obj(&[("weeks", 1), ("days", 10)]).normalize(),
+        obj(&[("weeks", 2), ("days", 3)])
+    );
+    assert_same!(
+        obj(&[("months", 14)]).normalize(),
+        obj(&[("years", 1), ("months", 2)])
+    );
+    assert_same!(
+        obj(&[("years", 1), ("months", -3)]).normalize(),
+        obj(&[("months", 9)])
+    );
+    assert_same!(
+        obj(&[("hours", -1), ("minutes", 15)]).normalize(),
+        obj(&[("minutes", -45)])
+    );
+    assert_same!(
+        obj(&[("months", 1), ("days", -2)]).normalize(),
+        obj(&[("months", 1), ("days", -2)])
+    );
+    assert_same!(Duration::default().normalize(), Duration::default());
+}
+
+#[test]
+fn test_shift_to() {
+    use tempotime::Unit;
+
+    let obj = |pairs: &[(&str, i64)]| Duration::from_object(pairs);
+    let dur = obj(&[("hours", 2), ("minutes", 30), ("seconds", 40)]);
+    assert_same!(dur.shift_to(&[Unit::Minutes]), obj(&[("minutes", 151)]));
+    assert_same!(
+        dur.shift_to(&[Unit::Seconds, Unit::Minutes]),
+        obj(&[("minutes", 150), ("seconds", 40)])
+    );
+    assert_same!(
+        dur.shift_to(&[Unit::Days, Unit::Hours]),
+        obj(&[("hours", 3)])
+    );
+    assert_same!(dur.shift_to(&[]), dur);
+    assert_same!(
+        obj(&[("years", 2)]).shift_to(&[Unit::Months]),
+        obj(&[("months", 24)])
+    );
+    assert_same!(
+        obj(&[("years", 1)]).shift_to(&[Unit::Days]),
+        obj(&[("days", 365)])
+    );
+    assert_same!(
+        obj(&[("months", 18)]).shift_to(&[Unit::Years, Unit::Days]),
+        obj(&[("years", 1), ("days", 180)])
+    );
+    assert_same!(
+        obj(&[("days", 400)]).shift_to(&[Unit::Years, Unit::Months, Unit::Days]),
+        obj(&[("years", 1), ("months", 1), ("days", 5)])
+    );
+    assert_same!(
+        obj(&[("days", -10)]).shift_to(&[Unit::Weeks, Unit::Days]),
+        obj(&[("weeks", -1), ("days", -3)])
+    );
+    assert_same!(
+        obj(&[("minutes", -89)]).shift_to(&[Unit::Hours]),
+        obj(&[("hours", -1)])
+    );
+    assert_same!(
+        obj(&[("minutes", -90)]).shift_to(&[Unit::Hours]),
+        obj(&[("hours", -2)])
+    );
+}
+
+#[test]
+fn test_unit_names() {
+    use tempotime::Unit;
+
+    for unit in Unit::ALL {
+        assert_eq!(unit.name().parse::<Unit>().unwrap(), unit);
+        assert_eq!(
+            unit.name().trim_end_matches('s').parse::<Unit>().unwrap(),
+            unit
+        );
+    }
+    assert!(Unit::Years < Unit::Milliseconds);
+    assert_eq!(
+        "fortnight".parse::<Unit>(),
+        Err(tempotime::Error::UnknownUnit("fortnight".to_string()))
+    );
+}
+
+#[test]
+fn test_as_unit_f64_and_floor() {
+    let dur = Duration::from_object(&[("minutes", 90)]);
+    assert_eq!(dur.as_unit_f64("hours"), 1.5);
+    assert_eq!(dur.as_unit_f64("hour"), 1.5);
+    assert_eq!(dur.as_unit_f64("days"), 1.5 / 24.0);
+    assert_eq!(dur.as_unit_f64("seconds"), 5400.0);
+    assert_eq!(dur.as_unit_f64("fortnights"), 0.0);
+    assert_eq!(
+        Duration::from_object(&[("days", 45)]).as_unit_f64("months"),
+        1.5
+    );
+
+    assert_eq!(dur.as_unit_floor("hours"), 1);
+    assert_eq!(dur.negate().as_unit_floor("hours"), -2);
+    assert_eq!(dur.negate().as_unit("hours"), -1);
+    assert_eq!(dur.as_unit_floor("fortnights"), 0);
+}
+
+#[test]
+fn test_to_human() {
+    use tempotime::HumanOptions;
+
+    let obj = |pairs: &[(&str, i64)]| Duration::from_object(pairs);
+    let compact = HumanOptions {
+        compact: true,
+        ..Default::default()
+    };
+    let dur = obj(&[
+        ("years", 1),
+        ("months", 2),
+        ("weeks", 1),
+        ("days", 3),
+        ("seconds", 1),
+        ("milliseconds", 250),
+    ]);
+    assert_eq!(
+        dur.to_human(),
+        "1 year, 2 months, 1 week, 3 days, 1 second, 250 milliseconds"
+    );
+    assert_eq!(dur.to_human_with(compact), "1y 2mo 1w 3d 1s 250ms");
+    assert_eq!(
+        dur.to_human_with(HumanOptions {
+            max_units: Some(3),
+            ..Default::default()
+        }),
+        "1 year, 2 months, 1 week"
+    );
+    assert_eq!(
+        obj(&[("minutes", 90)]).normalize().to_human(),
+        "1 hour, 30 minutes"
+    );
+    assert_eq!(obj(&[("hours", -1)]).to_human(), "-1 hour");
+    assert_eq!(Duration::default().to_human(), "0 seconds");
+    assert_eq!(Duration::default().to_human_with(compact), "0s");
+}
+
+#[test]
+fn test_display_is_iso() {
+    let dur = Duration::from_object(&[("days", 3), ("hours", 4)]);
+    assert_eq!(dur.to_string(), "P3DT4H");
+    assert_eq!(
+        format!("retry after {}", Duration::default()),
+        "retry after PT0S"
+    );
+}
+
+#[test]
+fn test_ordering_by_length() {
+    let obj = |pairs: &[(&str, i64)]| Duration::from_object(pairs);
+    assert_eq!(obj(&[("hours", 1)]), obj(&[("minutes", 60)]));
+    assert_eq!(obj(&[("months", 1)]), obj(&[("days", 30)]));
+    assert_eq!(obj(&[("years", 1)]), obj(&[("days", 365)]));
+    assert_eq!(obj(&[("days", 1), ("hours", -24)]), Duration::default());
+    assert!(!obj(&[("hours", 1)]).equals(&obj(&[("minutes", 60)])));
+
+    let first = obj(&[("seconds", 30)]);
+    let second = obj(&[("minutes", 1), ("seconds", -45)]);
+    assert!(second < first);
+    assert_same!(first.clone().max(second.clone()), first);
+
+    let mut delays = [
+        obj(&[("weeks", 1)]),
+        obj(&[("hours", -2)]),
+        obj(&[("months", 1)]),
+        obj(&[("days", 8)]),
+    ];
+    delays.sort();
+    let hours: Vec<i64> = delays.iter().map(|d| d.as_unit("hours")).collect();
+    assert_eq!(hours, vec![-2, 168, 192, 720]);
+}
+
+#[test]
+fn test_std_duration_conversions() {
+    use std::time::Duration as StdDuration;
+    use tempotime::Error;
+
+    let dur = Duration::from(StdDuration::from_micros(2_500_999));
+    assert_same!(dur, Duration::from_object(&[("milliseconds", 2500)]));
+    assert_eq!(
+        Duration::from(StdDuration::MAX).as_unit("milliseconds"),
+        i64::MAX
+    );
+
+    let dur = Duration::from_object(&[("days", 1), ("hours", -1)]);
+    assert_eq!(dur.to_std(), Ok(StdDuration::from_secs(23 * 3600)));
+    assert_eq!(
+        Duration::from_object(&[("months", 1)]).to_std(),
+        Ok(StdDuration::from_secs(30 * 86_400))
+    );
+    assert_eq!(Duration::default().to_std(), Ok(StdDuration::ZERO));
+    assert_eq!(
+        Duration::from_object(&[("milliseconds", -1)]).to_std(),
+        Err(Error::NegativeDuration)
+    );
+    assert_eq!(
+        Duration::from_object(&[("years", i64::MAX)]).to_std(),
+        Ok(StdDuration::from_millis(u64::MAX))
+    );
+}
+
+#[test]
+fn test_add_sub_operators() {
+    let a = Duration::from_object(&[("hours", 1), ("minutes", 30)]);
+    let b = Duration::from_object(&[("minutes", 45)]);
+    assert_same!(
+        a.clone() + b.clone(),
+        Duration::from_object(&[("hours", 1), ("minutes", 75)])
+    );
+    assert_same!(
+        a - b,
+        Duration::from_object(&[("hours", 1), ("minutes", -15)])
+    );
+}
+
+#[test]
+fn test_between_calendar_units() {
+    use tempotime::{DateTime, Unit};
+    let at = |iso: &str| DateTime::from_iso(iso).unwrap();
+    let ymd = [Unit::Years, Unit::Months, Unit::Days];
+
+    let dur = Duration::between(
+        &at("2023-01-15T00:00:00Z"),
+        &at("2024-03-28T00:00:00Z"),
+        &ymd,
+    );
+    assert_same!(
+        dur,
+        Duration::from_object(&[("years", 1), ("months", 2), ("days", 13)])
+    );
+
+    // Month ends clamp, so Jan 31st plus one month is Feb 29th in 2024.
+    let dur = Duration::between(
+        &at("2024-01-31T00:00:00Z"),
+        &at("2024-03-01T00:00:00Z"),
+        &ymd,
+    );
+    assert_same!(dur, Duration::from_object(&[("months", 1), ("days", 1)]));
+
+    // A month is not complete until the time of day is reached too.
+    let dur = Duration::between(
+        &at("2024-01-15T12:00:00Z"),
+        &at("2024-02-15T11:00:00Z"),
+        &[Unit::Months, Unit::Hours],
+    );
+    assert_same!(dur, Duration::from_object(&[("hours", 743)]));
+
+    let dur = Duration::between(
+        &at("2024-03-28T00:00:00Z"),
+        &at("2023-01-15T00:00:00Z"),
+        &ymd,
+    );
+    assert_same!(
+        dur,
+        Duration::from_object(&[("years", -1), ("months", -2), ("days", -13)])
+    );
+
+    // Feb 29th plus three years clamps to Feb 28th 2023, a year short of the end.
+    let dur = Duration::between(
+        &at("2020-02-29T00:00:00Z"),
+        &at("2024-02-28T00:00:00Z"),
+        &[Unit::Years, Unit::Days],
+    );
+    assert_same!(dur, Duration::from_object(&[("years", 3), ("days", 365)]));
+}
+
+#[test]
+fn test_between_time_units() {
+    use tempotime::{DateTime, Unit};
+    let start = DateTime::from_iso("2024-05-01T08:00:00Z").unwrap();
+    let end = DateTime::from_iso("2024-05-03T09:30:15.250Z").unwrap();
+
+    let dur = Duration::between(&start, &end, &[Unit::Hours, Unit::Minutes]);
+    assert_same!(
+        dur,
+        Duration::from_object(&[("hours", 49), ("minutes", 30)])
+    );
+    let dur = Duration::between(
+        &start,
+        &end,
+        &[Unit::Days, Unit::Seconds, Unit::Milliseconds],
+    );
+    assert_same!(
+        dur,
+        Duration::from_object(&[("days", 2), ("seconds", 5415), ("milliseconds", 250)])
+    );
+    let dur = Duration::between(&start, &end, &[Unit::Weeks]);
+    assert_same!(dur, Duration::default());
+    assert_same!(
+        Duration::between(&start, &end, &[]),
+        Duration::from_object(&[("milliseconds", 178_215_250)])
+    );
+}
+
+#[test]
+fn test_overflow_saturates_or_errors() {
+    use tempotime::Error;
+    let obj = |pairs: &[(&str, i64)]| Duration::from_object(pairs);
+    let huge = obj(&[("years", i64::MAX)]);
+    assert_eq!(huge.as_unit("years"), i64::MAX);
+    assert_eq!(huge.as_unit("milliseconds"), i64::MAX);
+    assert_eq!(obj(&[("years", i64::MIN)]).as_unit("days"), i64::MIN);
+    assert_eq!(
+        obj(&[("years", 1_000_000_000)]).as_unit("years"),
+        1_000_000_000
+    );
+    assert_eq!(
+        obj(&[("weeks", i64::MAX), ("weeks", -1)]).as_unit_floor("weeks"),
+        -1
+    );
+    assert!(huge.as_unit_f64("years") > 9.2e18);
+
+    let year = obj(&[("years", 1)]);
+    assert_same!(huge.plus(&year), huge);
+    assert_same!(
+        obj(&[("days", i64::MIN)]).negate(),
+        obj(&[("days", i64::MAX)])
+    );
+    assert_same!(huge.times(2), huge);
+    let long = obj(&[("minutes", 1), ("seconds", i64::MAX)]);
+    assert_same!(
+        long.times_f64(1.5),
+        obj(&[("minutes", 1), ("seconds", i64::MAX)])
+    );
+    assert_eq!(long.divided_by_f64(0.5).as_unit("seconds"), i64::MAX);
+    assert_eq!(huge.checked_plus(&year), Err(Error::Overflow));
+    assert_eq!(
+        obj(&[("hours", i64::MIN)]).checked_minus(&obj(&[("hours", 1)])),
+        Err(Error::Overflow)
+    );
+    assert_eq!(
+        obj(&[("seconds", i64::MAX / 2 + 1)]).checked_times(2),
+        Err(Error::Overflow)
+    );
+    assert_same!(year.checked_times(3).unwrap(), obj(&[("years", 3)]));
+    assert_same!(year.checked_minus(&year).unwrap(), Duration::default());
+    assert!(huge.abs().equals(&huge));
+}
+
+#[test]
+fn test_from_object_f64() {
+    let obj = |pairs: &[(&str, i64)]| Duration::from_object(pairs);
+    assert_same!(
+        Duration::from_object_f64(&[("hours", 1.5)]),
+        obj(&[("hours", 1), ("minutes", 30)])
+    );
+    assert_same!(
+        Duration::from_object_f64(&[("days", 2.0), ("minutes", 3.0)]),
+        obj(&[("days", 2), ("minutes", 3)])
+    );
+    assert_same!(
+        Duration::from_object_f64(&[("years", 1.5)]),
+        obj(&[("years", 1), ("months", 6)])
+    );
+    assert_same!(
+        Duration::from_object_f64(&[("months", 0.5)]),
+        obj(&[("days", 15)])
+    );
+    assert_same!(
+        Duration::from_object_f64(&[("weeks", 1.5)]),
+        obj(&[("weeks", 1), ("days", 3), ("hours", 12)])
+    );
+    assert_same!(
+        Duration::from_object_f64(&[("minutes", -2.25)]),
+        obj(&[("minutes", -2), ("seconds", -15)])
+    );
+    assert_same!(
+        Duration::from_object_f64(&[("milliseconds", 2.6)]),
+        obj(&[("milliseconds", 3)])
+    );
+    assert_same!(
+        Duration::from_object_f64(&[("hour", 0.5), ("hours", 0.5)]),
+        obj(&[("minutes", 60)])
+    );
+    assert_same!(
+        Duration::from_object_f64(&[("fortnights", 1.0)]),
+        Duration::default()
+    );
+}
+
+#[test]
+fn test_typed_constructors() {
+    let obj = |pairs: &[(&str, i64)]| Duration::from_object(pairs);
+    assert_same!(
+        Duration::days(3).and_hours(2),
+        obj(&[("days", 3), ("hours", 2)])
+    );
+    assert_same!(
+        Duration::years(1).and_months(2).and_weeks(3),
+        obj(&[("years", 1), ("months", 2), ("weeks", 3)])
+    );
+    assert_same!(
+        Duration::minutes(5).and_seconds(30).and_milliseconds(250),
+        obj(&[("minutes", 5), ("seconds", 30), ("milliseconds", 250)])
+    );
+    assert_same!(Duration::hours(1).and_hours(-4), obj(&[("hours", -4)]));
+    assert_same!(
+        Duration::months(0).and_days(1).and_years(2),
+        obj(&[("years", 2), ("days", 1)])
+    );
+    assert_same!(Duration::weeks(2), obj(&[("weeks", 2)]));
+    assert_same!(Duration::seconds(9), obj(&[("seconds", 9)]));
+    assert_same!(
+        Duration::milliseconds(7).and_minutes(1),
+        obj(&[("minutes", 1), ("milliseconds", 7)])
+    );
+
+    const TIMEOUT: Duration = Duration::seconds(30).and_milliseconds(500);
+    assert_eq!(TIMEOUT.as_unit("milliseconds"), 30_500);
+}
+
+#[test]
+fn test_total_and_interval_length() {
+    use tempotime::{DateTime, Interval, Unit};
+    let dur = Duration::weeks(1).and_days(3).and_hours(5);
+    assert_eq!(dur.total(Unit::Days), 10);
+    assert_eq!(dur.total(Unit::Weeks), 1);
+    assert_eq!(dur.total(Unit::Minutes), dur.as_unit("minutes"));
+
+    let interval = Interval::from_date_times(
+        DateTime::from_iso("2025-01-01T00:00:00Z").unwrap(),
+        DateTime::from_iso("2025-03-02T06:00:00Z").unwrap(),
+    );
+    assert_same!(interval.length_in(Unit::Months), Duration::months(2));
+    assert_same!(interval.length_in(Unit::Days), interval.length("days"));
+    assert_same!(interval.length("lightyears"), Duration::default());
+}
+
+#[test]
+fn test_get_and_set() {
+    use tempotime::Unit;
+    let dur = Duration::from_object(&[
+        ("years", 1),
+        ("months", 2),
+        ("weeks", 3),
+        ("days", 4),
+        ("hours", 5),
+        ("minutes", 6),
+        ("seconds", 7),
+        ("milliseconds", 8),
+    ]);
+    let values: Vec<i64> = Unit::ALL.iter().map(|&unit| dur.get(unit)).collect();
+    assert_eq!(values, vec![1, 2, 3, 4, 5, 6, 7, 8]);
+    let getters = [
+        dur.get_years(),
+        dur.get_months(),
+        dur.get_weeks(),
+        dur.get_days(),
+        dur.get_hours(),
+        dur.get_minutes(),
+        dur.get_seconds(),
+        dur.get_milliseconds(),
+    ];
+    assert_eq!(getters.to_vec(), values);
+
+    for unit in Unit::ALL {
+        let changed = dur.set(unit, -9);
+        assert_eq!(changed.get(unit), -9);
+        assert_eq!(changed.set(unit, dur.get(unit)).to_iso(), dur.to_iso());
+    }
+    assert_same!(Duration::default().set(Unit::Hours, 5), Duration::hours(5));
+}
+
+#[test]
+fn test_sign_predicates() {
+    let cases = [
+        (Duration::default(), 0),
+        (Duration::milliseconds(1), 1),
+        (Duration::seconds(-1), -1),
+        (Duration::hours(1).and_minutes(-60), 0),
+        (Duration::days(1).and_hours(-25), -1),
+        (Duration::months(1).and_days(-29), 1),
+        (Duration::years(i64::MIN), -1),
+    ];
+    for (dur, sign) in cases {
+        assert_eq!(dur.signum(), sign, "{:?}", dur);
+        assert_eq!(dur.is_zero(), sign == 0, "{:?}", dur);
+        assert_eq!(dur.is_negative(), sign < 0, "{:?}", dur);
+    }
+}
+
+#[test]
+fn test_to_format() {
+    let lap = Duration::hours(1)
+        .and_minutes(7)
+        .and_seconds(4)
+        .and_milliseconds(250);
+    assert_eq!(lap.to_format("hh:mm:ss.SSS"), "01:07:04.250");
+    assert_eq!(lap.to_format("h:m:s"), "1:7:4");
+    assert_eq!(lap.to_format("mm:ss"), "67:04");
+    assert_eq!(lap.to_format("ss"), "4024");
+    assert_eq!(lap.to_format("hh'h' mm'm'"), "01h 07m");
+
+    assert_eq!(Duration::hours(36).to_format("hh:mm:ss"), "36:00:00");
+    assert_eq!(Duration::hours(36).to_format("d'd' hh:mm"), "1d 12:00");
+    assert_eq!(Duration::minutes(90).normalize().to_format("mm"), "90");
+    assert_eq!(Duration::days(10).to_format("w'w' d'd'"), "1w 3d");
+    assert_eq!(Duration::days(17).to_format("ww"), "02");
+    assert_eq!(Duration::years(1).and_months(14).to_format("y M"), "2 2");
+    assert_eq!(
+        Duration::seconds(59).and_milliseconds(999).to_format("mm"),
+        "00"
+    );
+
+    assert_eq!(Duration::seconds(-75).to_format("mm:ss"), "-01:15");
+    assert_eq!(Duration::default().to_format("hh:mm:ss"), "00:00:00");
+    assert_eq!(Duration::hours(1).to_format("'elapsed'"), "elapsed");
+}
+
+#[test]
+fn test_from_human() {
+    let parse = |s: &str| Duration::from_human(s).unwrap();
+    assert_same!(parse("90s"), Duration::seconds(90));
+    assert_same!(parse("2h 30m"), Duration::hours(2).and_minutes(30));
+    assert_same!(parse("2h30m"), Duration::hours(2).and_minutes(30));
+    assert_same!(parse("1 day 4 hours"), Duration::days(1).and_hours(4));
+    assert_same!(
+        parse("1 day, 4 hours and 5 minutes"),
+        Duration::days(1).and_hours(4).and_minutes(5)
+    );
+    assert_same!(parse("  3 Weeks  "), Duration::weeks(3));
+    assert_same!(
+        parse("2y 3mo 1w"),
+        Duration::years(2).and_months(3).and_weeks(1)
+    );
+    assert_same!(
+        parse("5 mins 10 secs 250ms"),
+        Duration::minutes(5).and_seconds(10).and_milliseconds(250)
+    );
+    assert_same!(parse("1 hr 2 hrs"), Duration::hours(3));
+    assert_same!(parse("1.5h"), Duration::hours(1).and_minutes(30));
+    assert_same!(parse("-15m"), Duration::minutes(-15));
+    assert_same!(parse("1 MS"), Duration::milliseconds(1));
+}
+
+#[test]
+fn test_from_human_errors() {
+    use tempotime::Error;
+    assert_eq!(
+        Duration::from_human(""),
+        Err(Error::UnexpectedEnd { position: 0 })
+    );
+    assert_eq!(
+        Duration::from_human(" , "),
+        Err(Error::UnexpectedEnd { position: 3 })
+    );
+    assert_eq!(
+        Duration::from_human("90"),
+        Err(Error::UnexpectedEnd { position: 2 })
+    );
+    assert_eq!(
+        Duration::from_human("5 dayz"),
+        Err(Error::Invalid {
+            what: "duration unit",
+            position: 2
+        })
+    );
+    assert_eq!(
+        Duration::from_human("5 :"),
+        Err(Error::Invalid {
+            what: "duration unit",
+            position: 2
+        })
+    );
+    assert_eq!(
+        Duration::from_human("h"),
+        Err(Error::Invalid {
+            what: "duration component",
+            position: 0
+        })
+    );
+    assert_eq!(
+        Duration::from_human("and 5m"),
+        Err(Error::Invalid {
+            what: "duration component",
+            position: 0
+        })
+    );
+    assert_eq!(
+        Duration::from_human("1..5h"),
+        Err(Error::Invalid {
+            what: "duration component",
+            position: 0
+        })
+    );
+    assert_eq!(
+        Duration::from_human("9223372036854775807s 1s"),
+        Err(Error::Invalid {
+            what: "duration component",
+            position: 21
+        })
+    );
+}
+
+#[test]
+fn test_from_millis_and_decompose() {
+    use tempotime::Unit;
+    let dur = Duration::from_millis(93_784_005);
+    assert_same!(dur, Duration::milliseconds(93_784_005));
+    assert_eq!(dur.as_unit("milliseconds"), 93_784_005);
+    assert_same!(
+        dur.decompose(&[]),
+        Duration::days(1)
+            .and_hours(2)
+            .and_minutes(3)
+            .and_seconds(4)
+            .and_milliseconds(5)
+    );
+    assert_same!(
+        dur.decompose(&[Unit::Minutes, Unit::Hours]),
+        Duration::hours(26).and_minutes(3)
+    );
+    assert_same!(dur.decompose(&[Unit::Days]), Duration::days(1));
+    assert_same!(
+        Duration::from_millis(-90_500).decompose(&[Unit::Minutes, Unit::Seconds]),
+        Duration::minutes(-1).and_seconds(-30)
+    );
+    assert_same!(
+        Duration::days(400).decompose(&[Unit::Years, Unit::Months, Unit::Days]),
+        Duration::years(1).and_months(1).and_days(5)
+    );
+    assert_same!(
+        Duration::hours(1)
+            .and_minutes(-30)
+            .decompose(&[Unit::Minutes]),
+        Duration::minutes(30)
+    );
+    assert_eq!(
+        Duration::from_millis(i64::MIN)
+            .decompose(&[])
+            .as_unit("milliseconds"),
+        i64::MIN
+    );
+}
@@ -21,7 +21,10 @@ fn roundtrip_numeric_month_and_millis() {
     let s = "2025-12-31 23:59:59.123";
     let pattern = "yyyy-MM-dd HH:mm:ss.SSS";
     let dt = DateTime::from_format(s, pattern).expect("parse");
-    assert_eq!(dt.to_format("yyyy-MM-dd HH:mm:ss.SSS"), "2025-12-31 23:59:59.123");
+    assert_eq!(
+        dt.to_format("yyyy-MM-dd HH:mm:ss.SSS"),
+        "2025-12-31 23:59:59.123"
+    );
 }
 
 #[test]
@@ -32,5 +35,3 @@ fn roundtrip_two_digit_year() {
     // two-digit year assumed to be 2000+
     assert_eq!(dt.to_format("yyyy-MM-dd HH:mm"), "2025-01-01 00:00");
 }
-
- 
\ No newline at end of file
@@ -1,4 +1,4 @@
-use tempotime::DateTime;
+use tempotime::{DateTime, Error};
 
 #[test]
 fn escaped_single_quote_roundtrip() {
@@ -15,7 +15,8 @@ fn unterminated_literal_is_error() {
     let res = DateTime::from_format("2025-10-30", "yyyy-MM-dd'unterminated");
     assert!(res.is_err());
     let e = res.err().unwrap();
-    // print the error to help debugging and assert it mentions unterminated literal
+    // print the error to help debugging and assert it is the unterminated literal
     eprintln!("from_format error: {}", e);
-    assert!(e.to_lowercase().contains("unterminated") || e.to_lowercase().contains("untertermin"));
+    assert_eq!(e, Error::UnterminatedLiteral);
+    assert!(e.to_string().to_lowercase().contains("unterminated"));
 }
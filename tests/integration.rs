@@ -23,7 +23,7 @@ fn test_interval_basic() {
 fn test_interval_boundaries() {
     let start = DateTime::from_iso("2025-10-01T00:00:00Z").unwrap();
     let end = DateTime::from_iso("2025-10-31T23:59:59Z").unwrap();
-    let interval = Interval::from_date_times(start.clone(), end.clone());
+    let interval = Interval::from_date_times(start, end);
 
     assert!(interval.contains(&start));
     assert!(interval.contains(&end));
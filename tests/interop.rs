@@ -0,0 +1,73 @@
+#![cfg(any(feature = "time", feature = "prost"))]
+
+use tempotime::DateTime;
+#[cfg(feature = "time")]
+use tempotime::Duration;
+
+#[cfg(feature = "time")]
+#[test]
+fn offset_date_time_round_trip() {
+    let odt = time::OffsetDateTime::from_unix_timestamp_nanos(1_761_834_600_123_456_789).unwrap();
+    let dt = DateTime::from(odt);
+    assert_eq!(dt.to_millis(), 1_761_834_600_123);
+
+    let back: time::OffsetDateTime = dt.into();
+    assert_eq!(back.unix_timestamp_nanos(), 1_761_834_600_123_000_000);
+    assert_eq!(back.offset(), time::UtcOffset::UTC);
+
+    // The source offset does not change the instant
+    let shifted = odt.to_offset(time::UtcOffset::from_hms(5, 30, 0).unwrap());
+    assert_eq!(DateTime::from(shifted).to_millis(), 1_761_834_600_123);
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn zoned_date_time_keeps_wall_clock() {
+    let dt = DateTime::from_iso("2025-10-30T14:30:00Z")
+        .unwrap()
+        .set_zone("Asia/Tokyo");
+    let odt: time::OffsetDateTime = dt.into();
+    assert_eq!(odt.offset().whole_seconds(), 9 * 3600);
+    assert_eq!((odt.day(), odt.hour(), odt.minute()), (30, 23, 30));
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn duration_conversions() {
+    let dur = Duration::from(time::Duration::seconds(90) + time::Duration::microseconds(1500));
+    assert_eq!(dur.as_unit("milliseconds"), 90_001);
+
+    let td: time::Duration = Duration::from_object(&[("days", 1), ("minutes", 1)]).into();
+    assert_eq!(td, time::Duration::seconds(86_460));
+
+    let negative = Duration::from(time::Duration::milliseconds(-250));
+    assert_eq!(negative.as_unit("milliseconds"), -250);
+}
+
+#[cfg(feature = "prost")]
+#[test]
+fn protobuf_timestamp_round_trip() {
+    use prost_types::Timestamp;
+
+    let dt = DateTime::from_millis(1_761_834_600_250).unwrap();
+    let ts = Timestamp::from(dt);
+    assert_eq!((ts.seconds, ts.nanos), (1_761_834_600, 250_000_000));
+    assert_eq!(DateTime::try_from(ts).unwrap(), dt);
+
+    // Pre-epoch instants keep nanos non-negative
+    let ts = Timestamp::from(DateTime::from_millis(-1).unwrap());
+    assert_eq!((ts.seconds, ts.nanos), (-1, 999_000_000));
+    assert_eq!(DateTime::try_from(ts).unwrap().to_millis(), -1);
+
+    // Denormalised input is accepted; out-of-range input is not
+    let ts = Timestamp {
+        seconds: 0,
+        nanos: 1_500_000_000,
+    };
+    assert_eq!(DateTime::try_from(ts).unwrap().to_millis(), 1500);
+    assert!(DateTime::try_from(Timestamp {
+        seconds: i64::MAX,
+        nanos: 0
+    })
+    .is_err());
+}
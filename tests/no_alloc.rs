@@ -0,0 +1,50 @@
+// `format_to_slice` and `iso_to_slice` are for targets without a heap, so
+// count the allocations they make on this thread.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+use tempotime::{DateTime, IsoOptions};
+
+struct Counting;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+fn allocations(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    f();
+    ALLOCATIONS.with(Cell::get) - before
+}
+
+#[test]
+fn formatting_into_slices_does_not_allocate() {
+    let utc = DateTime::from_iso("2025-10-30T14:30:00.250Z").unwrap();
+    let offset = utc.set_zone("+05:30");
+    let mut buf = [0u8; 96];
+    for dt in [utc, offset] {
+        let count = allocations(|| {
+            dt.format_to_slice(&mut buf, "EEE, MMMM do yyyy 'at' h:mm:ss.SSS a ZZ ZZZZ z")
+                .unwrap();
+            dt.format_to_slice(&mut buf, "h 'o''clock' a").unwrap();
+            dt.iso_to_slice(&mut buf, IsoOptions::default()).unwrap();
+            assert!(dt.format_to_slice(&mut buf[..4], "yyyy-MM-dd").is_err());
+        });
+        assert_eq!(count, 0);
+    }
+}
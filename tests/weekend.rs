@@ -0,0 +1,71 @@
+use tempotime::{DateTime, Settings, Weekday, Weekend};
+
+#[test]
+fn weekend_membership() {
+    assert!(Weekend::SATURDAY_SUNDAY.contains(Weekday::Saturday));
+    assert!(Weekend::SATURDAY_SUNDAY.contains(Weekday::Sunday));
+    assert!(!Weekend::SATURDAY_SUNDAY.contains(Weekday::Friday));
+    assert_eq!(
+        Weekend::new(&[Weekday::Sunday, Weekday::Sunday]),
+        Weekend::SUNDAY
+    );
+    assert_eq!(Weekend::default(), Weekend::SATURDAY_SUNDAY);
+}
+
+// Settings are process-wide, so everything touching them lives in one test.
+#[test]
+fn calendar_settings() {
+    let friday = DateTime::from_iso("2025-10-31T12:00:00Z").unwrap();
+    let sunday = DateTime::from_iso("2025-11-02T12:00:00Z").unwrap();
+
+    assert!(!friday.is_weekend());
+    assert!(friday.is_weekday());
+    assert!(sunday.is_weekend());
+    assert!(!sunday.is_weekday());
+
+    Settings::set_weekend(Weekend::FRIDAY_SATURDAY);
+    assert_eq!(Settings::weekend(), Weekend::FRIDAY_SATURDAY);
+    assert!(friday.is_weekend());
+    assert!(!sunday.is_weekend());
+    assert!(sunday.is_weekday());
+
+    Settings::set_weekend(Weekend::default());
+    assert!(!friday.is_weekend());
+
+    let new_year = DateTime::from_iso("2023-01-01T12:00:00Z").unwrap();
+    assert_eq!(new_year.to_format("iiii nn"), "2022 52");
+    Settings::set_week_rule(tempotime::WeekRule::US);
+    assert_eq!(new_year.local_week_number(), 1);
+    assert_eq!(new_year.local_week_year(), 2023);
+    assert_eq!(new_year.to_format("n ii"), "1 23");
+    Settings::set_week_rule(tempotime::WeekRule::default());
+}
+
+#[test]
+fn week_rules_differ_around_new_year() {
+    use tempotime::WeekRule;
+
+    // Friday 2021-01-01 closes ISO week 53 of 2020 but opens US week 1 of 2021.
+    let dt = DateTime::from_iso("2021-01-01T12:00:00Z").unwrap();
+    assert_eq!(dt.week_number_with(WeekRule::ISO), 53);
+    assert_eq!(dt.week_year_with(WeekRule::ISO), 2020);
+    assert_eq!(dt.week_number_with(WeekRule::US), 1);
+    assert_eq!(dt.week_year_with(WeekRule::US), 2021);
+
+    // Monday 2024-12-30 already belongs to week 1 of 2025 under both rules.
+    let dt = DateTime::from_iso("2024-12-30T12:00:00Z").unwrap();
+    assert_eq!(dt.week_number_with(WeekRule::ISO), 1);
+    assert_eq!(dt.week_year_with(WeekRule::US), 2025);
+
+    // Saturday 2025-11-01 (US week starts Sunday Oct 26)
+    let dt = DateTime::from_iso("2025-11-01T12:00:00Z").unwrap();
+    assert_eq!(dt.week_number_with(WeekRule::ISO), 44);
+    assert_eq!(dt.week_number_with(WeekRule::US), 44);
+    let sunday = DateTime::from_iso("2025-11-02T12:00:00Z").unwrap();
+    assert_eq!(sunday.week_number_with(WeekRule::ISO), 44);
+    assert_eq!(sunday.week_number_with(WeekRule::US), 45);
+
+    let custom = WeekRule::new(tempotime::Weekday::Saturday, 1);
+    assert_eq!(custom.first_day(), tempotime::Weekday::Saturday);
+    assert_eq!(custom.minimal_days(), 1);
+}
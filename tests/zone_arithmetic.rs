@@ -0,0 +1,86 @@
+// Tokyo has kept +09:00 without DST since 1951, so these read the same with
+// the built-in zones and with the tz database.
+
+use tempotime::{DateTime, Duration, Unit};
+
+fn at(iso: &str) -> DateTime {
+    DateTime::from_iso(iso).unwrap()
+}
+
+#[test]
+fn zone_keeps_the_instant() {
+    let utc = at("2025-10-30T12:00:00Z");
+    let tokyo = utc.set_zone("Asia/Tokyo");
+    assert_eq!(tokyo, utc);
+    assert_eq!(tokyo.diff(&utc, "milliseconds"), 0.0);
+    // The offset is written out, so the string still names the same instant.
+    assert_eq!(tokyo.to_iso(), "2025-10-30T21:00:00+09:00");
+    assert_eq!(at(&tokyo.to_iso()), utc);
+    assert_eq!(tokyo.offset(), 540);
+}
+
+#[test]
+fn zone_survives_arithmetic() {
+    let dt = at("2025-10-30T20:00:00Z").set_zone("Asia/Tokyo");
+    // 05:00 on the 31st in Tokyo; start of day is Tokyo midnight (15:00 UTC on the 30th)
+    let start = dt.start_of("day");
+    assert_eq!(start.to_format("yyyy-MM-dd HH:mm"), "2025-10-31 00:00");
+    assert_eq!(start, at("2025-10-30T15:00:00Z"));
+    assert_eq!(start.zone_name(), Some("Asia/Tokyo"));
+    assert_eq!(dt.plus(&Duration::hours(1)).to_format("HH:mm"), "06:00");
+}
+
+#[test]
+fn start_and_end_of_units_in_zone() {
+    // Friday the 31st in Tokyo, still Thursday the 30th in UTC.
+    let dt = at("2025-10-30T20:00:00Z").set_zone("Asia/Tokyo");
+    let fmt = "yyyy-MM-dd HH:mm:ss.SSS";
+    assert_eq!(
+        dt.start_of_unit(Unit::Weeks).to_format(fmt),
+        "2025-10-27 00:00:00.000"
+    );
+    assert_eq!(
+        dt.end_of_unit(Unit::Weeks).to_format(fmt),
+        "2025-11-02 23:59:59.999"
+    );
+    assert_eq!(
+        dt.start_of_unit(Unit::Months).to_format(fmt),
+        "2025-10-01 00:00:00.000"
+    );
+    assert_eq!(
+        dt.end_of_unit(Unit::Months).to_format(fmt),
+        "2025-10-31 23:59:59.999"
+    );
+    assert_eq!(dt.end_of_unit(Unit::Days), at("2025-10-31T14:59:59.999Z"));
+    assert_eq!(dt.start_of_unit(Unit::Years), at("2024-12-31T15:00:00Z"));
+    assert_eq!(
+        dt.end_of_unit(Unit::Seconds).to_format(fmt),
+        "2025-10-31 05:00:00.999"
+    );
+}
+
+#[test]
+fn calendar_math_in_zone() {
+    // January 31st in Tokyo, still the 30th in UTC.
+    let dt = at("2025-01-30T20:00:00Z").set_zone("Asia/Tokyo");
+    let next = dt.plus(&Duration::from_object(&[("months", 1)]));
+    assert_eq!(next.to_format("yyyy-MM-dd HH:mm"), "2025-02-28 05:00");
+    let back = dt.minus(&Duration::from_object(&[("years", 1), ("days", 31)]));
+    assert_eq!(back.to_format("yyyy-MM-dd HH:mm"), "2023-12-31 05:00");
+}
+
+#[cfg(feature = "tz")]
+#[test]
+fn calendar_math_across_dst() {
+    // 10:30 EDT on Thursday; the clocks go back early on Sunday.
+    let dt = at("2025-10-30T14:30:00Z").set_zone("America/New_York");
+    let sunday = dt.plus(&Duration::days(3));
+    assert_eq!(
+        sunday.to_format("yyyy-MM-dd HH:mm ZZ"),
+        "2025-11-02 10:30 -05:00"
+    );
+    assert_eq!(dt.plus(&Duration::hours(72)).to_format("HH:mm"), "09:30");
+    let start = sunday.start_of("day");
+    assert_eq!(start.to_format("HH:mm ZZ"), "00:00 -04:00");
+    assert_eq!(sunday.diff(&start, "hours"), 11.5);
+}
@@ -0,0 +1,280 @@
+use tempotime::{
+    DateTime, Duration, Error, Settings, StaticProvider, TimeZoneProvider, TzfileProvider,
+};
+
+fn ms(iso: &str) -> i64 {
+    DateTime::from_iso(iso).unwrap().to_millis()
+}
+
+// A version 2 TZif file with an empty version 1 block, the given transitions
+// (seconds, type index) and local time types (offset, is DST, abbreviation),
+// and a footer.
+fn tzif(transitions: &[(i64, u8)], types: &[(i32, bool, &str)], footer: &str) -> Vec<u8> {
+    let header = |out: &mut Vec<u8>, timecnt: usize, typecnt: usize, charcnt: usize| {
+        out.extend_from_slice(b"TZif2");
+        out.extend_from_slice(&[0; 15]);
+        for count in [0, 0, 0, timecnt, typecnt, charcnt] {
+            out.extend_from_slice(&(count as u32).to_be_bytes());
+        }
+    };
+    let mut chars = Vec::new();
+    let mut out = Vec::new();
+    header(&mut out, 0, 0, 0);
+    let charcnt = types.iter().map(|(.., name)| name.len() + 1).sum();
+    header(&mut out, transitions.len(), types.len(), charcnt);
+    for (at, _) in transitions {
+        out.extend_from_slice(&at.to_be_bytes());
+    }
+    out.extend(transitions.iter().map(|(_, ix)| ix));
+    for (offset, is_dst, name) in types {
+        out.extend_from_slice(&offset.to_be_bytes());
+        out.extend_from_slice(&[*is_dst as u8, chars.len() as u8]);
+        chars.extend_from_slice(name.as_bytes());
+        chars.push(0);
+    }
+    out.extend_from_slice(&chars);
+    out.extend_from_slice(format!("\n{}\n", footer).as_bytes());
+    out
+}
+
+fn new_york_2025() -> Vec<u8> {
+    let spring = ms("2025-03-09T07:00:00Z") / 1000;
+    let fall = ms("2025-11-02T06:00:00Z") / 1000;
+    tzif(
+        &[(spring, 1), (fall, 0)],
+        &[(-18000, false, "EST"), (-14400, true, "EDT")],
+        "EST5EDT,M3.2.0,M11.1.0",
+    )
+}
+
+#[test]
+fn tzfile_table_and_footer() {
+    let mut provider = TzfileProvider::new();
+    provider.insert("Test/New_York", &new_york_2025()).unwrap();
+    provider
+        .insert(
+            "Test/Sydney",
+            &tzif(
+                &[],
+                &[(36000, false, "AEST")],
+                "AEST-10AEDT,M10.1.0,M4.1.0/3",
+            ),
+        )
+        .unwrap();
+    assert_eq!(
+        provider.zone_names().collect::<Vec<_>>(),
+        ["Test/New_York", "Test/Sydney"]
+    );
+
+    let ny = provider.zone_id("Test/New_York").unwrap();
+    assert_eq!(provider.zone_id("test/new_york"), None);
+    assert_eq!(provider.offset_at(ny, ms("2025-01-15T12:00:00Z")), -18000);
+    assert_eq!(provider.offset_at(ny, ms("2025-07-01T12:00:00Z")), -14400);
+    assert!(provider.is_dst(ny, ms("2025-07-01T12:00:00Z")));
+    // Past the table the footer rule applies.
+    assert_eq!(provider.offset_at(ny, ms("2040-07-01T12:00:00Z")), -14400);
+    assert_eq!(provider.offset_at(ny, ms("2040-12-01T12:00:00Z")), -18000);
+
+    assert_eq!(
+        provider.next_transition(ny, ms("2025-01-01T00:00:00Z")),
+        Some(ms("2025-03-09T07:00:00Z"))
+    );
+    assert_eq!(
+        provider.previous_transition(ny, ms("2025-11-02T06:00:00Z")),
+        Some(ms("2025-11-02T06:00:00Z"))
+    );
+    assert_eq!(
+        provider.next_transition(ny, ms("2025-12-01T00:00:00Z")),
+        Some(ms("2026-03-08T07:00:00Z"))
+    );
+    assert_eq!(
+        provider.previous_transition(ny, ms("2030-01-01T00:00:00Z")),
+        Some(ms("2029-11-04T06:00:00Z"))
+    );
+
+    // Southern hemisphere: DST spans the new year.
+    let sydney = provider.zone_id("Test/Sydney").unwrap();
+    assert_eq!(
+        provider.offset_at(sydney, ms("2030-01-01T00:00:00Z")),
+        39600
+    );
+    assert_eq!(
+        provider.offset_at(sydney, ms("2030-07-01T00:00:00Z")),
+        36000
+    );
+    assert_eq!(
+        provider.next_transition(sydney, ms("2030-01-01T00:00:00Z")),
+        Some(ms("2030-04-06T16:00:00Z"))
+    );
+
+    assert_eq!(
+        provider.insert("Broken", b"not a tzfile"),
+        Err(Error::Invalid {
+            what: "tzfile",
+            position: 0
+        })
+    );
+    // Abbreviations come from the designations and the footer.
+    assert_eq!(
+        provider
+            .abbreviation(ny, ms("2025-01-15T12:00:00Z"))
+            .as_deref(),
+        Some("EST")
+    );
+    assert_eq!(
+        provider
+            .abbreviation(ny, ms("2040-07-01T12:00:00Z"))
+            .as_deref(),
+        Some("EDT")
+    );
+    assert_eq!(
+        provider
+            .abbreviation(sydney, ms("2030-07-01T00:00:00Z"))
+            .as_deref(),
+        Some("AEST")
+    );
+    let quoted = tzif(&[], &[(10800, false, "+03")], "<+03>-3");
+    provider.insert("Test/Quoted", &quoted).unwrap();
+    let id = provider.zone_id("Test/Quoted").unwrap();
+    assert_eq!(provider.abbreviation(id, 0).as_deref(), Some("+03"));
+
+    let truncated = &new_york_2025()[..60];
+    assert!(provider.insert("Broken", truncated).is_err());
+    // Counts larger than the file are rejected before anything is read.
+    let mut oversized = new_york_2025();
+    oversized[36..40].copy_from_slice(&u32::MAX.to_be_bytes());
+    assert_eq!(
+        provider.insert("Broken", &oversized),
+        Err(Error::Invalid {
+            what: "tzfile",
+            position: 44
+        })
+    );
+    let mut oversized = new_york_2025();
+    oversized[44 + 36..44 + 40].copy_from_slice(&u32::MAX.to_be_bytes());
+    assert_eq!(
+        provider.insert("Broken", &oversized),
+        Err(Error::Invalid {
+            what: "tzfile",
+            position: 88
+        })
+    );
+    assert_eq!(provider.len(), 3);
+}
+
+#[test]
+fn system_tzfile() {
+    let Ok(data) = std::fs::read("/usr/share/zoneinfo/America/New_York") else {
+        return;
+    };
+    let mut provider = TzfileProvider::new();
+    provider.insert("America/New_York", &data).unwrap();
+    let ny = provider.zone_id("America/New_York").unwrap();
+    assert_eq!(provider.offset_at(ny, ms("1990-07-01T12:00:00Z")), -14400);
+    assert_eq!(provider.offset_at(ny, ms("2025-01-15T12:00:00Z")), -18000);
+    assert_eq!(provider.offset_at(ny, ms("2060-07-01T12:00:00Z")), -14400);
+    assert_eq!(
+        provider.next_transition(ny, ms("2025-10-01T00:00:00Z")),
+        Some(ms("2025-11-02T06:00:00Z"))
+    );
+}
+
+#[test]
+fn static_provider() {
+    let id = StaticProvider.zone_id("asia/tokyo").unwrap();
+    assert_eq!(StaticProvider.zone_name(id), "Asia/Tokyo");
+    assert_eq!(StaticProvider.offset_at(id, 0), 32400);
+    assert_eq!(StaticProvider.next_transition(id, 0), None);
+    assert_eq!(StaticProvider.zone_id("Mars/Olympus_Mons"), None);
+}
+
+#[cfg(feature = "tz")]
+#[test]
+fn chrono_tz_provider() {
+    use tempotime::ChronoTzProvider;
+
+    let id = ChronoTzProvider.zone_id("Europe/Paris").unwrap();
+    assert_eq!(ChronoTzProvider.zone_name(id), "Europe/Paris");
+    assert_eq!(
+        ChronoTzProvider.offset_at(id, ms("2025-07-01T00:00:00Z")),
+        7200
+    );
+    assert!(ChronoTzProvider.is_dst(id, ms("2025-07-01T00:00:00Z")));
+    assert_eq!(
+        ChronoTzProvider.next_transition(id, ms("2025-07-01T00:00:00Z")),
+        Some(ms("2025-10-26T01:00:00Z"))
+    );
+}
+
+// Zones on a fixed offset that jumps forward an hour at 2025-06-01T00:00Z.
+struct Stepped;
+
+impl TimeZoneProvider for Stepped {
+    fn zone_id(&self, name: &str) -> Option<usize> {
+        (name == "Test/Stepped").then_some(0)
+    }
+
+    fn zone_name(&self, _id: usize) -> &str {
+        "Test/Stepped"
+    }
+
+    fn offset_at(&self, _id: usize, instant_ms: i64) -> i32 {
+        if instant_ms < 1_748_736_000_000 {
+            0
+        } else {
+            3600
+        }
+    }
+}
+
+// The provider is process-wide, so all checks that install one share a
+// single test.
+#[test]
+fn installed_providers() {
+    let mut tzfile = TzfileProvider::new();
+    tzfile.insert("Test/New_York", &new_york_2025()).unwrap();
+    Settings::set_zone_provider(Box::leak(Box::new(tzfile)));
+
+    let summer = DateTime::from_iso("2025-07-01T12:00:00Z")
+        .unwrap()
+        .set_zone("Test/New_York");
+    assert_eq!(summer.zone_name(), Some("Test/New_York"));
+    assert_eq!(summer.offset(), -240);
+    assert!(summer.is_dst());
+    assert_eq!(summer.to_format("h:mm a ZZZZ"), "8:00 am EDT");
+    Settings::set_zone_abbreviations("Test/New_York", "ET", "ET (DST)");
+    assert_eq!(summer.to_format("h:mm a ZZZZ"), "8:00 am ET (DST)");
+    assert_eq!(
+        summer
+            .minus(&Duration::days(180))
+            .render("{zone_abbreviation}")
+            .unwrap(),
+        "ET"
+    );
+    Settings::clear_zone_abbreviations();
+    assert_eq!(summer.to_format("ZZZZ"), "EDT");
+    let (at, delta) = summer.next_dst_transition().unwrap();
+    assert_eq!(at.to_format("yyyy-MM-dd HH:mm"), "2025-11-02 01:00");
+    assert_eq!(delta, Duration::hours(-1));
+    // Built-in zones and fixed offsets are still found.
+    assert_eq!(summer.set_zone("Asia/Tokyo").offset(), 540);
+    assert_eq!(summer.set_zone("+02:00").offset(), 120);
+
+    static STEPPED: Stepped = Stepped;
+    Settings::set_zone_provider(&STEPPED);
+    let before = DateTime::from_iso("2025-05-01T00:00:00Z")
+        .unwrap()
+        .set_zone("Test/Stepped");
+    assert_eq!(before.offset(), 0);
+    assert!(!before.is_dst());
+    let (at, delta) = before.next_dst_transition().unwrap();
+    assert_eq!(at, DateTime::from_iso("2025-06-01T00:00:00Z").unwrap());
+    assert_eq!(delta, Duration::hours(1));
+    assert!(before.try_set_zone("Test/New_York").is_err());
+    // Zones resolved earlier keep their provider.
+    assert_eq!(summer.offset(), -240);
+
+    Settings::reset_zone_provider();
+    assert!(before.try_set_zone("Test/Stepped").is_err());
+    assert_eq!(before.plus(&Duration::days(60)).offset(), 60);
+}